@@ -0,0 +1,35 @@
+use osmanthus::infer_layout;
+
+fn main() {
+    // (样本, 期望的格式, 期望的日期顺序, 是否带有时区)
+    let cases = vec![
+        (vec!["05/10/2023 14:20", "25/10/2023 09:05", "01/11/2023 23:59", "N/A"], "%d/%m/%Y %H:%M", "dmy", false),
+        (vec!["10/05/2023", "10/25/2023", "11/1/2023"], "%m/%d/%Y", "mdy", false),
+        (vec!["2023-10-05T14:20:30+08:00", "2023-10-06T08:00:00+08:00", "2023-10-07 12:00"], "%Y-%m-%dT%H:%M:%S%#z", "ymd", true),
+        (vec!["Oct 5, 2023 3:04 pm", "Nov 12, 2023 11:30 am", "Thu, 5 Oct 2023"], "%B %d, %Y %I:%M %p", "mdy", false),
+        (vec!["2023年10月5日", "2023年11月25日"], "%Y年%m月%d日", "ymd", false),
+        (vec!["20231005", "20231125", "20231231"], "%Y%m%d", "ymd", false),
+        (vec!["20231005T142030Z", "20231125T080000Z"], "%Y%m%dT%H%M%S%#z", "ymd", true),
+        (vec!["2023-10-05 14:20:30,123 INFO", "2023-10-05 14:20:31,456 INFO"], "%Y-%m-%d %H:%M:%S,%3f INFO", "ymd", false),
+    ];
+    for (samples, format, order, timezone) in cases{
+        let layout = infer_layout(&samples, None).expect(samples[0]);
+        println!("layout: {:<48} => {:?}", samples[0], layout);
+        assert_eq!(layout.format, format, "{}", samples[0]);
+        assert_eq!(layout.order, order, "{}", samples[0]);
+        assert_eq!(layout.timezone, timezone, "{}", samples[0]);
+    }
+    // 推断出格式后解析其余的值｜不符合格式的值视为离群值
+    let layout = infer_layout(&["05/10/2023", "25/10/2023"], None).unwrap();
+    let r = layout.parse("12/11/2023", None);
+    println!("layout: {:<48} => {}, status: {}", "12/11/2023", r.time, r.status);
+    assert!(r.status);
+    assert_eq!(r.time.to_string(), "2023-11-12 00:00:00");
+    assert_eq!(r.format, "%d/%m/%Y");
+    for outlier in ["2023-11-12", "13/13/2023", "yesterday"]{
+        let r = layout.parse(outlier, None);
+        println!("layout: {:<48} => status: {}", outlier, r.status);
+        assert!(!r.status, "{}", outlier);
+    }
+    assert!(infer_layout(&["N/A", "unknown"], None).is_none());
+}
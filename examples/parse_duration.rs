@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use osmanthus::parse_duration;
+
+fn main() {
+    // (样本, 期望的 (年, 月, 天, 秒), 识别方式)
+    let samples = vec![
+        ("PT2H30M", (0, 0, 0, 9000.0), "iso8601"),
+        ("P1Y2M", (1, 2, 0, 0.0), "iso8601"),
+        ("P3W", (0, 0, 21, 0.0), "iso8601"),
+        ("P1DT12H", (0, 0, 1, 43200.0), "iso8601"),
+        ("PT0,5S", (0, 0, 0, 0.5), "iso8601"),
+        ("-P1D", (0, 0, -1, 0.0), "iso8601"),
+        ("P0001-02-03T04:05:06", (1, 2, 3, 14706.0), "iso8601"),
+        ("1:30:00", (0, 0, 0, 5400.0), "clock"),
+        ("0:45", (0, 0, 0, 2700.0), "clock"),
+        ("3天4小时", (0, 0, 3, 14400.0), "text"),
+        ("三天四小时", (0, 0, 3, 14400.0), "text"),
+        ("2个月", (0, 2, 0, 0.0), "text"),
+        ("2 hrs 15 mins", (0, 0, 0, 8100.0), "text"),
+        ("1 hour and 30 minutes", (0, 0, 0, 5400.0), "text"),
+        ("1h30m", (0, 0, 0, 5400.0), "text"),
+        ("1.5 days", (0, 0, 1, 43200.0), "text"),
+        ("2 years, 3 weeks", (2, 0, 21, 0.0), "text"),
+        ("2 jam 30 menit", (0, 0, 0, 9000.0), "text"),
+        ("3 semaines", (0, 0, 21, 0.0), "text"),
+    ];
+    for (sample, (years, months, days, seconds), method) in samples{
+        let r = parse_duration(sample, None);
+        println!("duration: {:<24} => {:?}", sample, r);
+        assert!(r.status, "{}", sample);
+        assert_eq!((r.years, r.months, r.days), (years, months, days), "{}", sample);
+        assert!((r.seconds - seconds).abs() < 1e-6, "{}", sample);
+        assert_eq!(r.method, method, "{}", sample);
+    }
+    for sample in ["P", "PT", "P1.5Y", "3 apples", "2023-10-05", "yesterday"]{
+        let r = parse_duration(sample, None);
+        println!("duration: {:<24} => status: {}", sample, r.status);
+        assert!(!r.status, "{}", sample);
+    }
+    // 年和月按日历计算
+    let time = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_opt(8, 0, 0).unwrap();
+    let r = parse_duration("P1M1DT2H", None);
+    assert_eq!(r.add_to(time).unwrap().to_string(), "2024-03-01 10:00:00");
+}
@@ -0,0 +1,28 @@
+use osmanthus::parse_absolute;
+use osmanthus::bind::Param;
+
+fn main() {
+    let formats = vec!["%d/%m/%Y %H:%M".to_string(), "%d/%m/%Y".to_string(), "%d %B %Y".to_string(), "%Y%m%d%H%M%S%z".to_string()];
+    // (样本, 期望的 UTC 时间｜无时区时为原始时间, 期望匹配的格式)
+    let samples = vec![
+        ("05/10/2023 14:20", "2023-10-05 14:20:00", "%d/%m/%Y %H:%M"),
+        ("05/10/2023", "2023-10-05 00:00:00", "%d/%m/%Y"),
+        ("5 October 2023", "2023-10-05 00:00:00", "%d %B %Y"),
+        ("5 octobre 2023", "2023-10-05 00:00:00", "%d %B %Y"),
+        ("5 ต.ค. 2023", "2023-10-05 00:00:00", "%d %B %Y"),
+        ("20231005142030+0800", "2023-10-05 06:20:30", "%Y%m%d%H%M%S%z"),
+    ];
+    for (sample, expected, format) in samples{
+        let r = parse_absolute(sample, Some(Param{formats: formats.clone(), ..Default::default()}));
+        let actual = if r.timezone.is_empty() {r.time} else {r.datetime.timezone.datetime};
+        println!("format: {:<24} => {}, format: {:?}, status: {}", sample, actual, r.format, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!(actual.to_string(), expected, "{}", sample);
+        assert_eq!(r.format, format, "{}", sample);
+    }
+    // 均未匹配时使用通用流程
+    let r = parse_absolute("2023年10月5日", Some(Param{formats: formats.clone(), ..Default::default()}));
+    println!("format: {:<24} => {}, format: {:?}, status: {}", "2023年10月5日", r.time, r.format, r.status);
+    assert!(r.status);
+    assert!(r.format.is_empty());
+}
@@ -0,0 +1,52 @@
+use chrono::Weekday;
+use osmanthus::parse_absolute;
+use osmanthus::bind::{Holiday, HolidayRule, Lexicon, Param};
+
+fn main() {
+    // (样本, 期望的时间, 期望的节日名称)
+    let samples = vec![
+        ("2023年国庆节", "2023-10-01 00:00:00", "国庆节"),
+        ("Christmas Eve 2023", "2023-12-24 00:00:00", "Christmas Eve"),
+        ("Christmas 2023 8pm", "2023-12-25 20:00:00", "Christmas"),
+        ("Thanksgiving 2022", "2022-11-24 00:00:00", "Thanksgiving"),
+        ("black friday 2023", "2023-11-24 00:00:00", "black friday"),
+        ("Memorial Day 2023", "2023-05-29 00:00:00", "Memorial Day"),
+        ("Good Friday 2024", "2024-03-29 00:00:00", "Good Friday"),
+        ("Easter 2024", "2024-03-31 00:00:00", "Easter"),
+        ("2023年除夕", "2023-01-21 00:00:00", "除夕"),
+        ("2024年春节前", "2024-02-10 00:00:00", "春节"),
+        ("二〇二三年中秋节", "2023-09-29 00:00:00", "中秋节"),
+        ("2024年腊八", "2024-01-18 00:00:00", "腊八"),
+        ("2023年国庆节晚上8点", "2023-10-01 20:00:00", "国庆节"),
+        ("2023-10-02 国庆节", "2023-10-02 00:00:00", "国庆节"),
+    ];
+    for (sample, expected, holiday) in samples{
+        let r = parse_absolute(sample, None);
+        println!("holiday: {:<24} => {}, {:?}", sample, r.time, r.holiday);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.time.to_string(), expected, "{}", sample);
+        assert_eq!(r.holiday, holiday, "{}", sample);
+    }
+    // 补充节日｜例如 双十一 和 11 月第 1 个周二的美国大选日
+    let mut lexicon = Lexicon::new();
+    lexicon.insert_holiday("双十一", Holiday{rule: HolidayRule::Fixed(11, 11), offset: 0});
+    lexicon.insert_holiday("election day", Holiday{rule: HolidayRule::Weekday(11, Weekday::Mon, 1), offset: 1});
+    for (sample, expected) in [("2023年双十一", "2023-11-11 00:00:00"), ("Election Day 2024", "2024-11-05 00:00:00")]{
+        let r = parse_absolute(sample, Some(Param::new().with_lexicon(lexicon.clone())));
+        println!("holiday: {:<24} => {}, {:?}", sample, r.time, r.holiday);
+        assert_eq!(r.time.to_string(), expected, "{}", sample);
+        // 补充的节日只在传入的词库中生效
+        assert!(parse_absolute(sample, None).holiday.is_empty(), "{}", sample);
+    }
+    let mut lexicon = Lexicon::new();
+    lexicon.extend_json(r#"{"holiday": {"双十二": "fixed 12 12"}}"#).unwrap();
+    let r = parse_absolute("2023年双十二", Some(Param::new().with_lexicon(lexicon)));
+    println!("holiday: {:<24} => {}, {:?}", "2023年双十二", r.time, r.holiday);
+    assert_eq!(r.time.to_string(), "2023-12-12 00:00:00");
+    assert!(Lexicon::new().extend_json(r#"{"holiday": {"双十二": "fixed 12"}}"#).is_err());
+    for sample in ["eastern 2023", "2023-10-05"]{
+        let r = parse_absolute(sample, None);
+        println!("holiday: {:<24} => {:?}", sample, r.holiday);
+        assert!(r.holiday.is_empty(), "{}", sample);
+    }
+}
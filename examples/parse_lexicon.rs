@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use osmanthus::{parse, parse_absolute, parse_relative};
+use osmanthus::bind::{Lexicon, Param};
+
+fn main() {
+    // 内置词库中没有的月份、相对时间和时区缩写
+    let samples = ["5 avgusta 2023 14:20 XYZ", "hace 3 horitas"];
+    for text in samples{
+        assert!(!parse(text, None).status, "{}", text);
+    }
+    // 从 JSON 文件补充词库
+    let path = std::env::temp_dir().join("osmanthus_lexicon.json");
+    std::fs::write(&path, r#"{"month": {"avgusta": "august"}, "relative": {"horitas": "hour ago"}, "timezone": {"xyz": 18000}}"#).unwrap();
+    let mut lexicon = Lexicon::new();
+    lexicon.load_json(&path).unwrap();
+    // 在代码中补充
+    lexicon.insert_weekday("lunedì", "monday");
+    let lexicon = Arc::new(lexicon);
+    let options = || Some(Param{lexicon: Some(lexicon.clone()), ..Default::default()});
+    let r = parse_absolute("5 avgusta 2023 14:20 XYZ", options());
+    println!("{:?}", r.datetime.timezone.datetime);
+    assert!(r.status);
+    assert_eq!(r.time.to_string(), "2023-08-05 14:20:00");
+    assert_eq!(r.datetime.timezone.datetime.to_string(), "2023-08-05 09:20:00");
+    let r = parse_relative("hace 3 horitas", options());
+    println!("{:?}", r.time);
+    assert!(r.status);
+    // 内置词库不受影响
+    assert!(!parse_relative("hace 3 horitas", None).status);
+    // 覆盖内置的词
+    let mut lexicon = Lexicon::new();
+    lexicon.extend_json(r#"{"timezone": {"cst": 28800}}"#).unwrap();
+    let r = parse_absolute("2023-08-05 14:20 CST", Some(Param{lexicon: Some(Arc::new(lexicon)), ..Default::default()}));
+    assert_eq!(r.datetime.timezone.datetime.to_string(), "2023-08-05 06:20:00");
+    // 未知的键和错误的值
+    assert!(Lexicon::new().extend_json(r#"{"months": {}}"#).is_err());
+    assert!(Lexicon::new().extend_json(r#"{"timezone": {"xyz": "+05:00"}}"#).is_err());
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,35 @@
+use chrono::{Datelike, Local};
+use osmanthus::parse_absolute;
+
+fn main() {
+    // (日志行, 期望的 UTC 时间｜无时区时为原始时间)
+    let samples = vec![
+        (r#"127.0.0.1 - - [05/Oct/2023:14:20:30 +0800] "GET / HTTP/1.1" 200 612"#, "2023-10-05 06:20:30"),
+        ("[05/Oct/2023:14:20:30 -0500]", "2023-10-05 19:20:30"),
+        ("05/Oct/2023:14:20:30 +0000", "2023-10-05 14:20:30"),
+        ("2023-10-05 14:20:30,123 INFO  [main] com.example.App - started", "2023-10-05 14:20:30.123"),
+        ("[2023-10-05 14:20:30,123] ERROR failed", "2023-10-05 14:20:30.123"),
+        ("2023-10-05 14:20:30.123 DEBUG 1234 --- [main] o.s.Application", "2023-10-05 14:20:30.123"),
+        ("2023/10/05 14:20:30 [error] 1234#0: *1 open() failed", "2023-10-05 14:20:30"),
+        ("2023/10/05 14:20:30.123456", "2023-10-05 14:20:30.123456"),
+        ("2023-10-05T14:20:30+0800 host systemd[1]: Started Session 1.", "2023-10-05 06:20:30"),
+        ("<34>1 2023-10-05T14:20:30.003Z mymachine su - ID47 - 'su root' failed", "2023-10-05 14:20:30.003"),
+    ];
+    for (sample, expected) in samples{
+        let r = parse_absolute(sample, None);
+        let actual = if r.timezone.is_empty() {r.time} else {r.datetime.timezone.datetime};
+        println!("log: {:<70} => {}, timezone: {:?}, status: {}", sample, actual, r.timezone, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!(actual.to_string(), expected, "{}", sample);
+    }
+    // syslog 不带年份｜取当前年份 晚于明天时视为去年
+    let year = Local::now().year();
+    for (sample, month, day) in [("Jan  5 14:20:30 host sshd[123]: Accepted password", 1, 5), ("Oct 05 14:20:30.123456 host kernel: eth0 up", 10, 5)]{
+        let r = parse_absolute(sample, None);
+        println!("log: {:<70} => {}, status: {}", sample, r.time, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!((r.time.month(), r.time.day()), (month, day), "{}", sample);
+        assert!(r.time.year() == year || r.time.year() == year - 1, "{}", sample);
+        assert!(r.time.date() <= Local::now().date_naive() + chrono::Duration::days(1), "{}", sample);
+    }
+}
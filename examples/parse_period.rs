@@ -0,0 +1,39 @@
+use osmanthus::parse_period;
+use osmanthus::bind::{Granularity, Param};
+
+fn main() {
+    // (样本, 财年起始月份, 期望的起始日期, 期望的结束日期, 期望的粒度)
+    let samples = vec![
+        ("Q3 2023", 0, "2023-07-01", "2023-09-30", Granularity::Quarter),
+        ("2023年第三季度", 0, "2023-07-01", "2023-09-30", Granularity::Quarter),
+        ("2023年第4季度", 0, "2023-10-01", "2023-12-31", Granularity::Quarter),
+        ("2023 Q1", 0, "2023-01-01", "2023-03-31", Granularity::Quarter),
+        ("3Q 2023", 0, "2023-07-01", "2023-09-30", Granularity::Quarter),
+        ("third quarter of 2023", 0, "2023-07-01", "2023-09-30", Granularity::Quarter),
+        ("Q3 FY2023", 10, "2023-04-01", "2023-06-30", Granularity::Quarter),
+        ("Q1 FY23", 4, "2022-04-01", "2022-06-30", Granularity::Quarter),
+        ("H1 2022", 0, "2022-01-01", "2022-06-30", Granularity::Half),
+        ("2023年上半年", 0, "2023-01-01", "2023-06-30", Granularity::Half),
+        ("2023年下半年", 0, "2023-07-01", "2023-12-31", Granularity::Half),
+        ("first half of 2023", 0, "2023-01-01", "2023-06-30", Granularity::Half),
+        ("2023年第42周", 0, "2023-10-16", "2023-10-22", Granularity::Week),
+        ("week 42 of 2023", 0, "2023-10-16", "2023-10-22", Granularity::Week),
+        ("2023-W01", 0, "2023-01-02", "2023-01-08", Granularity::Week),
+        ("FY2023", 10, "2022-10-01", "2023-09-30", Granularity::Year),
+        ("2023财年", 7, "2022-07-01", "2023-06-30", Granularity::Year),
+        ("FY2023", 0, "2023-01-01", "2023-12-31", Granularity::Year),
+    ];
+    for (sample, fiscal, start, end, granularity) in samples{
+        let r = parse_period(sample, Some(Param{fiscal, ..Default::default()}));
+        println!("period: {:<24} => {} ~ {}, {:?}", sample, r.start.time.date(), r.end.time.date(), r.granularity);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.start.time.date().to_string(), start, "{}", sample);
+        assert_eq!(r.end.time.date().to_string(), end, "{}", sample);
+        assert_eq!(r.granularity, granularity, "{}", sample);
+    }
+    for sample in ["2023", "Q5 2023", "week 54 of 2023", "2023-10-05", "hello world"]{
+        let r = parse_period(sample, None);
+        println!("period: {:<24} => status: {}", sample, r.status);
+        assert!(!r.status, "{}", sample);
+    }
+}
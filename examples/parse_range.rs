@@ -0,0 +1,43 @@
+use chrono::{Datelike, Local, Weekday};
+use osmanthus::parse_range;
+
+fn main() {
+    let year = Local::now().year();
+    // (样本, 期望的起始时间, 期望的结束时间)
+    let samples = vec![
+        ("2023-10-01 ~ 2023-10-05".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-05 00:00:00".to_string()),
+        ("2023-10-01-2023-10-05".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-05 00:00:00".to_string()),
+        ("2023-10-01 - 05".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-05 00:00:00".to_string()),
+        ("2023年10月1日至7日".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-07 00:00:00".to_string()),
+        ("2023年10月1日到11月7日".to_string(), "2023-10-01 00:00:00".to_string(), "2023-11-07 00:00:00".to_string()),
+        ("10月1日至7日".to_string(), format!("{}-10-01 00:00:00", year), format!("{}-10-07 00:00:00", year)),
+        ("2023年12月25日至1月3日".to_string(), "2023-12-25 00:00:00".to_string(), "2024-01-03 00:00:00".to_string()),
+        ("2023-10-05 09:00 - 17:30".to_string(), "2023-10-05 09:00:00".to_string(), "2023-10-05 17:30:00".to_string()),
+        ("2023-10-05 09:00–2023-10-06 18:00".to_string(), "2023-10-05 09:00:00".to_string(), "2023-10-06 18:00:00".to_string()),
+        ("from Oct 1, 2023 to Oct 5, 2023".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-05 00:00:00".to_string()),
+        ("Oct 1 until Oct 5, 2023".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-05 00:00:00".to_string()),
+        ("1 – 7 October 2023".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-07 00:00:00".to_string()),
+        ("October 1 - 7, 2023".to_string(), "2023-10-01 00:00:00".to_string(), "2023-10-07 00:00:00".to_string()),
+    ];
+    for (sample, start, end) in samples{
+        let r = parse_range(&sample, None);
+        println!("range: {:<40} => {} ~ {}, status: {}", sample, r.start.time, r.end.time, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.start.time.to_string(), start, "{}", sample);
+        assert_eq!(r.end.time.to_string(), end, "{}", sample);
+    }
+    // 星期范围｜起始时间为今天或之后最近的周一
+    for sample in ["from Mon to Fri", "周一至周五", "星期一到星期五"]{
+        let r = parse_range(sample, None);
+        println!("range: {:<40} => {} ~ {}, status: {}", sample, r.start.time, r.end.time, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.start.time.weekday(), Weekday::Mon, "{}", sample);
+        assert_eq!(r.end.time.weekday(), Weekday::Fri, "{}", sample);
+        assert_eq!((r.end.time - r.start.time).num_days(), 4, "{}", sample);
+    }
+    for sample in ["2023-10-05", "2023-10-05 ~ 2023-10-01", "tomorrow to next week"]{
+        let r = parse_range(sample, None);
+        println!("range: {:<40} => status: {}", sample, r.status);
+        assert!(!r.status, "{}", sample);
+    }
+}
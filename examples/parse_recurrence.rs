@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+use osmanthus::parse_recurrence;
+
+fn main() {
+    // 2023-10-05 是周四
+    let after = NaiveDate::from_ymd_opt(2023, 10, 5).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    // (样本, 期望的 RRULE, 期望的前三次发生时间)
+    let samples = vec![
+        ("every Monday at 9am", "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO;BYHOUR=9;BYMINUTE=0;BYSECOND=0", ["2023-10-09 09:00:00", "2023-10-16 09:00:00", "2023-10-23 09:00:00"]),
+        ("每月1号", "FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=1", ["2023-11-01 00:00:00", "2023-12-01 00:00:00", "2024-01-01 00:00:00"]),
+        ("daily at 08:00", "FREQ=DAILY;INTERVAL=1;BYHOUR=8;BYMINUTE=0;BYSECOND=0", ["2023-10-06 08:00:00", "2023-10-07 08:00:00", "2023-10-08 08:00:00"]),
+        ("每天下午3点半", "FREQ=DAILY;INTERVAL=1;BYHOUR=15;BYMINUTE=30;BYSECOND=0", ["2023-10-05 15:30:00", "2023-10-06 15:30:00", "2023-10-07 15:30:00"]),
+        ("every 2 weeks on Friday", "FREQ=WEEKLY;INTERVAL=2;BYDAY=FR", ["2023-10-06 00:00:00", "2023-10-20 00:00:00", "2023-11-03 00:00:00"]),
+        ("每周一至周五 9:30", "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TU,WE,TH,FR;BYHOUR=9;BYMINUTE=30;BYSECOND=0", ["2023-10-06 09:30:00", "2023-10-09 09:30:00", "2023-10-10 09:30:00"]),
+        ("weekends 10am-6pm", "FREQ=WEEKLY;INTERVAL=1;BYDAY=SA,SU;BYHOUR=10;BYMINUTE=0;BYSECOND=0", ["2023-10-07 10:00:00", "2023-10-08 10:00:00", "2023-10-14 10:00:00"]),
+        ("mondays and thursdays", "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TH", ["2023-10-09 00:00:00", "2023-10-12 00:00:00", "2023-10-16 00:00:00"]),
+        ("on the 15th of every month", "FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=15", ["2023-10-15 00:00:00", "2023-11-15 00:00:00", "2023-12-15 00:00:00"]),
+        ("每年10月1日", "FREQ=YEARLY;INTERVAL=1;BYMONTHDAY=1;BYMONTH=10", ["2024-10-01 00:00:00", "2025-10-01 00:00:00", "2026-10-01 00:00:00"]),
+        ("every other day", "FREQ=DAILY;INTERVAL=2", ["2023-10-07 00:00:00", "2023-10-09 00:00:00", "2023-10-11 00:00:00"]),
+        ("每隔三天", "FREQ=DAILY;INTERVAL=3", ["2023-10-08 00:00:00", "2023-10-11 00:00:00", "2023-10-14 00:00:00"]),
+        ("每周日", "FREQ=WEEKLY;INTERVAL=1;BYDAY=SU", ["2023-10-08 00:00:00", "2023-10-15 00:00:00", "2023-10-22 00:00:00"]),
+        ("every year on Feb 29", "FREQ=YEARLY;INTERVAL=1;BYMONTHDAY=29;BYMONTH=2", ["2024-02-29 00:00:00", "2028-02-29 00:00:00", "2032-02-29 00:00:00"]),
+        ("每月最后一天", "FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=-1", ["2023-10-31 00:00:00", "2023-11-30 00:00:00", "2023-12-31 00:00:00"]),
+    ];
+    for (sample, rrule, expected) in samples{
+        let r = parse_recurrence(sample, None);
+        let occurrences: Vec<String> = r.occurrences(after, 3).iter().map(|v| v.to_string()).collect();
+        println!("recurrence: {:<28} => {}, {:?}", sample, r.to_rrule(), occurrences);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.to_rrule(), rrule, "{}", sample);
+        assert_eq!(occurrences, expected, "{}", sample);
+    }
+    for sample in ["Monday at 9am", "Tues at 9am", "2023-10-05", "hello world"]{
+        let r = parse_recurrence(sample, None);
+        println!("recurrence: {:<28} => status: {}", sample, r.status);
+        assert!(!r.status, "{}", sample);
+    }
+}
@@ -0,0 +1,30 @@
+use osmanthus::parse_absolute;
+
+fn main() {
+    // (样本, 期望的 UTC 时间)
+    let samples = vec![
+        ("Thu, 05 Oct 2023 14:20:30 +0800", "2023-10-05 06:20:30"),
+        ("Thu, 05 Oct 2023 14:20:30 GMT", "2023-10-05 14:20:30"),
+        ("Thu, 5 Oct 2023 14:20:30 -0500", "2023-10-05 19:20:30"),
+        ("05 Oct 2023 14:20 +0000", "2023-10-05 14:20:00"),
+        ("Thu, 05 Oct 2023 14:20:30 -0700 (PDT)", "2023-10-05 21:20:30"),
+        ("Thu, 05 Oct 2023 14:20:30 EST", "2023-10-05 19:20:30"),
+        ("Thu, 05 Oct 2023 14:20:30 PDT", "2023-10-05 21:20:30"),
+        ("Thu, 05 Oct 2023 14:20:30 UT", "2023-10-05 14:20:30"),
+        ("Thu, 05 Oct 2023 14:20:30 Z", "2023-10-05 14:20:30"),
+        ("Thu, 05 Oct 23 14:20:30 +0800", "2023-10-05 06:20:30"),
+        ("Wed, 05 Oct 94 14:20:30 GMT", "1994-10-05 14:20:30"),
+        ("thu, 05 oct 2023 14:20:30 gmt", "2023-10-05 14:20:30"),
+        ("Thursday, 05-Oct-23 14:20:30 GMT", "2023-10-05 14:20:30"),
+        ("Sunday, 06-Nov-94 08:49:37 GMT", "1994-11-06 08:49:37"),
+        ("Thu, 05-Oct-2023 14:20:30 GMT", "2023-10-05 14:20:30"),
+        ("Thu Oct  5 14:20:30 2023", "2023-10-05 14:20:30"),
+        ("Sun Nov  6 08:49:37 1994", "1994-11-06 08:49:37"),
+    ];
+    for (sample, expected) in samples{
+        let r = parse_absolute(sample, None);
+        println!("rfc: {:<42} => {}, timezone: {:?}, status: {}", sample, r.datetime.timezone.datetime, r.timezone, r.status);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.datetime.timezone.datetime.to_string(), expected, "{}", sample);
+    }
+}
@@ -0,0 +1,30 @@
+use osmanthus::{parse, parse_absolute};
+use osmanthus::bind::Param;
+
+fn main() {
+    let samples = [
+        ("发布时间：2023-10-05 14:20 来源：新华网", "2023-10-05 14:20"),
+        ("  2023-10-05T14:20:00Z ", "2023-10-05T14:20:00Z"),
+        ("Posted on October 5th, 2023 at 3:30 pm by admin", "October 5th, 2023 at 3:30 pm"),
+        ("二〇二三年十月五日下午三点", "二〇二三年十月五日下午三点"),
+        ("会议定于2023年国庆节举行", "2023年国庆节"),
+        ("2023年10月5号 星期四", "2023年10月5号"),
+        ("127.0.0.1 - - [05/Oct/2023:14:20:00 +0800] \"GET /\"", "05/Oct/2023:14:20:00 +0800"),
+        ("updated 2023.10.05", "2023.10.05"),
+    ];
+    for (text, expected) in samples{
+        let r = parse_absolute(text, Some(Param::new().with_span(true)));
+        let (start, end) = r.span.expect(text);
+        println!("{} -> {:?}", text, &text[start..end]);
+        assert_eq!(&text[start..end], expected);
+    }
+    // 从标题中去掉时间
+    let title = "国庆假期安排 2023-10-01 更新";
+    let r = parse(title, Some(Param::new().with_span(true)));
+    let (start, end) = r.span.unwrap();
+    assert_eq!(format!("{}{}", &title[..start], &title[end..]), "国庆假期安排  更新");
+    // 未识别到时间
+    assert_eq!(parse_absolute("来源：新华网", Some(Param::new().with_span(true))).span, None);
+    // 未开启时不追踪原文位置
+    assert_eq!(parse_absolute(title, None).span, None);
+}
@@ -0,0 +1,25 @@
+use osmanthus::{parse, parse_absolute};
+use osmanthus::bind::Param;
+
+fn main() {
+    // 日月年的日期｜追踪中可以看到月和日的交换
+    let r = parse_absolute("06-07-2023 15:00", Some(Param{trace: true, ..Default::default()}));
+    for line in r.trace.iter(){
+        println!("{}", line);
+    }
+    assert_eq!(r.time.to_string(), "2023-07-06 15:00:00");
+    for stage in ["eliminate_noise", "unitize_text", "split_with_numeric", "eliminate_noise_normal", "reorder", "tokenize", "parse_token", "reload_datetime_with_force", "attach_timezone"]{
+        assert!(r.trace.iter().any(|v| v.starts_with(&format!("{}: ", stage))), "{}", stage);
+    }
+    assert!(r.trace.iter().any(|v| v.starts_with("parse_token: \"2023\" -> YEAR 2023")));
+    assert!(r.trace.iter().any(|v| v.starts_with("reload_datetime_with_force: ") && v.contains("swap=true")));
+    // 标准格式的快速解析｜记录偏移量
+    let r = parse_absolute("Thu, 05 Oct 2023 14:20:30 +0800", Some(Param{trace: true, ..Default::default()}));
+    for line in r.trace.iter(){
+        println!("{}", line);
+    }
+    assert!(r.trace.iter().any(|v| v.starts_with("attach_timezone: ") && v.contains("offset=28800s")));
+    // 未开启时不记录
+    let r = parse("2023-10-05 14:20", None);
+    assert!(r.trace.is_empty());
+}
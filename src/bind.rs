@@ -1,4 +1,5 @@
-use chrono::NaiveDateTime;
+use std::{collections::HashMap, sync::Arc};
+use chrono::{NaiveDateTime, NaiveTime, Weekday};
 
 #[derive(Debug, PartialEq, Default, Clone)]
 pub enum DateTimeLabel {
@@ -13,8 +14,24 @@ pub struct Result{
     pub time: NaiveDateTime,
     pub datetime: DateTime,
     pub timezone: String,
+    pub validity: DateValidity,
+    pub calendar: EraBasedCalendar,  // 原文所用的历法｜公历时为 Non
+    pub format: String,  // 匹配的格式提示｜未提供或均未匹配时为空
+    pub holiday: String,  // 文本中的节日名称｜例如 国庆节 Christmas Eve 未出现节日时为空
+    pub trace: Vec<String>,  // 解析追踪｜仅在开启 Param.trace 时记录 每一项为 阶段: 中间结果或决策
+    pub span: Option<(usize, usize)>,  // 时间文本在输入中的字节范围 [start, end)｜仅在开启 Param.span 时由绝对时间解析提供 未识别时为 None
 }
 
+/// 日期合法性
+/// Unrecognized 未识别出日期
+/// Valid 合法日期
+/// Invalid 识别出年月日但日期不存在 例如 2023-02-30
+/// Rolled 宽松模式下溢出的天数顺延后得到的日期 例如 2023-02-30 -> 2023-03-02
+#[derive(Default, PartialEq, Debug, Clone)]
+pub enum DateValidity {
+    #[default]
+    Unrecognized, Valid, Invalid, Rolled}
+
 #[derive(Debug, Default)]
 pub struct Parse{
     pub param: Param
@@ -62,6 +79,17 @@ pub struct ResultDateText{
     pub text: String,
 }
 
+/// 标准格式的解析结果
+/// offset 文本中的时区偏移量(秒)｜未标注时为 None
+/// span 时间在去掉首尾空白后的文本中的字节范围｜整个文本都是时间时为 None
+#[derive(Debug, Default)]
+pub struct ResultStandard{
+    pub time: NaiveDateTime,
+    pub validity: DateValidity,
+    pub offset: Option<i32>,
+    pub span: Option<(usize, usize)>,
+}
+
 #[derive(Debug, Default)]
 pub struct TimeMark{
     pub year: DateTimeItem,
@@ -90,15 +118,26 @@ pub struct ParseAbsolute{
     pub time: NaiveDateTime,
     pub era: EraBasedCalendar,
     pub token: Vec<Token>,
+    pub validity: DateValidity,  // 预处理阶段的合法性｜例如超出年号起止的日本年号日期
+    pub holiday: String,  // 预处理阶段识别出的节日名称
+    pub trace: Vec<String>,  // 解析追踪
+    pub origin: Vec<(usize, usize)>,  // 当前文本逐字符对应的原文字节范围
+    pub spans: Vec<(usize, usize)>,  // TOKEN 对应的原文字节范围｜与 token 一一对应
 }
 
 /// 基于纪元年号的年份
-/// Reiwa 日本令和
-/// Thai 泰历
-#[derive(Default, PartialEq, Debug)]
+/// Reiwa 日本年号｜明治至令和 已在预处理阶段换算为公历
+/// Thai 泰历｜基于泰语月份或泰文推断
+/// Buddhist 佛历｜文本中明确标注了 พ.ศ.
+/// Gregorian 公历｜文本中明确标注了 ค.ศ.
+/// Minguo 民国纪年｜文本中明确标注了 民國 或者开启了民国纪年模式
+/// Jalali 伊朗历｜基于伊朗历月份、波斯数字或者 هجری شمسی 推断
+/// Hijri 伊斯兰历｜已在预处理阶段换算为公历
+/// Lunar 农历｜已在预处理阶段换算为公历
+#[derive(Default, PartialEq, Debug, Clone)]
 pub enum EraBasedCalendar {
     #[default]
-    Non, Reiwa, Thai}
+    Non, Reiwa, Thai, Buddhist, Gregorian, Minguo, Jalali, Hijri, Lunar}
 
 #[derive(Debug, Default)]
 pub struct ParseRelative{
@@ -109,10 +148,109 @@ pub struct ParseRelative{
 pub struct Param{
     // pub era: String, // 纪元年份
     pub timezone: String,  // 时区
-    pub strict: bool  // 严格模式
+    pub strict: bool,  // 严格模式
+    pub lenient: bool,  // 宽松模式｜溢出的天数顺延至下个月
+    pub minguo: bool,  // 民国纪年模式｜无纪元标志的三位数年份按民国纪年换算 适用于台湾地区来源
+    pub formats: Vec<String>,  // 格式提示｜chrono strftime 格式 按顺序优先尝试 均未匹配时再使用通用流程
+    pub fiscal: u32,  // 财年起始月份｜0 和 1 为自然年 大于 12 时 parse_period 不做解析 财年以结束时所在的年份命名 例如 10 表示 FY2023 为 2022-10-01 至 2023-09-30
+    pub trace: bool,  // 解析追踪｜记录绝对时间解析各阶段的中间文本、TOKEN 和决策 结果见 Result.trace
+    pub span: bool,  // 原文范围｜追踪时间文本在输入中的位置 结果见 Result.span 规整文本时需要额外的对齐开销
+    pub lexicon: Option<Arc<Lexicon>>,  // 词库｜None 时使用内置词库
 }
 
 
+/// 时间范围｜start 为起始时间 end 为结束时间 缺省的部分已从另一端继承
+/// granularity 时间段的粒度 例如 Q3 2023 为 Quarter｜普通的时间范围为 Non
+#[derive(Debug, Default)]
+pub struct ResultRange{
+    pub status: bool,
+    pub start: Result,
+    pub end: Result,
+    pub granularity: Granularity,
+}
+
+/// 时间段的粒度｜周为 ISO 8601 周 季度、半年和年按财年起始月份计算
+#[derive(Default, PartialEq, Debug, Clone)]
+pub enum Granularity {
+    #[default]
+    Non, Week, Quarter, Half, Year}
+
+/// 时长｜年和月的天数不固定 因此与天数、秒数分开记录 周已换算为天
+/// method 识别方式 iso8601 clock text
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ResultDuration{
+    pub status: bool,
+    pub method: String,
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+    pub seconds: f64,
+}
+
+/// 重复频率｜对应 RFC 5545 的 FREQ
+#[derive(Default, PartialEq, Debug, Clone)]
+pub enum Frequency {
+    #[default]
+    Non, Daily, Weekly, Monthly, Yearly}
+
+/// 重复规则｜参照 RFC 5545 的 RRULE
+/// interval 间隔 例如每两周为 2
+/// by_day 星期 by_month_day 每月的第几天 负数从月末倒数 例如 -1 为最后一天 by_month 月份
+/// time 发生的时间 未指定时为 None
+#[derive(Debug, Default, Clone)]
+pub struct ResultRecurrence{
+    pub status: bool,
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub time: Option<NaiveTime>,
+}
+
+/// 节日的日期规则
+/// Fixed 公历的固定日期 (月, 日) 例如圣诞节
+/// Weekday 某月的第 N 个星期几 (月, 星期, N) N 为 -1 时为最后一个 例如感恩节为 11 月的第 4 个周四
+/// Lunar 农历日期 (月, 日) 例如中秋节为八月十五
+/// Easter 复活节 即春分月圆后的第一个周日
+#[derive(Debug, PartialEq, Clone)]
+pub enum HolidayRule {
+    Fixed(u32, u32), Weekday(u32, Weekday, i32), Lunar(u32, u32), Easter}
+
+/// 节日｜offset 相对于规则日期的偏移天数 例如除夕为春节的前一天 黑色星期五为感恩节的后一天
+#[derive(Debug, PartialEq, Clone)]
+pub struct Holiday{
+    pub rule: HolidayRule,
+    pub offset: i64,
+}
+
+/// 词库｜月份、相对时间、星期、午线和时区偏移量 名称均为小写
+/// Lexicon::new() 为内置词库的副本 Lexicon::default() 为空词库
+/// relative_order 相对时间的匹配顺序｜同时命中多个时靠后的优先
+#[derive(Debug, Default, Clone)]
+pub struct Lexicon{
+    pub month: HashMap<String, String>,  // 月份名称 -> 英文月份 例如 enero -> january
+    pub relative: HashMap<String, String>,  // 相对时间 -> 英文表述 例如 hace un rato -> minute ago
+    pub relative_order: Vec<String>,
+    pub weekday: HashMap<String, String>,  // 星期名称 -> 英文星期 例如 lunes -> monday
+    pub meridian: HashMap<String, String>,  // 上下午 -> am 或 pm
+    pub timezone: HashMap<String, i32>,  // 时区缩写 -> 偏移量(秒)
+    pub holiday: HashMap<String, Holiday>,  // 节日名称 -> 日期规则 例如 双十一 -> Fixed(11, 11)
+}
+
+/// 从样本中推断出的时间格式
+/// format 与 chrono strftime 兼容的格式 也可以直接用作 Param.formats 的格式提示
+/// order 日期字段的顺序 例如 ymd dmy mdy
+/// timezone 文本中是否带有时区偏移量
+/// samples 与格式相符的样本数量
+#[derive(Debug, Default, Clone)]
+pub struct Layout{
+    pub format: String,
+    pub order: String,
+    pub timezone: bool,
+    pub samples: usize,
+}
+
 #[derive(Debug, Default)]
 pub struct DateTime{
     pub local: Item,
@@ -2,28 +2,40 @@ use chrono::Duration;
 use chrono::prelude::*;
 use crate::bind::*;
 use crate::core::interfaces::{Parse};
-use crate::core::corpus::{unitize_date_text, search_meridian, unitize_month_numeric, get_offset_local_utc, unitize_spacial_express_time, search_era_japan, search_with_utc_pattern, search_dubious_date, unitize_timezone_with_text};
+use crate::core::standard::{parse_iso8601_fast, parse_rfc, parse_log, parse_format};
+use crate::core::corpus::{get_lexicon, unitize_date_text, search_meridian, unitize_month_numeric, get_offset_local_utc, unitize_spacial_express_time, search_era_japan, search_with_utc_pattern, search_dubious_date, unitize_timezone_with_text, search_hijri_date, search_lunar_date, has_gregorian_year, has_persian_digit, search_era_marker, search_holiday, search_holiday_year, has_holiday_date, has_holiday_time};
 use crate::utils::{eliminate_noise, unitize_text, split_with_numeric,
                    section_with_space, search_offset_with_text, is_number,
-                   create_datetime, create_timestamp, str_convert,
-                   eliminate_symbol_point, tokenize, reorder_text_meridian};
+                   create_datetime, create_date, create_timestamp, str_convert,
+                   eliminate_symbol_point, tokenize, reorder_text_meridian, unitize_numeral, unitize_digit, create_date_hijri, create_date_jalali, create_date_lunar, create_date_holiday,
+                   create_origin, align_origin};
 
 const MAX_NUMBER_MONTH: u32 = 12;
 const MAX_NUMBER_DAY: u32 = 31;
-const MAX_NUMBER_HOURS: u32 = 24;
-const MAX_NUMBER_MINUTES: u32 = 60;
-const MAX_NUMBER_SECONDS: u32 = 60;
 const MIN_NUMBER_YEAR: u32 = 1970;
 const MAX_NUMBER_YEAR: u32 = 9999;
 const MIN_NUMBER_YEAR_THAI: i32 = 2513;  // 泰历相对于公历多 543 年因此最小的泰历时间: 1970 + 543
 const DIFF_NUMBER_YEAR_THAI: u32 = 543;
+const MIN_NUMBER_YEAR_JALALI: u32 = 1349;  // 伊朗历 1349 年即公历 1970 年
+const DIFF_NUMBER_YEAR_MINGUO: u32 = 1911;  // 民国元年即 1912 年
+const MAX_NUMBER_YEAR_MINGUO: u32 = 999;
 const MAX_LEN_TIME_TEXT: usize = 5;  // 11:02
+const TEXT_SUFFIX_SPAN: [&str; 16] = ["年", "月", "日", "号", "號", "时", "時", "点", "點", "分", "秒", "년", "월", "일", "시", "초"];
+const TEXT_ORDINAL_SPAN: [&str; 4] = ["st", "nd", "rd", "th"];
 
 
 impl Parse for ParseAbsolute{
     fn parse(&mut self, text: &str) -> Result{
+        if let Some(r) = self.parse_hint(text){
+            return r
+        }
+        if let Some(mut r) = self.parse_standard(text){
+            self.assemble(&mut r);
+            return r
+        }
         let item = self.pretreatment(text);
         let mut r = self.work(&item);
+        r.span = r.span.map(|v| extend_span(text, v));
         self.assemble(&mut r);
         return r
     }
@@ -38,9 +50,77 @@ impl ParseAbsolute{
         return ParseAbsolute{..Default::default()}
     }
 
-    fn assemble(&self, item: &mut Result){
+    /// 按格式提示解析｜均未匹配时返回 None
+    pub fn parse_hint(&mut self, text: &str) -> Option<Result>{
+        let lexicon = get_lexicon(&self.param);
+        let (format, item) = parse_format(text, &self.param.formats, &lexicon)?;
+        self.record("parse_hint", || format!("matched {:?}", format));
+        let span = locate_span_standard(text, item.span);
+        let mut r = self.assemble_standard(item);
+        r.format = format;
+        r.span = (r.status && self.param.span).then_some(span);
+        self.assemble(&mut r);
+        Some(r)
+    }
+
+    /// 标准格式的快速解析｜ISO 8601、RFC 2822/5322、HTTP 日期和日志时间
+    /// 匹配时不再经过通用流程
+    fn parse_standard(&mut self, text: &str) -> Option<Result>{
+        let lenient = self.param.lenient;
+        let lexicon = get_lexicon(&self.param);
+        let item = parse_iso8601_fast(text, lenient).or_else(|| parse_rfc(text, lenient)).or_else(|| parse_log(text, lenient, &lexicon))?;
+        self.record("parse_standard", || format!("{} offset={:?}", item.time, item.offset));
+        let span = locate_span_standard(text, item.span);
+        let mut r = self.assemble_standard(item);
+        r.span = (r.status && self.param.span).then_some(span);
+        Some(r)
+    }
+
+    /// 将快速解析或格式提示得到的时间组装为结果｜文本中的偏移量仅在未指定时区时生效
+    fn assemble_standard(&mut self, item: ResultStandard) -> Result{
+        let mut data = Result { method: String::from("absolute"), validity: item.validity, ..Default::default()};
+        if self.param.timezone.is_empty(){
+            if let Some(offset) = item.offset{
+                self.offset = offset;
+                self.param.timezone = if offset == 0 {"utc".to_string()} else {"other".to_string()};
+            }
+        }else if let Some(n) = unitize_timezone_with_text(self.param.timezone.as_str(), &get_lexicon(&self.param)){
+            self.offset = *n;
+        }
+        data.timezone = self.param.timezone.clone();
+        if data.validity == DateValidity::Valid || data.validity == DateValidity::Rolled{
+            data.time = item.time;
+            if self.validate(item.time){
+                data.status = true;
+            }else{
+                data.validity = DateValidity::Unrecognized;
+            }
+        }
+        data
+    }
+
+    fn assemble(&mut self, item: &mut Result){
         self.attach_era(item);
         self.attach_timezone(item);
+        item.holiday = self.holiday.clone();
+        let (era, timezone, offset, utc) = (item.calendar.clone(), item.timezone.clone(), self.offset, item.datetime.timezone.datetime);
+        self.record("attach_timezone", || format!("calendar={:?} timezone={:?} offset={}s utc={}", era, timezone, offset, utc));
+        item.trace = std::mem::take(&mut self.trace);
+    }
+
+    /// 记录解析追踪｜仅在开启 Param.trace 时生成内容 避免影响常规解析的性能
+    fn record<F: FnOnce() -> String>(&mut self, stage: &str, detail: F){
+        if self.param.trace{
+            self.trace.push(format!("{}: {}", stage, detail()));
+        }
+    }
+
+    /// 追踪原文位置｜每次改写文本后调用 仅在开启 Param.span 时对齐
+    fn track(&mut self, before: &str, after: &str){
+        if !self.param.span{
+            return
+        }
+        self.origin = align_origin(before, after, &self.origin);
     }
 
     fn attach_era(&self, item: &mut Result){
@@ -51,15 +131,44 @@ impl ParseAbsolute{
                     if let Some(di) = item.time.with_year(item.time.year()-DIFF_NUMBER_YEAR_THAI as i32){
                         item.time = di;
                     }
+                }else{
+                    // 不符合泰历年份时按公历处理
+                    item.calendar = EraBasedCalendar::Non;
+                }
+            }
+            EraBasedCalendar::Buddhist => {
+                // 明确标注佛历时无需判断年份范围
+                if let Some(di) = item.time.with_year(item.time.year()-DIFF_NUMBER_YEAR_THAI as i32){
+                    item.time = di;
                 }
             }
             _=> {}
         }
     }
 
+    /// 是否为公历年份｜严格模式仅对公历年份限定上限
+    /// 民国纪年在解析数字时已换算为公历
+    /// 日本年号、伊斯兰历和农历在预处理阶段已换算为公历
+    fn is_gregorian(&self) -> bool{
+        matches!(self.era, EraBasedCalendar::Non | EraBasedCalendar::Gregorian | EraBasedCalendar::Minguo |
+                           EraBasedCalendar::Reiwa | EraBasedCalendar::Hijri | EraBasedCalendar::Lunar)
+    }
+
 
     /// 预处理
     fn pretreatment(&mut self, text: &str) -> String{
+        // 波斯数字通常意味着伊朗历
+        if has_persian_digit(text){
+            self.era = EraBasedCalendar::Jalali;
+        }
+        if self.param.span{
+            self.origin = create_origin(text);
+        }
+        let original = text;
+        let text = unitize_digit(text);
+        let text = text.as_str();
+        self.track(original, text);
+        self.record("unitize_digit", || text.to_string());
         // 时区和偏移量
         if self.param.timezone == "".to_string(){
             if let Some(offset) = self.search_timezone_with_offset(text){
@@ -71,18 +180,139 @@ impl ParseAbsolute{
                     0 => {self.param.timezone = "utc".to_string();}
                     _ => {self.param.timezone = "other".to_string();}
                 }
+                self.record("search_timezone_with_offset", || format!("offset={}s", offset));
             }
         }else{
-            if let Some(n) = unitize_timezone_with_text(self.param.timezone.as_str()){
+            if let Some(n) = unitize_timezone_with_text(self.param.timezone.as_str(), &get_lexicon(&self.param)){
                 self.offset = *n;
             }
         }
+        // 农历日期的处理｜需在汉字数字之前 例如 腊月廿三
+        let temp = self.unitize_era_lunar(text);
+        self.track(text, &temp);
+        self.record("unitize_era_lunar", || temp.clone());
+        // 汉字数字的处理
+        let numeral: String = unitize_numeral(&temp);
+        self.track(&temp, &numeral);
+        self.record("unitize_numeral", || numeral.clone());
+        // 节日的处理｜需在汉字数字之后 例如 二〇二三年国庆节
+        let holiday = self.unitize_holiday(&numeral);
+        self.track(&numeral, &holiday);
+        self.record("unitize_holiday", || holiday.clone());
         // 特殊时间表达式的处理
-        let mut temp: String = unitize_spacial_express_time(text);
-        temp = search_era_japan(&temp);
+        let temp = unitize_spacial_express_time(&holiday);
+        self.track(&holiday, &temp);
+        self.record("unitize_spacial_express_time", || temp.clone());
+        let (converted, validity) = search_era_japan(&temp, self.param.lenient);
+        self.track(&temp, &converted);
+        self.record("search_era_japan", || format!("{} validity={:?}", converted, validity));
+        if converted != temp{
+            self.era = EraBasedCalendar::Reiwa;
+        }
+        if validity != DateValidity::Unrecognized{
+            self.validity = validity;
+        }
+        let temp = self.unitize_era_hijri(&converted);
+        self.track(&converted, &temp);
+        let era = self.era.clone();
+        self.record("unitize_era_hijri", || format!("{} era={:?}", temp, era));
         return temp
     }
 
+    /// 农历日期的处理
+    /// 将农历日期换算为公历 例如 农历八月十五 正月初一 癸卯年腊月廿三
+    /// 缺省年份时使用当前年份｜文本中已有完整的公历日期时只移除农历日期 不切换历法 例如 2023年10月5日 农历八月廿一
+    fn unitize_era_lunar(&mut self, text: &str) -> String{
+        if let Some((start, end, year, month, day, leap)) = search_lunar_date(text){
+            let head = &text[..start];
+            let tail = &text[end..];
+            if has_holiday_date(head) || has_holiday_date(tail){
+                return head.to_string() + " " + tail
+            }
+            let year = year.unwrap_or(Local::now().year());
+            match create_date_lunar(year, month, day, leap, self.param.lenient){
+                (Some(date), _) => {
+                    self.era = EraBasedCalendar::Lunar;
+                    return head.to_string() + " " + date.format("%Y-%m-%d").to_string().as_str() + " " + tail
+                }
+                // 超出农历数据范围的年份为 Unrecognized 数据范围内不存在的日期为 Invalid
+                (None, validity) => {
+                    self.validity = validity;
+                    return head.to_string() + " " + tail
+                }
+            }
+        }
+        text.to_string()
+    }
+
+    /// 节日的处理
+    /// 将节日换算为公历日期 例如 2023年国庆节 -> 2023-10-01 Christmas Eve 2023 -> 2023-12-24
+    /// 文本中已有完整日期时只移除节日名称 例如 2023-10-01 国庆节、2023年10月5日 我们在国庆节见
+    /// 缺省年份时需要有时刻才视为日期 并使用当前年份 例如 国庆节晚上8点｜国庆节快乐 released on Christmas 不是日期
+    fn unitize_holiday(&mut self, text: &str) -> String{
+        if let Some((start, end, holiday)) = search_holiday(text, &get_lexicon(&self.param)){
+            let mut head = text[..start].to_string();
+            let mut tail = text[end..].to_string();
+            if has_holiday_date(&head) || has_holiday_date(&tail){
+                self.holiday = text[start..end].to_string();
+                return head + " " + &tail
+            }
+            let mut year = None;
+            for part in [&mut head, &mut tail]{
+                if let Some((i, j, v)) = search_holiday_year(part){
+                    *part = part[..i].to_string() + " " + &part[j..];
+                    year = Some(v);
+                    break
+                }
+            }
+            if year.is_none() && has_gregorian_year(&(head.clone() + " " + &tail)){
+                self.holiday = text[start..end].to_string();
+                return head + " " + &tail
+            }
+            if year.is_none() && !has_holiday_time(&(head.clone() + " " + &tail)){
+                return text.to_string()
+            }
+            self.holiday = text[start..end].to_string();
+            match create_date_holiday(&holiday, year.unwrap_or(Local::now().year())){
+                Some(date) => return head + " " + date.format("%Y-%m-%d").to_string().as_str() + " " + &tail,
+                None => {
+                    self.validity = DateValidity::Invalid;
+                    return head + " " + &tail
+                }
+            }
+        }
+        text.to_string()
+    }
+
+    /// 伊斯兰历日期的处理
+    /// 同时出现公历日期时优先使用公历 例如 الخميس 20 ربيع الأول 1445 هـ الموافق 5 أكتوبر 2023
+    /// 否则将伊斯兰历日期换算为公历
+    fn unitize_era_hijri(&mut self, text: &str) -> String{
+        if search_era_marker(text) == Some(EraBasedCalendar::Jalali){
+            return text.to_string()
+        }
+        if let Some((start, end, year, month, day)) = search_hijri_date(text){
+            let head = &text[..start];
+            let tail = &text[end..];
+            let rest = head.to_string() + " " + tail;
+            if has_gregorian_year(&rest){
+                return rest
+            }
+            match create_date_hijri(year, month, day, self.param.lenient){
+                (Some(date), validity) => {
+                    self.era = EraBasedCalendar::Hijri;
+                    self.validity = validity;
+                    return head.to_string() + " " + date.format("%Y-%m-%d").to_string().as_str() + " " + tail
+                }
+                (None, validity) => {
+                    self.validity = validity;
+                    return rest
+                }
+            }
+        }
+        text.to_string()
+    }
+
     fn search_timezone_with_offset(&mut self, text: &str) -> Option<i32>{
         if let Some(offset) = self.search_offset_with_patterns(text){
             return Some(offset)
@@ -91,7 +321,7 @@ impl ParseAbsolute{
     }
 
     fn search_offset_with_patterns(&self, text: &str) -> Option<i32>{
-        for name in vec!["TZZeroOffset", "TZOffset", "TZZero"]{
+        for name in ["TZZeroOffset", "TZOffset", "TZZero"]{
             if let Some(offset) = self.find_timezone_with_captures(text, name){
                 return Some(offset)
             }
@@ -163,21 +393,17 @@ impl ParseAbsolute{
                 // LOCAL=UTC+DIFF
                 item.datetime.local.datetime = item.datetime.timezone.datetime + Duration::seconds(get_offset_local_utc());
                 item.datetime.local.timestamp = create_timestamp(item.datetime.local.datetime);
-                // println!("local: {:?}", item.datetime.local.datetime);
                 return
             }
             _ =>{
                 // 指定时区非协调时区
                 // 其他时区转换为协调时区
-                // println!("附加时区前: {:?}", item.time);
                 let temp = item.time - Duration::seconds(self.offset as i64);
                 item.datetime.timezone.datetime = temp;
                 item.datetime.timezone.timestamp = create_timestamp(temp);
-                // println!("附加时区后｜协调时区时间: {:?}", temp);
                 // LOCAL=UTC+DIFF
                 item.datetime.local.datetime = item.datetime.timezone.datetime + Duration::seconds(get_offset_local_utc());
                 item.datetime.local.timestamp = create_timestamp(item.datetime.local.datetime);
-                // println!("协调时区转本地时区: {:?}", item.datetime.local.datetime);
                 return
             }
         }
@@ -206,6 +432,7 @@ impl ParseAbsolute{
     /// 既不是数字也不在有效字符范围内的用空字符串代替
     fn eliminate_noise_normal(&self,section:Vec<String>) -> Vec<String>{
         let mut res: Vec<String> = Vec::new();
+        let lexicon = get_lexicon(&self.param);
         for text in &section{
             if is_number(text.as_str()){
                 res.push(text.to_string());
@@ -214,7 +441,7 @@ impl ParseAbsolute{
             if self.skip_unitize_date_text(&section, text){
                 continue
             }
-            if let Some(value) = unitize_date_text(text.as_str()){
+            if let Some(value) = unitize_date_text(text.as_str(), &lexicon){
                 res.push(value);
                 continue
             }
@@ -266,7 +493,8 @@ impl ParseAbsolute{
     }
 
     /// 正式解析
-    fn parse_token(&self) -> (NaiveDateTime, bool){
+    /// 返回 (时间, 合法性, 原文位置)
+    fn parse_token(&mut self) -> (NaiveDateTime, DateValidity, Option<(usize, usize)>){
         let mut mark = TimeMark{..Default::default()};
         let mut param_utc = false;
         if self.param.timezone != "".to_string() || self.offset != 0{
@@ -275,35 +503,118 @@ impl ParseAbsolute{
         let mut month_force = false;
         let mut padding_order: Vec<String> = Vec::new();
         let mut datetime: NaiveDateTime = create_datetime(true, param_utc);
+        let mut notes: Vec<String> = Vec::new();
+        // 年月日和时间来自的 TOKEN 下标｜用于计算原文位置
+        let mut used: [Option<usize>; 4] = [None; 4];
         for (index, item) in self.token.iter().enumerate(){
+            let before = (mark.year.status, mark.month.status, mark.month.value, mark.day.status);
             match item.label{
                 DateTimeLabel::Numeric =>{
                     // 时间类数据
                     if item.text.contains(":") && !mark.time.status && item.text.chars().count() > 2{
                         (datetime, mark.time.status) = self.parse_time_text(index, item, &self.token, datetime);
+                        if mark.time.status{
+                            used[3] = Some(index);
+                        }
+                        if self.param.trace{
+                            notes.push(format!("{:?} -> TIME {}", item.text, datetime.time()));
+                        }
                     }
                     // 数字型日期类数据
                     let (number, datetime_type, force) = self.parse_number(item);
+                    if self.param.trace{
+                        notes.push(format!("{:?} -> {:?} {} force={}", item.text, datetime_type, number, force));
+                    }
                     padding_order.push(self.get_order(&datetime_type));
-                    datetime = self.reload_datetime(datetime, number, datetime_type, &mut mark, item.label.clone(), force);
+                    self.reload_mark(number, datetime_type, &mut mark, item.label.clone(), force);
                 }
                 DateTimeLabel::Characters => {
                     // 字符型日期类数据
                     let (number, datetime_type, force) = self.parse_alpha(item);
+                    if self.param.trace{
+                        notes.push(format!("{:?} -> {:?} {} force={}", item.text, datetime_type, number, force));
+                    }
                     if datetime_type == DateType::MONTH && force{
                         month_force = force;
                     }
                     padding_order.push(self.get_order(&datetime_type));
-                    datetime = self.reload_datetime(datetime, number, datetime_type, &mut mark, item.label.clone(), force);
+                    self.reload_mark(number, datetime_type, &mut mark, item.label.clone(), force);
                 }
                 _ => {}
             }
+            if mark.year.status && !before.0{
+                used[0] = Some(index);
+            }
+            // 月份名称强行设定月份时 原有的月份移为天
+            if mark.day.status && !before.3{
+                used[2] = if before.1 && item.label == DateTimeLabel::Characters {used[1]} else {Some(index)};
+            }
+            if mark.month.status && (!before.1 || mark.month.value != before.2){
+                used[1] = Some(index);
+            }
         }
-        if mark.year.status && mark.month.status && mark.day.status && self.validate(datetime){
-            let d = self.reload_datetime_with_force(month_force, datetime, padding_order);
-            return (d, true)
+        for note in notes{
+            self.record("parse_token", || note);
+        }
+        self.record("mark", || format!("year={:?} month={:?} day={:?} time={}", mark.year.status.then_some(mark.year.value), mark.month.status.then_some(mark.month.value), mark.day.status.then_some(mark.day.value), mark.time.status));
+        if !(mark.year.status && mark.month.status && mark.day.status){
+            return (datetime, DateValidity::Unrecognized, None)
+        }
+        let span = self.search_span_used(&used);
+        // 年月日齐全后统一校验日期合法性｜区分非法日期和非日期
+        let order: String = padding_order.concat().replace(' ', "");
+        let (month, day) = self.reload_datetime_with_force(month_force, mark.month.value, mark.day.value, padding_order);
+        let swap = month != mark.month.value;
+        self.record("reload_datetime_with_force", || format!("order={:?} force={} swap={} month={} day={}", order, month_force, swap, month, day));
+        let year = mark.year.value as i32;
+        let (date, validity) = match self.era{
+            // 伊朗历年份换算为公历｜公历年份则按公历处理
+            EraBasedCalendar::Jalali if year < MIN_NUMBER_YEAR as i32 => create_date_jalali(year, month, day, self.param.lenient),
+            _ => create_date(year, month, day, self.param.lenient)
+        };
+        if let Some(di) = date{
+            datetime = NaiveDateTime::new(di, datetime.time());
+            if self.validate(datetime){
+                return (datetime, validity, span)
+            }
+            return (datetime, DateValidity::Unrecognized, None)
+        }
+        (datetime, validity, None)
+    }
+
+    /// 参与解析的 TOKEN 在原文中的范围｜时间两侧的上下午一并计入
+    fn search_span_used(&self, used: &[Option<usize>]) -> Option<(usize, usize)>{
+        if self.spans.len() != self.token.len(){
+            return None
+        }
+        let mut indexes: Vec<usize> = used.iter().flatten().cloned().collect();
+        if let Some(index) = used[3]{
+            for sibling in [index + 1, index.wrapping_sub(1)]{
+                if self.token.get(sibling).map(|v| search_meridian(&v.text).is_some()).unwrap_or(false){
+                    indexes.push(sibling);
+                    break
+                }
+            }
+        }
+        union_span(indexes.iter().map(|i| self.spans[*i]))
+    }
+
+    /// TOKEN 在原文中的范围｜item 从当前文本的第 start 个字符开始
+    fn search_span_token(&self, item: &str, start: usize) -> Vec<(usize, usize)>{
+        let mut data: Vec<(usize, usize)> = Vec::new();
+        if !self.param.span{
+            return data
+        }
+        let mut position = start;
+        for token in tokenize(item){
+            let length = token.text.chars().count();
+            if token.label != DateTimeLabel::Invalid{
+                let span = self.origin.get(position..position + length).and_then(|v| union_span(v.iter().cloned()));
+                data.push(span.unwrap_or((0, 0)));
+            }
+            position += length;
         }
-        return (datetime, false)
+        data
     }
 
     fn get_order(&self, datetime_type: &DateType) -> String{
@@ -319,12 +630,10 @@ impl ParseAbsolute{
         return String::from(" ")
     }
 
-    /// 基于是否强月份和填充顺序更新时间
+    /// 基于是否强月份和填充顺序更新月和日
     /// 主要是处理 06-07-2023 这类日月年时间格式
     /// 将其转化为 2023-07-06 年月日
-    fn reload_datetime_with_force(&self, force: bool, datetime: NaiveDateTime, padding_order: Vec<String>) -> NaiveDateTime{
-        let day = datetime.day();
-        let month = datetime.month();
+    fn reload_datetime_with_force(&self, force: bool, month: u32, day: u32, padding_order: Vec<String>) -> (u32, u32){
         let mut order: String = String::from("");
         for i in padding_order{
             if i.trim().is_empty(){
@@ -333,13 +642,9 @@ impl ParseAbsolute{
             order.push_str(i.as_str());
         }
         if day <= MAX_NUMBER_MONTH && month <= MAX_NUMBER_MONTH && !force && order == "mmy"{
-            if let Some(di) = datetime.with_month(day){
-                if let Some(d) = di.with_day(month){
-                    return d
-                }
-            }
+            return (day, month)
         }
-        return datetime
+        (month, day)
     }
 
     fn search_sibling_meridian(&self, index: usize, token: &Vec<Token>) -> Option<String>{
@@ -363,30 +668,15 @@ impl ParseAbsolute{
 
 
     /// 严格模式和限制模式的校验
-    /// 校验日期数值合法性
+    /// 校验年份范围｜月份天数的校验在生成日期时完成
     fn validate(&self, datetime: NaiveDateTime) -> bool{
         if datetime.year() < MIN_NUMBER_YEAR as i32{
             return false
         }
-        if datetime.month() > MAX_NUMBER_MONTH{
-            return false
-        }
-        if  datetime.day() > MAX_NUMBER_DAY{
-            return false
-        }
-        if datetime.hour() > MAX_NUMBER_HOURS{
-            return false
-        }
-        if datetime.minute() > MAX_NUMBER_MINUTES{
-            return false
-        }
-        if datetime.second() > MAX_NUMBER_SECONDS{
-            return false
-        }
         if self.param.strict{
             let reference = create_datetime(false, false);
             let di = datetime + Duration::seconds(self.offset as i64);
-            if reference < di && self.era == EraBasedCalendar::Non{
+            if reference < di && self.is_gregorian(){
                 return false
             }
         }
@@ -414,69 +704,55 @@ impl ParseAbsolute{
         return (datetime, status_mark_time)
     }
 
-    /// 刷新时间对象
-    fn reload_datetime(&self, datetime: NaiveDateTime, number: u32, datetime_type: DateType, mark: &mut TimeMark, label: DateTimeLabel, force: bool) -> NaiveDateTime{
+    /// 刷新时间标记
+    /// 只记录年月日的数值｜日期合法性在标记完成后统一校验
+    /// 避免 with_day/with_month 因先后顺序失败而残留旧值
+    fn reload_mark(&self, number: u32, datetime_type: DateType, mark: &mut TimeMark, label: DateTimeLabel, force: bool){
         match datetime_type{
             DateType::YEAR => {
-                if let Some(d) = datetime.with_year(number as i32){
-                    if mark.year.status{
-                        return datetime
-                    }
-                    mark.year.status = true;
-                    mark.year.label = label;
-                    mark.year.value = number;
-                    return d
+                if mark.year.status{
+                    return
                 }
+                mark.year.status = true;
+                mark.year.label = label;
+                mark.year.value = number;
             }
             DateType::MONTH => {
                 // 月份｜值和类型确定的情况下可以强行设定
-                if force{
-                    if let Some(mut dm) = datetime.with_month(number){
-                        if !mark.day.status {
-                            if let Some(d) = dm.with_day(mark.month.value){
-                                mark.day.value = mark.month.value;
-                                mark.day.label = mark.month.label.clone();
-                                mark.day.status = true;
-                                dm = d;
-                            }
-                        }
-                        mark.month.status = true;
-                        mark.month.label = label;
-                        mark.month.value = number;
-                        return dm
+                if force && number <= MAX_NUMBER_MONTH{
+                    if !mark.day.status && mark.month.status{
+                        mark.day.value = mark.month.value;
+                        mark.day.label = mark.month.label.clone();
+                        mark.day.status = true;
                     }
+                    mark.month.status = true;
+                    mark.month.label = label;
+                    mark.month.value = number;
+                    return
                 }
                 // 月份｜类型相同且都为字符串类型的情况下可以强行设定
                 if mark.month.label == DateTimeLabel::Characters && label == DateTimeLabel::Characters && number <= MAX_NUMBER_MONTH{
-                    if let Some(d) = datetime.with_month(number){
-                        mark.month.status = true;
-                        mark.month.label = label;
-                        mark.month.value = number;
-                        return d
-                    }
+                    mark.month.status = true;
+                    mark.month.label = label;
+                    mark.month.value = number;
+                    return
                 }
                 // 月份｜符合条件且月份信息没有锁定的情况下可以设定
-                if number <= MAX_NUMBER_MONTH && !mark.month.status{
-                    if let Some(d) = datetime.with_month(number) {
-                        mark.month.status = true;
-                        mark.month.label = label;
-                        mark.month.value = number;
-                        return d
-                    }
+                if number > 0 && number <= MAX_NUMBER_MONTH && !mark.month.status{
+                    mark.month.status = true;
+                    mark.month.label = label;
+                    mark.month.value = number;
+                    return
                 }
                 // 天｜符合条件且天信息没有锁定的情况下可以设定
-                if number <= MAX_NUMBER_DAY && !mark.day.status{
-                    if let Some(d) = datetime.with_day(number){
-                        mark.day.status = true;
-                        mark.day.label = label;
-                        mark.day.value = number;
-                        return d
-                    }
+                if number > 0 && number <= MAX_NUMBER_DAY && !mark.day.status{
+                    mark.day.status = true;
+                    mark.day.label = label;
+                    mark.day.value = number;
                 }
             }
             _ => {}
         }
-        return datetime
     }
 
     /// 基于固有格式解析时分秒和时区
@@ -522,15 +798,23 @@ impl ParseAbsolute{
         如果是，则是
         */
         match token.text.parse(){
-            Ok(number) => {
+            Ok(mut number) => {
                 if number > 0 && number <= MAX_NUMBER_DAY{
                     return (number, DateType::MONTH, false)
                 }
+                // 民国纪年｜换算为公历年份
+                if self.era == EraBasedCalendar::Minguo && number <= MAX_NUMBER_YEAR_MINGUO{
+                    number += DIFF_NUMBER_YEAR_MINGUO;
+                }
+                // 伊朗历｜年份在生成日期时换算为公历
+                if self.era == EraBasedCalendar::Jalali && (MIN_NUMBER_YEAR_JALALI..MIN_NUMBER_YEAR).contains(&number){
+                    return (number, DateType::YEAR, true)
+                }
                 if number < MIN_NUMBER_YEAR || number > MAX_NUMBER_YEAR && !self.param.strict{
                     return (0, DateType::NONE, false)
                 }
                 let datetime = create_datetime(false, false);
-                if self.param.strict && self.era == EraBasedCalendar::Non{
+                if self.param.strict && self.is_gregorian(){
                     // 严格模式下限定年份不超过当前年份
                     if number as i32 <= datetime.year(){
                         return (number, DateType::YEAR, true)
@@ -564,37 +848,86 @@ impl ParseAbsolute{
         if let Some(item) = search_dubious_date(text){
             let token = self.tokenize(&item);
             self.token.extend(token);
-            return text.replace(&item, "")
+            let start = text.find(&item).map(|i| text[..i].chars().count()).unwrap_or(0);
+            let spans = self.search_span_token(&item, start);
+            self.spans.extend(spans);
+            let data = text.replace(&item, "");
+            self.track(text, &data);
+            return data
         }
         return text.to_string()
     }
 
     fn work(&mut self, text: &str) -> Result {
         let mut data = Result { timezone: self.param.timezone.clone(), method: String::from("absolute"), ..Default::default()};
+        if self.validity == DateValidity::Invalid{
+            data.validity = DateValidity::Invalid;
+            return data
+        }
         // 匹配可疑的日期文本｜同时提高后续解析时的优先级
         let text = self.mark_dubious_date(text);
+        if self.param.trace{
+            let tokens = display_token(&self.token);
+            self.record("mark_dubious_date", || format!("{} tokens=[{}]", text, tokens));
+        }
         // 去噪
         let item = eliminate_noise(text.as_str());
+        self.track(&text, &item);
+        self.record("eliminate_noise", || item.clone());
         // 规整
-        let (mut seed, era) = unitize_text(&item);
-        self.era = era;
-        seed = eliminate_symbol_point(seed.as_str());
+        let lexicon = get_lexicon(&self.param);
+        let (seed, era) = unitize_text(&item, &lexicon, self.era == EraBasedCalendar::Jalali);
+        self.track(&item, &seed);
+        self.record("unitize_text", || format!("{} era={:?}", seed, era));
+        if era != EraBasedCalendar::Non{
+            self.era = era;
+        }
+        if self.era == EraBasedCalendar::Non && self.param.minguo{
+            self.era = EraBasedCalendar::Minguo;
+        }
+        let point = eliminate_symbol_point(seed.as_str());
+        self.track(&seed, &point);
+        self.record("eliminate_symbol_point", || point.clone());
         // 切分
-        let seed= reorder_text_meridian(seed.as_str());
-        let mut section: Vec<String> = split_with_numeric(seed);
+        let seed= reorder_text_meridian(point.as_str());
+        self.track(&point, &seed);
+        self.record("reorder_text_meridian", || seed.clone());
+        let mut section: Vec<String> = split_with_numeric(seed.clone());
+        self.record("split_with_numeric", || format!("{:?}", section));
         section = section_with_space(section);
+        self.record("section_with_space", || format!("{:?}", section));
         // 附加属性
-        let (offset, timezone) = search_offset_with_text(&section);
+        let (offset, timezone) = search_offset_with_text(&section, &lexicon);
+        self.record("search_offset_with_text", || format!("offset={}s timezone={:?}", offset, timezone));
         self.apply_offset_timezone(offset, timezone);
         // 二次规整
         section = self.eliminate_noise_normal(section);
+        self.record("eliminate_noise_normal", || format!("{:?}", section));
         let entry = self.reorder(section);
+        self.track(&seed, &entry);
+        self.record("reorder", || entry.clone());
         // 正式解析
         let token:Vec<Token> = self.tokenize(&entry);
         self.token.extend(token);
-        let (datetime, status) = self.parse_token();
+        let spans = self.search_span_token(&entry, 0);
+        self.spans.extend(spans);
+        if self.param.trace{
+            let tokens = display_token(&self.token);
+            self.record("tokenize", || format!("[{}]", tokens));
+        }
+        let (datetime, validity, span) = self.parse_token();
+        self.record("parse_token", || format!("{} validity={:?} span={:?}", datetime, validity, span));
         data.time = datetime;
-        data.status = status;
+        data.status = validity == DateValidity::Valid || validity == DateValidity::Rolled;
+        data.validity = validity;
+        // 预处理时换算的日期已经顺延 例如宽松模式下的伊斯兰历 1445-02-30
+        if data.status && self.validity == DateValidity::Rolled{
+            data.validity = DateValidity::Rolled;
+        }
+        if data.status{
+            data.calendar = self.era.clone();
+            data.span = span;
+        }
         return data
     }
 
@@ -605,3 +938,44 @@ impl ParseAbsolute{
         }
     }
 }
+
+/// TOKEN 的可读形式｜例如 "2023"/Numeric "oct"/Characters
+fn display_token(token: &[Token]) -> String{
+    let items: Vec<String> = token.iter().map(|v| format!("{:?}/{:?}", v.text, v.label)).collect();
+    items.join(" ")
+}
+
+
+/// 多个原文范围的并集｜忽略宽度为零的范围
+fn union_span<I: Iterator<Item = (usize, usize)>>(spans: I) -> Option<(usize, usize)>{
+    spans.filter(|v| v.0 < v.1).reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+}
+
+/// 标准格式的时间在原文中的范围｜标准解析器会去掉首尾空白
+fn locate_span_standard(text: &str, span: Option<(usize, usize)>) -> (usize, usize){
+    let start = text.len() - text.trim_start().len();
+    let (i, j) = span.unwrap_or((0, text.trim().len()));
+    (start + i, start + j)
+}
+
+/// 扩展到完整的原文｜补全被截断的单词以及末尾的单位 例如 Oct -> October 5 -> 5日 5 -> 5th
+fn extend_span(text: &str, span: (usize, usize)) -> (usize, usize){
+    let (mut start, mut end) = span;
+    let alpha = |v: Option<char>| v.map(|ch| ch.is_ascii_alphabetic()).unwrap_or(false);
+    while start > 0 && alpha(text[start..].chars().next()) && alpha(text[..start].chars().next_back()){
+        start -= 1;
+    }
+    while end < text.len() && alpha(text[..end].chars().next_back()) && alpha(text[end..].chars().next()){
+        end += 1;
+    }
+    let rest = &text[end..];
+    if let Some(suffix) = TEXT_SUFFIX_SPAN.iter().find(|v| rest.starts_with(**v)){
+        end += suffix.len();
+    }else if text[..end].ends_with(|ch: char| ch.is_ascii_digit()){
+        if let Some(suffix) = TEXT_ORDINAL_SPAN.iter().find(|v| rest.to_ascii_lowercase().starts_with(**v) && !alpha(rest[v.len()..].chars().next())){
+            end += suffix.len();
+        }
+    }
+    let item = &text[start..end];
+    (start + item.len() - item.trim_start().len(), end - item.len() + item.trim_end().len())
+}
\ No newline at end of file
@@ -1,9 +1,10 @@
 use serde_json;
-use std::{collections::HashMap};
-use chrono::{Local, Utc};
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+use chrono::{Datelike, Duration, Local, NaiveDate, Utc, Weekday};
 use once_cell::sync::Lazy;
 use regex::{Regex, RegexBuilder};
-use crate::bind::ResultRelativeText;
+use crate::bind::{ResultRelativeText, EraBasedCalendar, DateValidity, Holiday, HolidayRule, Lexicon, Param};
+use crate::utils::{convert_numeral, days_in_month};
 
 /// 时区和差值
 static TIMEZONE_OFFSET:Lazy<HashMap<&str, i32>> = Lazy::new(|| {
@@ -36,6 +37,162 @@ static REGEX_UTC_TIMEZONE:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
 });
 
 
+/// ISO 8601 正则表达式｜须完整匹配整个文本
+/// calendar 日历日期 例如 2023-10-05 20231005
+/// week 周日期 例如 2023-W40-4 2023W404 2023-W40
+/// ordinal 序数日期 例如 2023-278 2023278
+/// time 时间和偏移量 例如 T14:20:30.123Z t142030+0800 14:20+08 T14
+static REGEX_ISO8601:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let time = r"(?P<separator>[Tt]|\s+)(?P<hour>\d{2})(?::?(?P<minute>\d{2})(?::?(?P<second>\d{2}))?)?(?:[.,](?P<fraction>\d+))?";
+    let offset = r"\s*(?P<offset>[Zz]|[+\-−]\d{2}(?::?\d{2})?)?";
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    for (name, date) in [
+        ("calendar", r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})|(?P<year_basic>\d{4})(?P<month_basic>\d{2})(?P<day_basic>\d{2})"),
+        ("week", r"(?P<year>\d{4})-?[Ww](?P<week>\d{2})(?:-?(?P<weekday>[1-7]))?"),
+        ("ordinal", r"(?P<year>\d{4})-?(?P<ordinal>\d{3})")]{
+        let pattern = format!(r"^(?:{})(?:{}(?:{})?)?$", date, time, offset);
+        items.insert(name, Regex::new(&pattern).unwrap());
+    }
+    items
+});
+
+/// 邮件和 HTTP 日期中的时区名称｜RFC 5322 obs-zone
+/// 除 Z 以外的军用时区字母按 RFC 5322 的约定视为 -0000
+static TEXT_ZONE_RFC:Lazy<HashMap<&str, i32>> = Lazy::new(|| {
+    let seed = r#"{"ut": 0, "utc": 0, "gmt": 0, "z": 0, "est": -18000, "edt": -14400, "cst": -21600, "cdt": -18000, "mst": -25200, "mdt": -21600, "pst": -28800, "pdt": -25200}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 邮件和 HTTP 日期正则表达式｜以单词边界匹配 可以带有邮件头等前缀 忽略大小写
+/// rfc2822 RFC 2822/5322 以及 RFC 7231 IMF-fixdate 例如 Thu, 05 Oct 2023 14:20:30 +0800
+/// rfc850 例如 Thursday, 05-Oct-23 14:20:30 GMT
+/// asctime 例如 Thu Oct  5 14:20:30 2023
+/// comment 邮件日期末尾的注释 例如 (PST)
+static REGEX_RFC:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let month = r"(?P<month>jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)";
+    let time = r"(?P<hour>\d{2}):(?P<minute>\d{2})(?::(?P<second>\d{2}))?";
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let rfc2822 = format!(r"\b(?:(?P<weekday>[a-z]{{3}})\s*,\s*)?(?P<day>\d{{1,2}})\s+{}\s+(?P<year>\d{{2,4}})\s+{}\s*(?P<zone>[+\-]\d{{4}}|[a-z]{{1,3}})\b", month, time);
+    let rfc850 = format!(r"\b(?P<weekday>[a-z]+)\s*,\s*(?P<day>\d{{1,2}})-{}-(?P<year>\d{{2,4}})\s+{}\s*(?P<zone>gmt|utc)\b", month, time);
+    let asctime = format!(r"\b(?P<weekday>[a-z]{{3}})\s+{}\s+(?P<day>\d{{1,2}})\s+{}\s+(?P<year>\d{{4}})\b", month, time);
+    for (name, pattern) in [("rfc2822", rfc2822), ("rfc850", rfc850), ("asctime", asctime)]{
+        items.insert(name, RegexBuilder::new(&pattern).case_insensitive(true).build().unwrap());
+    }
+    items.insert("comment", Regex::new(r"\([^()]*\)").unwrap());
+    items
+});
+
+/// 日志时间正则表达式｜忽略大小写
+/// clf Apache/NCSA 通用日志格式和 nginx 例如 [05/Oct/2023:14:20:30 +0800]
+/// syslog 行首的 月 日 时:分:秒 主机 例如 Oct  5 14:20:30 host sshd[123]: ... 以及 journald 的 short 和 short-precise 格式
+/// java 行首的 年-月-日 时:分:秒 例如 Log4j 的 2023-10-05 14:20:30,123 INFO 和 nginx 错误日志的 2023/10/05 14:20:30 [error]
+/// iso 行首带偏移量的 ISO 8601 时间 例如 journald 的 short-iso 格式和 RFC 5424 syslog 的 <34>1 2023-10-05T14:20:30.003Z host
+static REGEX_LOG:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let level = r"trace|debug|info|notice|warn|warning|error|err|crit|critical|alert|emerg|fatal|severe";
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let patterns = vec![
+        ("clf", r"(?P<day>\d{2})/(?P<month>[a-z]{3})/(?P<year>\d{4}):(?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2})(?:\s+(?P<zone>[+\-]\d{4}))?".to_string()),
+        ("syslog", r"^(?:<\d{1,3}>)?(?P<month>[a-z]{3})\s+(?P<day>\d{1,2})\s+(?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2})(?:\.(?P<fraction>\d{1,9}))?\s+(?P<host>[^\s:]+)".to_string()),
+        ("java", format!(r"^\[?(?P<year>\d{{4}})[-/](?P<month>\d{{2}})[-/](?P<day>\d{{2}})[ T](?P<hour>\d{{2}}):(?P<minute>\d{{2}}):(?P<second>\d{{2}})(?:(?P<separator>[.,])(?P<fraction>\d{{1,9}}))?\]?(?P<tail>\s+(?:\[|(?:{})\b)|$)?", level)),
+        ("iso", r"^(?:<\d{1,3}>\d?\s*)?(?P<time>\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:z|[+\-]\d{2}:?\d{2}))\s".to_string()),
+    ];
+    for (name, pattern) in patterns{
+        items.insert(name, RegexBuilder::new(&pattern).case_insensitive(true).build().unwrap());
+    }
+    items
+});
+
+/// 时长正则表达式
+/// iso ISO 8601 时长 例如 PT2H30M P1Y2M P3W 以及 ISO 8601-2 的负时长 -P1D
+/// iso_alternative ISO 8601 时长的替代格式 例如 P0001-02-03T04:05:06
+/// clock 时:分[:秒] 例如 1:30:00
+/// number 数字与其后的单位 例如 2 hrs 3天
+static REGEX_DURATION:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let number = r"\d+(?:[.,]\d+)?";
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let patterns = vec![
+        ("iso", format!(r"^(?P<sign>[+\-])?P(?:(?P<year>{n})Y)?(?:(?P<month>{n})M)?(?:(?P<week>{n})W)?(?:(?P<day>{n})D)?(?:T(?:(?P<hour>{n})H)?(?:(?P<minute>{n})M)?(?:(?P<second>{n})S)?)?$", n=number)),
+        ("iso_alternative", r"^(?P<sign>[+\-])?P(?P<year>\d{4})-?(?P<month>\d{2})-?(?P<day>\d{2})(?:T(?P<hour>\d{2}):?(?P<minute>\d{2}):?(?P<second>\d{2}(?:[.,]\d+)?))?$".to_string()),
+        ("clock", r"^(?P<hour>\d+):(?P<minute>[0-5]\d)(?::(?P<second>[0-5]\d(?:[.,]\d+)?))?$".to_string()),
+        ("number", format!(r"(?P<number>{})\s*(?P<unit>[^\d\s,;]+)", number)),
+    ];
+    for (name, pattern) in patterns{
+        items.insert(name, RegexBuilder::new(&pattern).case_insensitive(true).build().unwrap());
+    }
+    items
+});
+
+/// 重复规则正则表达式
+/// interval every 2 weeks、every other day、每隔3天、每月
+/// frequency daily weekly 等频率词
+/// weekday 周一 星期日 禮拜三
+/// month_day 1st 15th 以及 10月1日 15号
+/// time 9am 08:00 10:30 pm 下午3点半
+static REGEX_RECURRENCE:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let patterns = vec![
+        ("interval", r"(?:\b(?:every|each)\s+(?:(?P<number>\d+|other)\s+)?(?P<unit>day|week|month|year)s?\b)|(?:每隔?(?P<number_zh>\d+)?(?:个|個)?(?P<unit_zh>天|日|周|週|星期|礼拜|禮拜|月|年))"),
+        ("frequency", r"\b(?P<unit>daily|everyday|weekly|biweekly|fortnightly|monthly|yearly|annually)\b|(?P<unit_zh>天天)"),
+        ("weekday", r"(?:周|週|星期|礼拜|禮拜)(?P<day>[一二三四五六日天])"),
+        ("month_day", r"(?:\b(?P<day>\d{1,2})(?:st|nd|rd|th)\b)|(?:(?:(?P<month_zh>\d{1,2})月)?(?P<day_zh>\d{1,2})(?:号|號|日))"),
+        ("last_day", r"最[后後][一1]天|月末|月底|\blast\s+day\b"),
+        ("time", r"(?:(?:上午|下午|早上|晚上|中午|凌晨)?\d{1,2}[点點时時](?:\d{1,2}分?|半)?)|(?:\d{1,2}(?::\d{2})?\s*(?:am|pm|a\.m\.|p\.m\.))|(?:\d{1,2}:\d{2}(?::\d{2})?)"),
+    ];
+    for (name, pattern) in patterns{
+        items.insert(name, RegexBuilder::new(pattern).case_insensitive(true).build().unwrap());
+    }
+    items
+});
+
+/// 季度、半年和周｜先将各种写法统一为 q3 h1 w42 再由 period 识别
+static REGEX_PERIOD:Lazy<HashMap<&str, Regex>>= Lazy::new(||{
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let patterns = vec![
+        ("week", r"\b(?:week|wk)\.?\s*(?:no\.?\s*|#\s*)?(?P<number>\d{1,2})\b"),
+        ("week_zh", r"第\s*(?P<number>\d{1,2})\s*(?:周|週|星期)"),
+        ("quarter_zh", r"第?\s*(?P<number>[1-4一二三四])\s*季度?"),
+        ("half_zh", r"(?P<half>上|下)半年度?"),
+        ("ordinal", r"\b(?P<ordinal>first|second|third|fourth|1st|2nd|3rd|4th)\s+(?P<unit>quarter|half)\b"),
+        ("fiscal", r"fy|fiscal\s+year|财年|財年|财政年度|財政年度|会计年度|會計年度"),
+        ("period", r"(?:^|[^a-z])(?:(?P<kind>[qhw])\s*(?P<number>\d{1,2})|(?P<number_reverse>[1-4])(?P<kind_reverse>[qh]))(?:[^a-z\d]|$)"),
+        ("year", r"(?:^|\D)(?P<year>\d{4})(?:\D|$)"),
+        ("year_fiscal", r"fy\s*'?(?P<year>\d{4}|\d{2})(?:\D|$)"),
+    ];
+    for (name, pattern) in patterns{
+        items.insert(name, RegexBuilder::new(pattern).case_insensitive(true).build().unwrap());
+    }
+    items
+});
+
+/// 时长单位｜常见的英文缩写 其余单位来自相对时间 例如 3天前 中的 天、hari yang lalu 中的 hari
+static TEXT_DURATION_UNIT:Lazy<HashMap<String, String>> = Lazy::new(|| {
+    create_duration_unit(TEXT_RELATIVE.iter().map(|(k, v)| (*k, v.as_str())))
+});
+
+/// 从相对时间的词库中提取时长单位｜常见的英文缩写优先
+fn create_duration_unit<'a>(relative: impl Iterator<Item = (&'a str, &'a str)>) -> HashMap<String, String>{
+    let seed = r#"{"y": "year", "yr": "year", "yrs": "year", "year": "year", "years": "year", "mo": "month", "mos": "month", "month": "month", "months": "month", "w": "week", "wk": "week", "wks": "week", "week": "week", "weeks": "week", "d": "day", "day": "day", "days": "day", "h": "hour", "hr": "hour", "hrs": "hour", "hour": "hour", "hours": "hour", "m": "minute", "min": "minute", "mins": "minute", "minute": "minute", "minutes": "minute", "s": "second", "sec": "second", "secs": "second", "second": "second", "seconds": "second", "星期": "week", "个星期": "week", "個星期": "week", "小时": "hour", "小時": "hour", "钟头": "hour", "鐘頭": "hour"}"#;
+    let mut units: HashMap<String, Vec<String>> = HashMap::new();
+    for (key, value) in relative{
+        let unit = value.trim_end_matches(" ago").to_string();
+        let words: Vec<&str> = match key.strip_suffix('前'){
+            Some(v) if !key.contains(' ') => vec![v],
+            _ => key.split_whitespace().collect()
+        };
+        for word in words{
+            let item = units.entry(word.to_string()).or_default();
+            if !item.contains(&unit){
+                item.push(unit.clone());
+            }
+        }
+    }
+    // 同一个词对应多个单位时无法确定 例如 lalu gelede
+    let mut items: HashMap<String, String> = units.into_iter().filter(|(_, v)| v.len() == 1).map(|(k, v)| (k, v[0].clone())).collect();
+    let m: HashMap<String, String> = serde_json::from_str(seed).unwrap();
+    items.extend(m);
+    items
+}
+
 /// 相对时间正则表达式
 static MONTH_NUMERIC:Lazy<HashMap<&str, u32>> = Lazy::new(|| {
     let seed = r#"{"january": 1, "february": 2, "march": 3, "april": 4, "may": 5, "june": 6, "july": 7, "august": 8, "september": 9, "october": 10, "november": 11, "december": 12}"#;
@@ -51,7 +208,7 @@ static TEXT_RELATIVE:Lazy<HashMap<&str, String>> = Lazy::new(|| {
 
 /// 月份
 static TEXT_MONTH:Lazy<HashMap<&str, String>> = Lazy::new(|| {
-    let seed = r#"{"ม.ค.": "january", "ก.พ.": "february", "มี.ค.": "march", "เม.ย.": "april", "พ.ค.": "may", "มิ.ย.": "june", "ก.ค.": "july", "ส.ค.": "august", "ก.ย.": "september", "ต.ค.": "october", "พ.ย.": "november", "ธ.ค.": "december","zib": "january", "zibandlela": "january", "nhlo": "february", "nhlolanja": "february", "mbi": "june", "mbimbitho": "march", "mab": "april", "mabasa": "april", "nkw": "may", "nkwenkwezi": "may", "nhla": "june", "nhlangula": "june", "ntu": "july", "ntulikazi": "july", "ncw": "august", "ncwabakazi": "august", "mpan": "september", "mpandula": "september", "mfu": "july", "mfumfu": "october", "lwe": "november", "lwezi": "november", "mpal": "december", "mpalakazi": "december", "jan": "january", "janairu": "january", "fab": "february", "faburairu": "february", "mar": "march", "maris": "march", "afi": "april", "afirilu": "april", "may": "may", "mayu": "may", "yun": "june", "yuni": "june", "yul": "july", "yuli": "july", "agu": "august", "agusta": "august", "sat": "september", "satumba": "september", "okt": "october", "oktoba": "october", "nuw": "november", "nuwamba": "november", "dis": "december", "disamba": "december", "januar": "january", "feb": "february", "februar": "february", "mär": "march", "märz": "march", "apr": "april", "april": "april", "mai": "may", "jun": "june", "juni": "june", "jul": "july", "juli": "july", "aug": "august", "auguscht": "august", "sep": "september", "septämber": "september", "oktoober": "october", "nov": "november", "novämber": "november", "dez": "december", "dezämber": "december", "ⵉⵏⵏ": "january", "ⵉⵏⵏⴰⵢⵔ": "january", "ⴱⵕⴰ": "february", "ⴱⵕⴰⵢⵕ": "february", "ⵎⴰⵕ": "march", "ⵎⴰⵕⵚ": "march", "ⵉⴱⵔ": "april", "ⵉⴱⵔⵉⵔ": "april", "ⵎⴰⵢ": "may", "ⵎⴰⵢⵢⵓ": "may", "ⵢⵓⵏ": "june", "ⵢⵓⵏⵢⵓ": "june", "ⵢⵓⵍ": "july", "ⵢⵓⵍⵢⵓⵣ": "july", "ⵖⵓⵛ": "august", "ⵖⵓⵛⵜ": "august", "ⵛⵓⵜ": "september", "ⵛⵓⵜⴰⵏⴱⵉⵔ": "september", "ⴽⵜⵓ": "october", "ⴽⵜⵓⴱⵔ": "october", "ⵏⵓⵡ": "november", "ⵏⵓⵡⴰⵏⴱⵉⵔ": "november", "ⴷⵓⵊ": "december", "ⴷⵓⵊⴰⵏⴱⵉⵔ": "december", "janwaliyo": "january", "febwaliyo": "february", "marisi": "march", "apu": "april", "apuli": "april", "maa": "may", "maayi": "may", "juu": "june", "juuni": "june", "julaayi": "july", "agusito": "august", "seb": "september", "sebuttemba": "september", "oki": "october", "okitobba": "october", "novemba": "november", "des": "december", "desemba": "december", "januari": "january", "februari": "february", "mac": "march", "machi": "march", "aprili": "april", "mei": "may", "julai": "july", "ago": "august", "agosti": "august", "septemba": "september", "dec": "december", "հնվ": "january", "հունվար": "january", "հունվարի": "january", "փետրվար": "february", "փետրվարի": "february", "փտվ": "february", "մարտ": "march", "մարտի": "march", "մրտ": "march", "ապր": "april", "ապրիլ": "april", "ապրիլի": "april", "մայիս": "may", "մայիսի": "may", "մյս": "may", "հնս": "june", "հունիս": "june", "հունիսի": "june", "հլս": "july", "հուլիս": "july", "հուլիսի": "july", "օգոստոս": "august", "օգոստոսի": "august", "օգս": "august", "սեպ": "september", "սեպտեմբեր": "september", "սեպտեմբերի": "september", "հոկ": "october", "հոկտեմբեր": "october", "հոկտեմբերի": "october", "նոյ": "november", "նոյեմբեր": "november", "նոյեմբերի": "november", "դեկ": "december", "դեկտեմբեր": "december", "դեկտեմբերի": "december", "ינואר": "january", "ינו׳": "january", "לינואר": "january", "בינואר": "january", "ינו": "january", "פברואר": "february", "פבר׳": "february", "בפברואר": "february", "לפברואר": "february", "פבר": "february", "מרץ": "march", "במרץ": "march", "למרץ": "march", "מרס": "march", "במרס": "march", "למרס": "march", "מארס": "march", "במארס": "march", "למארס": "march", "אפריל": "april", "אפר׳": "april", "באפריל": "april", "לאפריל": "april", "אפר": "april", "מאי": "may", "במאי": "may", "למאי": "may", "יוני": "june", "ביוני": "june", "ליוני": "june", "יונ": "june", "יולי": "july", "ביולי": "july", "ליולי": "july", "יול": "july", "אוגוסט": "august", "אוג׳": "august", "באוגוסט": "august", "לאוגוסט": "august", "אוג": "august", "ספטמבר": "september", "ספט׳": "september", "בספטמבר": "september", "לספטמבר": "september", "ספט": "september", "אוקטובר": "october", "אוק׳": "october", "באוקטובר": "october", "לאוקטובר": "october", "אוק": "october", "נובמבר": "november", "נוב׳": "november", "בנובמבר": "november", "לנובמבר": "november", "נוב": "november", "דצמבר": "december", "דצמ׳": "december", "בדצמבר": "december", "לדצמבר": "december", "דצמ": "december", "яну": "january", "януари": "january", "ян": "january", "фев": "february", "февруари": "february", "фв": "february", "март": "march", "апр": "april", "април": "april", "ап": "april", "май": "may", "юни": "june", "юн": "june", "юли": "july", "юл": "july", "авг": "august", "август": "august", "сеп": "september", "септември": "september", "септ": "september", "септм": "september", "окт": "october", "октомври": "october", "ное": "november", "ноември": "november", "дек": "december", "декември": "december", "zan": "january", "zanvie": "january", "fev": "february", "fevriye": "february", "mars": "march", "avr": "april", "avril": "april", "me": "may", "zin": "june", "zil": "july", "zilye": "july", "out": "october", "septam": "september", "oktob": "october", "novam": "november", "desam": "december", "يانۋار": "january", "فېۋرال": "february", "مارت": "march", "ئاپرېل": "april", "ماي": "may", "ئىيۇن": "june", "ئىيۇل": "july", "ئاۋغۇست": "august", "سېنتەبىر": "september", "ئۆكتەبىر": "october", "نويابىر": "november", "دېكابىر": "december", "kbz": "january", "okwokubanza": "january", "kbr": "february", "okwakabiri": "february", "kst": "march", "okwakashatu": "march", "kkn": "april", "okwakana": "april", "ktn": "may", "okwakataana": "may", "kmk": "june", "okwamukaaga": "june", "kms": "july", "okwamushanju": "july", "kmn": "august", "okwamunaana": "august", "kmw": "september", "okwamwenda": "september", "kkm": "october", "okwaikumi": "october", "knk": "november", "okwaikumi na kumwe": "november", "knb": "december", "okwaikumi na ibiri": "december", "იან": "january", "იანვარი": "january", "თებ": "february", "თებერვალი": "february", "მარ": "march", "მარტი": "march", "აპრ": "april", "აპრილი": "april", "მაი": "may", "მაისი": "may", "ივნ": "june", "ივნისი": "june", "ივლ": "july", "ივლისი": "july", "აგვ": "august", "აგვისტო": "august", "სექ": "september", "სექტემბერი": "september", "ოქტ": "october", "ოქტომბერი": "october", "ნოე": "november", "ნოემბერი": "november", "დეკ": "december", "დეკემბერი": "december", "1月": "january", "2月": "february", "3月": "march", "4月": "april", "5月": "may", "6月": "june", "7月": "july", "8月": "august", "9月": "september", "10月": "october", "11月": "november", "12月": "december", "一月": "january", "二月": "february", "三月": "march", "四月": "april", "五月": "may", "六月": "june", "七月": "july", "八月": "august", "九月": "september", "十月": "october", "十一月": "november", "十二月": "december", "sii": "january", "siilo": "january", "col": "february", "colte": "february", "mbo": "march", "mbooy": "march", "see": "may", "seeɗto": "april", "duu": "may", "duujal": "may", "kor": "june", "korse": "june", "mor": "july", "morso": "july", "juk": "august", "juko": "august", "siilto": "september", "slt": "september", "yar": "october", "yarkomaa": "october", "jol": "july", "jolal": "november", "bow": "december", "bowte": "december", "yan": "january", "yanvar": "january", "fevral": "february", "mart": "march", "aprel": "april", "iyn": "june", "iyun": "june", "iyl": "july", "iyul": "july", "avg": "august", "avgust": "august", "sen": "september", "sentabr": "september", "oktabr": "october", "noy": "november", "noyabr": "november", "dek": "december", "dekabr": "december", "янв": "january", "январь": "january", "февраль": "february", "мар": "march", "апрель": "april", "июн": "june", "июнь": "june", "июл": "july", "июль": "july", "сен": "september", "сентябрь": "september", "октябрь": "october", "ноя": "november", "ноябрь": "november", "декабрь": "december", "ཟླ་༡": "january", "ཟླ་དངཔ་": "january", "སྤྱི་ཟླ་དངཔ་": "january", "ཟླ་༢": "february", "ཟླ་གཉིས་པ་": "february", "སྤྱི་ཟླ་གཉིས་པ་": "february", "ཟླ་༣": "march", "ཟླ་གསུམ་པ་": "march", "སྤྱི་ཟླ་གསུམ་པ་": "march", "ཟླ་༤": "april", "ཟླ་བཞི་པ་": "april", "སྤྱི་ཟླ་བཞི་པ": "april", "ཟླ་༥": "may", "ཟླ་ལྔ་པ་": "may", "སྤྱི་ཟླ་ལྔ་པ་": "may", "ཟླ་༦": "june", "ཟླ་དྲུག་པ": "june", "སྤྱི་ཟླ་དྲུག་པ": "june", "ཟླ་༧": "july", "ཟླ་བདུན་པ་": "july", "སྤྱི་ཟླ་བདུན་པ་": "july", "ཟླ་༨": "august", "ཟླ་བརྒྱད་པ་": "august", "སྤྱི་ཟླ་བརྒྱད་པ་": "august", "ཟླ་༩": "september", "ཟླ་དགུ་པ་": "september", "སྤྱི་ཟླ་དགུ་པ་": "september", "ཟླ་༡༠": "october", "ཟླ་བཅུ་པ་": "october", "སྤྱི་ཟླ་བཅུ་པ་": "october", "ཟླ་༡༡": "november", "ཟླ་བཅུ་གཅིག་པ་": "november", "སྤྱི་ཟླ་བཅུ་གཅིག་པ་": "november", "ཟླ་༡༢": "december", "ཟླ་བཅུ་གཉིས་པ་": "december", "སྤྱི་ཟླ་བཅུ་གཉིས་པ་": "december", "hut": "january", "pa mwedzi gwa hutala": "january", "pa mwedzi gwa wuvili": "february", "vil": "february", "dat": "march", "pa mwedzi gwa wudatu": "march", "pa mwedzi gwa wutai": "april", "tai": "april", "han": "may", "pa mwedzi gwa wuhanu": "may", "pa mwedzi gwa sita": "june", "sit": "june", "pa mwedzi gwa saba": "july", "sab": "july", "nan": "august", "pa mwedzi gwa nane": "august", "pa mwedzi gwa tisa": "september", "tis": "september", "kum": "october", "pa mwedzi gwa kumi": "october", "kmj": "november", "pa mwedzi gwa kumi na moja": "november", "kmb": "december", "pa mwedzi gwa kumi na mbili": "december", "žan": "january", "žanwiye": "january", "fee": "february", "feewiriye": "february", "marsi": "march", "awi": "april", "awiril": "april", "žuw": "june", "žuweŋ": "june", "žuy": "july", "žuyye": "july", "ut": "august", "sek": "september", "sektanbur": "september", "oktoobur": "october", "noo": "november", "noowanbur": "november", "dee": "december", "deesanbur": "december", "sa": "january", "sanvie": "january", "fe": "february", "fébirie": "february", "ma": "march", "ab": "april", "aburil": "april", "mee": "may", "su": "june", "sueŋ": "june", "sú": "july", "súuyee": "july", "se": "september", "settembar": "september", "ok": "october", "oktobar": "october", "no": "november", "novembar": "november", "de": "december", "disambar": "december", "aprilyi": "april", "junyi": "june", "julyai": "july", "agusti": "august", "ene": "january", "enero": "january", "peb": "february", "pebrero": "february", "marso": "march", "abr": "april", "abril": "april", "mayo": "may", "hun": "june", "hunyo": "june", "hul": "july", "hulyo": "july", "agosto": "august", "set": "september", "setyembre": "september", "oktubre": "october", "nob": "november", "nobyembre": "november", "disyembre": "december", "جنوری": "january", "فروری": "february", "مارچ": "march", "اپریل": "april", "مئ": "may", "جون": "june", "جولائی": "july", "اگست": "august", "ستمبر": "september", "اکتوبر": "october", "نومبر": "november", "دسمبر": "december", "maret": "march", "agt": "august", "agustus": "august", "Agu": "august", "september": "september", "Sept": "september", "oktober": "october", "november": "november", "desember": "december", "i̇yun": "june", "i̇yul": "july", "avq": "august", "avqust": "august", "sentyabr": "september", "oktyabr": "october", "जनवरी": "january", "फेब्रुअरी": "february", "मार्च": "march", "अप्रिल": "april", "मई": "may", "मे": "may", "जुन": "june", "जुलाई": "july", "अगस्ट": "august", "सेप्टेम्बर": "september", "अक्टोबर": "october", "नोभेम्बर": "november", "डिसेम्बर": "december", "nye": "january", "nyenye": "january", "ful": "september", "fulundïgi": "february", "mbä": "march", "mbängü": "march", "ngu": "april", "ngubùe": "april", "bêl": "may", "bêläwü": "may", "fön": "june", "föndo": "june", "len": "july", "lengua": "july", "kük": "august", "kükürü": "august", "mvu": "september", "mvuka": "september", "ngb": "february", "ngberere": "october", "nab": "november", "nabändüru": "november", "kak": "december", "kakauka": "december", "januarie": "january", "februarie": "february", "maart": "march", "mrt": "march", "junie": "june", "julie": "july", "augustus": "august", "ਜਨ": "january", "ਜਨਵਰੀ": "january", "ਫ਼ਰ": "february", "ਫ਼ਰਵਰੀ": "february", "ਮਾਰਚ": "march", "ਅਪ੍ਰੈ": "april", "ਅਪ੍ਰੈਲ": "april", "ਮਈ": "may", "ਜੂਨ": "june", "ਜੁਲਾ": "july", "ਜੁਲਾਈ": "july", "ਅਗ": "august", "ਅਗਸਤ": "august", "ਸਤੰ": "september", "ਸਤੰਬਰ": "september", "ਅਕਤੂ": "october", "ਅਕਤੂਬਰ": "october", "ਨਵੰ": "november", "ਨਵੰਬਰ": "november", "ਦਸੰ": "december", "ਦਸੰਬਰ": "december", "nduŋmbi saŋ": "january", "pɛsaŋ pɛ́pá": "february", "pɛsaŋ pɛ́tát": "march", "pɛsaŋ pɛ́nɛ́kwa": "april", "pɛsaŋ pataa": "may", "pɛsaŋ pɛ́nɛ́ntúkú": "june", "pɛsaŋ saambá": "july", "pɛsaŋ pɛ́nɛ́fɔm": "august", "pɛsaŋ pɛ́nɛ́pfúꞌú": "september", "pɛsaŋ nɛgɛ́m": "october", "pɛsaŋ ntsɔ̌pmɔ́": "november", "pɛsaŋ ntsɔ̌ppá": "december", "mbe": "january", "mweri wa mbere": "january", "kai": "february", "mweri wa kaĩri": "february", "kat": "march", "mweri wa kathatũ": "march", "kan": "april", "mweri wa kana": "april", "gat": "may", "mweri wa gatano": "may", "gan": "june", "mweri wa gatantatũ": "june", "mug": "july", "mweri wa mũgwanja": "july", "knn": "august", "mweri wa kanana": "august", "ken": "september", "mweri wa kenda": "september", "iku": "october", "mweri wa ikũmi": "october", "imw": "november", "mweri wa ikũmi na ũmwe": "november", "igi": "december", "mweri wa ikũmi na kaĩrĩ": "december", "zen": "january", "zenâr": "january", "fevrâr": "february", "març": "march", "avrîl": "april", "jug": "june", "jugn": "june", "lui": "february", "avo": "august", "avost": "august", "setembar": "september", "otu": "october", "otubar": "october", "dic": "december", "dicembar": "december", "flo": "january", "fĩi loo": "january", "cla": "february", "cokcwaklaŋne": "february", "cki": "march", "cokcwaklii": "march", "fmf": "april", "fĩi marfoo": "april", "mad": "july", "madǝǝuutǝbijaŋ": "may", "mamǝŋgwãafahbii": "june", "mamǝŋgwãalii": "july", "mli": "july", "madǝmbii": "august", "mam": "may", "fde": "september", "fĩi dǝɓlii": "september", "fmu": "october", "fĩi mundaŋ": "october", "fgw": "november", "fĩi gwahlle": "november", "fyu": "december", "fĩi yuru": "december", "ဇန်": "january", "ဇန်နဝါရီ": "january", "ဖေ": "february", "ဖေဖော်ဝါရီ": "february", "မတ်": "march", "ဧ": "april", "ဧပြီ": "april", "မေ": "may", "ဇွန်": "june", "ဇူ": "july", "ဇူလိုင်": "july", "ဩ": "august", "ဩဂုတ်": "august", "စက်": "september", "စက်တင်ဘာ": "september", "အောက်": "october", "အောက်တိုဘာ": "october", "နို": "november", "နိုဝင်ဘာ": "november", "ဒီ": "december", "ဒီဇင်ဘာ": "december", "јан": "january", "јануар": "january", "феб": "february", "фебруар": "february", "мај": "may", "јун": "june", "јул": "july", "септембар": "september", "октобар": "october", "нов": "november", "новембар": "november", "дец": "december", "децембар": "december", "janeru": "january", "febreru": "february", "marsu": "march", "maiu": "may", "junhu": "june", "julhu": "july", "agostu": "august", "setenbru": "september", "otubru": "october", "nuv": "november", "nuvenbru": "november", "diz": "december", "dizenbru": "december", "janúar": "january", "febrúar": "february", "apríl": "april", "maí": "may", "jún": "june", "júní": "june", "júl": "july", "júlí": "july", "ágú": "august", "ágúst": "august", "október": "october", "nóv": "november", "nóvember": "november", "ጃንዩ": "january", "ጃንዩወሪ": "january", "ፌብሩ": "february", "ፌብሩወሪ": "february", "ማርች": "march", "ኤፕሪ": "april", "ኤፕሪል": "april", "ሜይ": "may", "ጁን": "june", "ጁላይ": "july", "ኦገስ": "august", "ኦገስት": "august", "ሴፕቴ": "september", "ሴፕቴምበር": "september", "ኦክቶ": "october", "ኦክቶበር": "october", "ኖቬም": "november", "ኖቬምበር": "november", "ዲሴም": "december", "ዲሴምበር": "december", "marec": "march", "maj": "may", "junij": "june", "julij": "july", "december": "december", "ǃkhanni": "january", "ǃkhanǀgôab": "february", "ǀkhuuǁkhâb": "march", "ǃhôaǂkhaib": "april", "ǃkhaitsâb": "may", "gamaǀaeb": "june", "ǂkhoesaob": "july", "aoǁkhuumûǁkhâb": "august", "taraǀkhuumûǁkhâb": "september", "oct": "october", "ǂnûǁnâiseb": "october", "ǀhooǂgaeb": "november", "hôasoreǁkhâb": "december", "1월": "january", "2월": "february", "3월": "march", "4월": "april", "5월": "may", "6월": "june", "7월": "july", "8월": "august", "9월": "september", "10월": "october", "11월": "november", "12월": "december", "august": "august", "jaan": "january", "jaanuar": "january", "veebr": "february", "veebruar": "february", "märts": "march", "aprill": "april", "juuli": "july", "sept": "september", "dets": "december", "detsember": "december", "yen": "january", "yennayer": "january", "yeb": "april", "yebrayer": "february", "ibr": "april", "ibrir": "april", "mayyu": "may", "yunyu": "june", "yulyuz": "july", "ɣuc": "august", "ɣuct": "august", "cut": "september", "cutanbir": "september", "kṭu": "october", "kṭuber": "october", "nwa": "november", "nwanbir": "november", "duj": "december", "dujanbir": "december", "gen": "january", "mis genver": "january", "hwe": "february", "mis hwevrer": "february", "meu": "march", "mis meurth": "march", "ebr": "april", "mis ebrel": "april", "mis me": "may", "met": "june", "mis metheven": "june", "gor": "july", "mis gortheren": "july", "est": "august", "mis est": "august", "gwn": "september", "mis gwynngala": "september", "hed": "october", "mis hedra": "october", "du": "november", "mis du": "november", "kev": "december", "mis kevardhu": "december", "ጥሪ": "january", "ለካ": "february", "ለካቲት": "february", "መጋ": "march", "መጋቢት": "march", "ሚያ": "april", "ሚያዝያ": "april", "ግን": "may", "ግንቦት": "may", "ሰነ": "june", "ሓም": "july", "ሓምለ": "july", "ነሓ": "august", "ነሓሰ": "august", "መስ": "september", "መስከረም": "september", "ጥቅ": "october", "ጥቅምቲ": "october", "ሕዳ": "november", "ሕዳር": "november", "ታሕ": "december", "ታሕሳስ": "december", "जाने": "january", "जानेवारी": "january", "फेब्रु": "february", "फेब्रुवारी": "february", "एप्रि": "april", "एप्रिल": "april", "जून": "june", "जुलै": "july", "ऑग": "august", "ऑगस्ट": "august", "सप्टें": "september", "सप्टेंबर": "september", "ऑक्टो": "october", "ऑक्टोबर": "october", "नोव्हें": "november", "नोव्हेंबर": "november", "डिसें": "december", "डिसेंबर": "december", "ιαν": "january", "ιανουάριος": "january", "ιανουαρίου": "january", "φεβ": "february", "φεβρουάριος": "february", "φεβρουαρίου": "february", "μάρ": "march", "μάρτιος": "march", "μαρ": "march", "μαρτίου": "march", "απρ": "april", "απρίλιος": "april", "απριλίου": "april", "μάι": "may", "μάιος": "may", "μαΐ": "may", "μαΐου": "may", "ιουν": "june", "ιουνίου": "june", "ιούν": "june", "ιούνιος": "june", "ιουλ": "july", "ιουλίου": "july", "ιούλ": "july", "ιούλιος": "july", "αυγ": "august", "αυγούστου": "august", "αύγ": "august", "αύγουστος": "august", "σεπ": "september", "σεπτέμβριος": "september", "σεπτεμβρίου": "september", "οκτ": "october", "οκτωβρίου": "october", "οκτώβριος": "october", "νοέ": "november", "νοέμβριος": "november", "νοε": "november", "νοεμβρίου": "november", "δεκ": "december", "δεκέμβριος": "december", "δεκεμβρίου": "december", "ꭴꮓ": "january", "ꭴꮓꮈꮤꮕ": "january", "ꭷꭶ": "february", "ꭷꭶꮅ": "february", "ꭰꮕ": "march", "ꭰꮕᏹ": "march", "ꭷꮼ": "april", "ꭷꮼꮒ": "april", "ꭰꮒ": "may", "ꭰꮒꮝꭼꮨ": "may", "ꮥꭽ": "june", "ꮥꭽꮇᏹ": "june", "ꭻᏸ": "july", "ꭻᏸꮙꮒ": "july", "ꭶꮆ": "august", "ꭶꮆꮒ": "august", "ꮪꮅ": "september", "ꮪꮅꮝꮧ": "september", "ꮪꮒ": "october", "ꮪꮒꮕꮧ": "october", "ꮕꮣ": "november", "ꮕꮣꮥꮖ": "november", "ꭵꮝ": "december", "ꭵꮝꭹᏹ": "december", "januara": "january", "februara": "february", "měr": "march", "měrc": "march", "měrca": "march", "apryl": "april", "apryla": "april", "maja": "may", "junija": "june", "julija": "july", "awg": "august", "awgust": "august", "awgusta": "august", "septembra": "september", "oktobra": "october", "now": "november", "nowember": "november", "nowembra": "november", "decembra": "december", "zanwuye": "january", "feburuye": "february", "awirili": "april", "mɛ": "may", "zuw": "june", "zuwɛn": "june", "zul": "july", "zuluye": "july", "uti": "august", "sɛt": "september", "sɛtanburu": "september", "ɔku": "october", "ɔkutɔburu": "october", "nowanburu": "november", "desanburu": "december", "sty": "january", "styczeń": "january", "stycznia": "january", "Styczeń": "january", "Styczen": "january", "Stycznia": "january", "Styczniu": "january", "Sty": "january", "lut": "september", "lutego": "february", "luty": "february", "Luty": "february", "Lutego": "february", "Lutym": "february", "Lut": "february", "marca": "march", "marzec": "march", "Marzec": "march", "Marca": "march", "Marcu": "march", "Mar": "march", "kwi": "april", "kwiecień": "april", "kwietnia": "april", "Kwiecień": "april", "Kwiecien": "april", "Kwietnia": "april", "Kwietniu": "april", "Kwi": "april", "Kwie": "april", "Maj": "may", "Maja": "may", "Maju": "may", "cze": "june", "czerwca": "june", "czerwiec": "june", "Czerwiec": "june", "Czerwca": "june", "Czerwcu": "june", "Cze": "june", "lip": "june", "lipca": "july", "lipiec": "july", "Lipiec": "july", "Lipca": "july", "Lipcu": "july", "Lip": "july", "sie": "august", "sierpień": "august", "sierpnia": "august", "Sierpień": "august", "Sierpien": "august", "Sierpnia": "august", "Sierpniu": "august", "Sie": "august", "wrz": "september", "wrzesień": "september", "września": "september", "Wrzesień": "september", "Wrzesien": "september", "Września": "september", "Wrzesnia": "september", "Wrześniu": "september", "Wrzesniu": "september", "Wrz": "september", "paź": "october", "październik": "october", "października": "october", "Październik": "october", "Pazdziernik": "october", "Października": "october", "Pazdziernika": "october", "Październiku": "october", "Pazdzierniku": "october", "Paź": "october", "Paz": "october", "lis": "november", "listopad": "november", "listopada": "october", "Listopad": "november", "Listopada": "november", "Listopadzie": "november", "Lis": "november", "gru": "december", "grudnia": "december", "grudzień": "december", "Grudzień": "december", "Grudzien": "december", "Grudnia": "december", "Grudniu": "december", "Gru": "december", "xan": "january", "xaneiro": "january", "febreiro": "february", "marzo": "march", "maio": "may", "xuño": "june", "xul": "july", "xullo": "july", "setembro": "september", "outubro": "october", "novembro": "november", "decembro": "december", "јанвар": "january", "феврал": "february", "апрел": "april", "ијн": "june", "ијун": "june", "ијл": "july", "ијул": "july", "сентјабр": "september", "октјабр": "october", "ној": "november", "нојабр": "november", "декабр": "december", "dac": "january", "dwe mar achiel": "january", "dar": "february", "dwe mar ariyo": "february", "dad": "march", "dwe mar adek": "march", "dan": "april", "dwe mar ang'wen": "april", "dah": "may", "dwe mar abich": "may", "dau": "june", "dwe mar auchiel": "june", "dao": "july", "dwe mar abiriyo": "july", "dab": "august", "dwe mar aboro": "august", "doc": "september", "dwe mar ochiko": "september", "dap": "october", "dwe mar apar": "october", "dgi": "november", "dwe mar gi achiel": "november", "dag": "december", "dwe mar apar gi ariyo": "december", "ଜାନୁଆରୀ": "january", "ଫେବୃଆରୀ": "february", "ମାର୍ଚ୍ଚ": "march", "ଅପ୍ରେଲ": "april", "ମଇ": "may", "ଜୁନ": "june", "ଜୁଲାଇ": "july", "ଅଗଷ୍ଟ": "august", "ସେପ୍ଟେମ୍ବର": "september", "ଅକ୍ଟୋବର": "october", "ନଭେମ୍ବର": "november", "ଡିସେମ୍ବର": "december", "1-р сар": "january", "нэгдүгээр сар": "january", "2-р сар": "february", "хоёрдугаар сар": "february", "3-р сар": "march", "гуравдугаар сар": "march", "4-р сар": "april", "дөрөвдүгээр сар": "april", "5-р сар": "may", "тавдугаар сар": "may", "6-р сар": "june", "зургадугаар сар": "june", "7-р сар": "july", "долдугаар сар": "july", "8-р сар": "august", "наймдугаар сар": "august", "9-р сар": "september", "есдүгээр сар": "september", "10-р сар": "october", "аравдугаар сар": "october", "11-р сар": "november", "арван нэгдүгээр сар": "november", "12-р сар": "december", "арван хоёрдугаар сар": "december", "thg 1": "january", "tháng 1": "january", "Tháng một": "january", "thg 2": "february", "tháng 2": "february", "Tháng hai": "february", "thg 3": "march", "tháng 3": "march", "Tháng ba": "march", "thg 4": "april", "tháng 4": "april", "Tháng tư": "april", "thg 5": "may", "tháng 5": "may", "Tháng năm": "may", "thg 6": "june", "tháng 6": "june", "Tháng sáu": "june", "thg 7": "july", "tháng 7": "july", "Tháng bảy": "july", "thg 8": "august", "tháng 8": "august", "Tháng tám": "august", "thg 9": "september", "tháng 9": "september", "Tháng chín": "september", "thg 10": "october", "tháng 10": "october", "Tháng mười": "october", "thg 11": "november", "tháng 11": "november", "Tháng mười một": "november", "thg 12": "december", "tháng 12": "december", "Tháng mười hai": "december", "janv": "january", "janvāris": "january", "febr": "february", "februāris": "february", "marts": "march", "aprīlis": "april", "maijs": "may", "jūn": "june", "jūnijs": "june", "jūl": "july", "jūlijs": "july", "augusts": "august", "septembris": "september", "oktobris": "october", "novembris": "november", "decembris": "december", "қаң": "january", "қаңтар": "january", "ақп": "february", "ақпан": "february", "нау": "march", "наурыз": "march", "сәу": "april", "сәуір": "april", "мам": "may", "мамыр": "may", "мау": "june", "маусым": "june", "шіл": "july", "шілде": "july", "там": "august", "тамыз": "august", "қыр": "september", "қыркүйек": "september", "қаз": "october", "қазан": "october", "қар": "november", "қараша": "november", "жел": "december", "желтоқсан": "december", "janeiro": "january", "fevereiro": "february", "março": "march", "junho": "june", "julho": "july", "Septembro": "september", "dezembro": "december", "orara": "january", "rar": "march", "muk": "february", "omuk": "february", "kwa": "january", "okwamg'": "march", "dun": "april", "odung'el": "april", "omaruk": "may", "mod": "june", "omodok'king'ol": "june", "ojola": "july", "opedel": "august", "ped": "august", "osokosokoma": "september", "sok": "september", "otibar": "october", "tib": "october", "lab": "february", "olabor": "november", "opoo": "december", "poo": "december", "sánzá ya yambo": "january", "fbl": "february", "sánzá ya míbalé": "february", "msi": "march", "sánzá ya mísáto": "march", "apl": "april", "sánzá ya mínei": "april", "sánzá ya mítáno": "may", "sánzá ya motóbá": "june", "sánzá ya nsambo": "july", "sánzá ya mwambe": "august", "stb": "september", "sánzá ya libwa": "september", "sánzá ya zómi": "october", "ɔtb": "october", "nvb": "november", "sánzá ya zómi na mɔ̌kɔ́": "november", "dsb": "december", "sánzá ya zómi na míbalé": "december", "septembar": "september", "decembar": "december", "iməg mbegtug": "january", "mbegtug": "january", "imeg àbùbì": "february", "imeg mbəŋchubi": "march", "iməg ngwə̀t": "april", "iməg fog": "may", "iməg ichiibɔd": "june", "iməg àdùmbə̀ŋ": "july", "iməg ichika": "august", "iməg kud": "september", "iməg tèsi'e": "october", "iməg zò": "november", "iməg krizmed": "december", "جنؤری": "january", "فرؤری": "february", "مارٕچ": "march", "میٔ": "may", "جوٗن": "june", "جوٗلایی": "july", "اکتوٗبر": "october", "mej": "may", "meja": "may", "meje": "may", "mwedi ntandi": "january", "mwedi wa pili": "february", "mwedi wa tatu": "march", "mwedi wa nchechi": "april", "mwedi wa nnyano": "may", "mwedi wa nnyano na umo": "june", "mwedi wa nnyano na mivili": "july", "mwedi wa nnyano na mitatu": "august", "mwedi wa nnyano na nchechi": "september", "mwedi wa nnyano na nnyano": "october", "mwedi wa nnyano na nnyano na u": "november", "mwedi wa nnyano na nnyano na m": "december", "январы": "january", "февр": "february", "февралы": "february", "мартъи": "march", "мартъийы": "march", "апрелы": "april", "майы": "may", "июны": "june", "июлы": "july", "августы": "august", "сент": "september", "сентябры": "september", "октябры": "october", "нояб": "november", "ноябры": "november", "декабры": "december", "dal": "january", "oladalʉ́": "january", "ará": "february", "arát": "february", "ɔɛn": "march", "ɔɛnɨ́ɔɨŋɔk": "march", "doy": "april", "olodoyíóríê inkókúâ": "april", "lép": "may", "oloilépūnyīē inkókúâ": "may", "kújúɔrɔk": "june", "rok": "june", "mórusásin": "july", "sás": "july", "bɔ́r": "august", "ɔlɔ́ɨ́bɔ́rárɛ": "august", "kús": "september", "kúshîn": "september", "gís": "october", "olgísan": "october", "pʉshʉ́ka": "november", "shʉ́": "november", "ntʉ́": "december", "ntʉ́ŋʉ́s": "december", "ජන": "january", "ජනවාරි": "january", "පෙබ": "february", "පෙබරවාරි": "february", "මාර්": "march", "මාර්තු": "march", "අප්‍රේල්": "april", "මැයි": "may", "ජූනි": "june", "ජූලි": "july", "අගෝ": "august", "අගෝස්තු": "august", "සැප්": "september", "සැප්තැම්බර්": "september", "ඔක්": "october", "ඔක්තෝබර්": "october", "නොවැ": "november", "නොවැම්බර්": "november", "දෙසැ": "december", "දෙසැම්බර්": "december", "qul": "january", "qulla puquy": "january", "hat": "february", "hatun puquy": "february", "pau": "march", "pauqar waray": "march", "ayr": "april", "ayriwa": "april", "aym": "may", "aymuray": "may", "int": "june", "inti raymi": "june", "ant": "july", "anta sitwa": "july", "qha": "august", "qhapaq sitwa": "august", "uma": "september", "uma raymi": "september", "kantaray": "october", "aya": "november", "ayamarq'a": "november", "kap": "december", "kapaq raymi": "december", "જાન્યુ": "january", "જાન્યુઆરી": "january", "ફેબ્રુ": "february", "ફેબ્રુઆરી": "february", "માર્ચ": "march", "એપ્રિલ": "april", "મે": "may", "જૂન": "june", "જુલાઈ": "july", "ઑગસ્ટ": "august", "સપ્ટે": "september", "સપ્ટેમ્બર": "september", "ઑક્ટો": "october", "ઑક્ટોબર": "october", "નવે": "november", "નવેમ્બર": "november", "ડિસે": "december", "ડિસેમ્બર": "december", "ມກ": "january", "ມັງກອນ": "january", "ກພ": "february", "ກຸມພາ": "february", "ມນ": "march", "ມີນາ": "march", "ມສ": "april", "ເມສາ": "april", "ພພ": "may", "ພຶດສະພາ": "may", "ມິຖ": "june", "ມິຖຸນາ": "june", "ກລ": "july", "ກໍລະກົດ": "july", "ສຫ": "august", "ສິງຫາ": "august", "ກຍ": "september", "ກັນຍາ": "september", "ຕລ": "october", "ຕຸລາ": "october", "ພຈ": "november", "ພະຈິກ": "november", "ທວ": "december", "ທັນວາ": "december", "januarĩ": "january", "feburuarĩ": "february", "ĩpu": "april", "ĩpurũ": "april", "mĩĩ": "may", "nju": "june", "njuni": "june", "njr": "july", "njuraĩ": "july", "aga": "august", "agasti": "august", "spt": "september", "oktũba": "october", "dicemba": "december", "janar": "january", "shk": "february", "shkurt": "february", "pri": "april", "prill": "april", "qer": "june", "qershor": "june", "korrik": "july", "gsh": "august", "gusht": "august", "sht": "september", "shtator": "september", "tet": "october", "tetor": "october", "nën": "november", "nëntor": "november", "dhj": "december", "dhjetor": "december", "Enero": "january", "Ene": "january", "Pebrero": "february", "Peb": "february", "Marso": "march", "Abril": "april", "Abr": "april", "Mayo": "may", "May": "may", "Hunyo": "june", "Hun": "june", "Hulyo": "july", "Hul": "july", "Agosto": "august", "Ago": "august", "Setyembre": "september", "Set": "september", "Oktubre": "october", "Okt": "october", "Nobyembre": "november", "Nob": "november", "Disyembre": "december", "Dis": "december", "جنو": "january", "فبر": "february", "فبروری": "february", "مار": "march", "اپر": "april", "می": "may", "جول": "july", "جولای": "july", "اگس": "august", "سپت": "september", "سپتمبر": "september", "اکت": "october", "نوم": "november", "دسم": "december", "epr": "april", "epreo": "april", "oga": "august", "ogasti": "august", "disemba": "december", "o1": "january", "pikítíkítie, oólí ú kutúan": "january", "o2": "february", "siɛyɛ́, oóli ú kándíɛ": "february", "o3": "march", "ɔnsúmbɔl, oóli ú kátátúɛ": "march", "mesiŋ, oóli ú kénie": "april", "o4": "april", "ensil, oóli ú kátánuɛ": "may", "o5": "may", "o6": "june", "ɔsɔn": "june", "efute": "july", "o7": "july", "o8": "august", "pisuyú": "august", "imɛŋ i puɔs": "september", "o9": "september", "imɛŋ i putúk,oóli ú kátíɛ": "october", "o10": "october", "makandikɛ": "november", "o11": "november", "o12": "december", "pilɔndɔ́": "december", "ಜನ": "january", "ಜನವರಿ": "january", "ಫೆಬ್ರ": "february", "ಫೆಬ್ರವರಿ": "february", "ಮಾರ್ಚ್": "march", "ಏಪ್ರಿ": "april", "ಏಪ್ರಿಲ್": "april", "ಮೇ": "may", "ಜೂನ್": "june", "ಜುಲೈ": "july", "ಆಗ": "august", "ಆಗಸ್ಟ್": "august", "ಸೆಪ್ಟೆಂ": "september", "ಸೆಪ್ಟೆಂಬರ್": "september", "ಅಕ್ಟೋ": "october", "ಅಕ್ಟೋಬರ್": "october", "ನವೆಂ": "november", "ನವೆಂಬರ್": "november", "ಡಿಸೆಂ": "december", "ಡಿಸೆಂಬರ್": "december", "oṣù ṣẹ́rẹ́": "january", "ṣẹ́rẹ́": "january", "oṣù èrèlè": "february", "èrèlè": "february", "oṣù ẹrẹ̀nà": "march", "ẹrẹ̀nà": "march", "oṣù ìgbé": "april", "ìgbé": "april", "oṣù ẹ̀bibi": "may", "ẹ̀bibi": "may", "oṣù òkúdu": "june", "òkúdu": "june", "agẹmọ": "july", "oṣù agẹmọ": "july", "oṣù ògún": "august", "ògún": "august", "owewe": "september", "oṣù owewe": "september", "oṣù ọ̀wàrà": "october", "ọ̀wàrà": "october", "bélú": "november", "oṣù bélú": "november", "oṣù ọ̀pẹ̀": "december", "ọ̀pẹ̀": "december", "urt": "january", "urtarrila": "january", "ots": "february", "otsaila": "february", "martxoa": "march", "api": "april", "apirila": "april", "maiatza": "may", "eka": "june", "ekaina": "june", "uzt": "july", "uztaila": "july", "abu": "august", "abuztua": "august", "ira": "september", "iraila": "september", "urr": "october", "urria": "october", "aza": "november", "azaroa": "november", "abe": "december", "abendua": "december", "јануари": "january", "јуни": "june", "јули": "july", "ноем": "november", "января": "january", "февраля": "february", "Фев": "february", "марта": "march", "апреля": "april", "мая": "may", "июня": "june", "июля": "july", "августа": "august", "сентября": "september", "Сен": "september", "октября": "october", "ноября": "november", "Ноя": "november", "декабря": "december", "wiótheȟika wí": "january", "thiyóȟeyuŋka wí": "february", "ištáwičhayazaŋ wí": "march", "pȟežítȟo wí": "april", "čhaŋwápetȟo wí": "may", "wípazukȟa-wašté wí": "june", "čhaŋpȟásapa wí": "july", "wasútȟuŋ wí": "august", "čhaŋwápeǧi wí": "september", "čhaŋwápe-kasná wí": "october", "waníyetu wí": "november", "tȟahékapšuŋ wí": "december", "มกราคม": "january", "มค": "january", "เดือนมกราคม": "january", "มกรา": "january", "กพ": "february", "กุมภาพันธ์": "february", "เดือนกุมภาพันธ์": "february", "กุมภา": "february", "มีค": "march", "มีนาคม": "march", "เดือนมีนาคม": "march", "มีนา": "march", "เมย": "april", "เมษายน": "april", "เมษา": "april", "เดือนเมษายน": "april", "พค": "may", "พฤษภาคม": "may", "เดือนพฤษภาคม": "may", "พฤษภา": "may", "มิถุนายน": "june", "มิย": "june", "เดือนมิถุนายน": "june", "มิถุนา": "june", "กค": "july", "กรกฎาคม": "july", "เดือนกรกฏาคม": "july", "กรกฎา": "july", "สค": "august", "สิงหาคม": "august", "สิงหา": "august", "เดือนสิงหาคม": "august", "กย": "september", "กันยายน": "september", "กันยา": "september", "เดือนกันยายน": "september", "ตค": "october", "ตุลาคม": "october", "เดือนตุลาคม": "october", "ตุลา": "october", "พย": "november", "พฤศจิกายน": "november", "เดือนพฤศจิกายน": "november", "พฤศจิ": "november", "ธค": "december", "ธันวาคม": "december", "เดือนธันวาคม": "december", "ธันวา": "december", "tammi": "january", "tammik": "january", "tammikuu": "january", "tammikuuta": "january", "tammikuussa": "january", "helmi": "february", "helmik": "february", "helmikuu": "february", "helmikuuta": "february", "helmikuussa": "february", "maalis": "march", "maalisk": "march", "maaliskuu": "march", "maaliskuuta": "march", "maaliskuussa": "march", "huhti": "april", "huhtik": "april", "huhtikuu": "april", "huhtikuuta": "april", "huhtikuussa": "april", "touko": "may", "toukok": "may", "toukokuu": "may", "toukokuuta": "may", "toukokuussa": "may", "kesä": "june", "kesäk": "june", "kesäkuu": "june", "kesäkuuta": "june", "kesäkuussa": "june", "heinä": "july", "heinäk": "july", "heinäkuu": "july", "heinäkuuta": "july", "heinäkuussa": "july", "elo": "august", "elok": "august", "elokuu": "august", "elokuuta": "august", "elokuussa": "august", "syys": "september", "syysk": "september", "syyskuu": "september", "syyskuuta": "september", "Syyskuussa": "september", "loka": "october", "lokak": "october", "lokakuu": "october", "lokakuuta": "october", "Lokakuussa": "october", "marras": "november", "marrask": "november", "marraskuu": "november", "marraskuuta": "november", "Marraskuussa": "november", "joulu": "december", "jouluk": "december", "joulukuu": "december", "joulukuuta": "december", "Joulukuussa": "december", "январ": "january", "сентябр": "september", "октябр": "october", "ноябр": "november", "ogo": "august", "ogos": "august", "disember": "december", "schan": "january", "schaner": "january", "favr": "february", "favrer": "february", "avrigl": "april", "matg": "may", "zercl": "june", "zercladur": "june", "fan": "july", "fanadur": "july", "avust": "august", "sett": "september", "settember": "september", "october": "october", "sij": "january", "siječanj": "january", "siječnja": "january", "siječnju": "january", "siječnjem": "january", "velj": "february", "veljača": "february", "veljače": "february", "veljači": "february", "veljačom": "february", "ožu": "march", "ožujak": "march", "ožujka": "march", "ožujku": "march", "ožujkom": "march", "tra": "april", "travanj": "april", "travnja": "april", "travnju": "april", "travnjem": "april", "svi": "may", "svibanj": "may", "svibnja": "may", "svibnju": "may", "svibnjem": "may", "lipanj": "june", "lipnja": "june", "lipnju": "june", "lipnjem": "june", "srp": "august", "srpanj": "july", "srpnja": "july", "srpnju": "july", "srpnjem": "july", "kol": "august", "kolovoz": "august", "kolovoza": "august", "kolovozu": "august", "kolovozom": "august", "ruj": "september", "rujan": "september", "rujna": "september", "rujnu": "september", "rujnom": "september", "listopadu": "november", "listopadom": "october", "stu": "november", "studeni": "november", "studenoga": "november", "studenom": "november", "studenomu": "november", "studenome": "november", "studenog": "november", "studenim": "november", "pro": "december", "prosinac": "december", "prosinca": "december", "prosincu": "december", "prosincem": "december", "mweri wa kwanza": "january", "mweri wa kaili": "february", "mweri wa katatu": "march", "mweri wa kaana": "april", "mweri wa tanu": "may", "mweri wa sita": "june", "mweri wa saba": "july", "mweri wa nane": "august", "mweri wa tisa": "september", "mweri wa ikumi": "october", "mweri wa ikumi na moja": "november", "mweri wa ikumi na mbili": "december", "ജനു": "january", "ജനുവരി": "january", "ഫെബ്രു": "february", "ഫെബ്രുവരി": "february", "മാർ": "march", "മാർച്ച്": "march", "ഏപ്രി": "april", "ഏപ്രിൽ": "april", "മേയ്": "may", "ജൂൺ": "june", "ജൂലൈ": "july", "ഓഗ": "august", "ഓഗസ്റ്റ്": "august", "സെപ്റ്റം": "september", "സെപ്റ്റംബർ": "september", "ഒക്ടോ": "october", "ഒക്‌ടോബർ": "october", "നവം": "november", "നവംബർ": "november", "ഡിസം": "december", "ഡിസംബർ": "december", "kɔn": "january", "kɔndɔŋ": "january", "màcɛ̂l": "february", "mat": "april", "màtùmb": "march", "mto": "april", "màtop": "april", "mpu": "may", "m̀puyɛ": "may", "hil": "june", "hìlòndɛ̀": "june", "nje": "july", "njèbà": "july", "hik": "august", "hìkaŋ": "august", "dip": "september", "dìpɔ̀s": "september", "bio": "october", "bìòôm": "october", "màyɛsèp": "november", "liɓ": "december", "lìbuy li ńyèe": "december", "ཟླ་བ་དང་པོ": "january", "ཟླ་བ་དང་པོ་": "january", "ཟླ་བ་གཉིས་པ": "february", "ཟླ་བ་གཉིས་པ་": "february", "ཟླ་བ་གསུམ་པ": "march", "ཟླ་བ་གསུམ་པ་": "march", "ཟླ་བ་བཞི་པ": "april", "ཟླ་བ་བཞི་པ་": "april", "ཟླ་བ་ལྔ་པ": "may", "ཟླ་བ་ལྔ་པ་": "may", "ཟླ་བ་དྲུག་པ": "june", "ཟླ་བ་དྲུག་པ་": "june", "ཟླ་བ་བདུན་པ": "july", "ཟླ་བ་བདུན་པ་": "july", "ཟླ་བ་བརྒྱད་པ": "august", "ཟླ་བ་བརྒྱད་པ་": "august", "ཟླ་བ་དགུ་པ": "september", "ཟླ་བ་དགུ་པ་": "september", "ཟླ་བ་བཅུ་པ": "october", "ཟླ་བ་བཅུ་པ་": "october", "ཟླ་བ་བཅུ་གཅིག་པ": "november", "ཟླ་བ་བཅུ་གཅིག་པ་": "november", "ཟླ་བ་བཅུ་གཉིས་པ": "december", "ཟླ་བ་བཅུ་གཉིས་པ་": "december", "tiop": "january", "tiop thar pɛt": "january", "pɛt": "february", "duɔ̱ɔ̱": "march", "duɔ̱ɔ̱ŋ": "march", "guak": "april", "duä": "may", "duät": "may", "kornyoot": "june", "pay": "november", "pay yie̱tni": "july", "thoo": "august", "tho̱o̱r": "august", "tɛɛ": "september", "tɛɛr": "september", "laa": "october", "laath": "october", "kur": "march", "tid": "december", "tio̱p in di̱i̱t": "december", "ndzɔ̀ŋɔ̀nùm": "january", "nùm": "january", "kɨz": "february", "ndzɔ̀ŋɔ̀kɨ̀zùʔ": "february", "ndzɔ̀ŋɔ̀tɨ̀dʉ̀ghà": "march", "tɨd": "march", "ndzɔ̀ŋɔ̀tǎafʉ̄ghā": "april", "taa": "march", "ndzɔ̀ŋèsèe": "may", "ndzɔ̀ŋɔ̀nzùghò": "june", "nzu": "june", "dum": "july", "ndzɔ̀ŋɔ̀dùmlo": "july", "fɔe": "august", "ndzɔ̀ŋɔ̀kwîfɔ̀e": "august", "dzu": "september", "ndzɔ̀ŋɔ̀tɨ̀fʉ̀ghàdzughù": "september", "lɔm": "october", "ndzɔ̀ŋɔ̀ghǔuwelɔ̀m": "october", "kaa": "november", "ndzɔ̀ŋɔ̀chwaʔàkaa wo": "november", "fwo": "december", "ndzɔ̀ŋèfwòo": "december", "jen": "january", "njenuarĩ": "january", "mwere wa kerĩ": "february", "wkr": "february", "mwere wa gatatũ": "march", "wgt": "march", "mwere wa kana": "april", "wkn": "april", "mwere wa gatano": "may", "wtn": "may", "mwere wa gatandatũ": "june", "wtd": "june", "mwere wa mũgwanja": "july", "wmj": "july", "mwere wa kanana": "august", "wnn": "august", "mwere wa kenda": "september", "wkd": "september", "mwere wa ikũmi": "october", "wik": "october", "mwere wa ikũmi na ũmwe": "november", "wmw": "november", "dit": "december", "ndithemba": "december", "s-ɔ": "january", "sanda-ɔpɛpɔn": "january", "k-ɔ": "february", "kwakwar-ɔgyefuo": "february", "e-ɔ": "march", "ebɔw-ɔbenem": "march", "e-o": "april", "ebɔbira-oforisuo": "april", "e-k": "may", "esusow aketseaba-kɔtɔnimba": "may", "o-a": "june", "obirade-ayɛwohomumu": "june", "a-k": "july", "ayɛwoho-kitawonsa": "july", "d-ɔ": "august", "difuu-ɔsandaa": "august", "f-ɛ": "september", "fankwa-ɛbɔ": "september", "ɔ-a": "october", "ɔbɛsɛ-ahinime": "october", "ɔ-o": "november", "ɔberɛfɛw-obubuo": "november", "m-ɔ": "december", "mumu-ɔpɛnimba": "december", "saus": "january", "sausio": "january", "sausis": "january", "vas": "february", "vasario": "february", "vasaris": "february", "kov": "march", "kovas": "march", "kovo": "march", "bal": "april", "balandis": "april", "balandžio": "april", "geg": "may", "gegužė": "may", "gegužės": "may", "birž": "june", "birželio": "june", "birželis": "june", "liep": "july", "liepa": "july", "liepos": "july", "rugp": "august", "rugpjūtis": "august", "rugpjūčio": "august", "rugs": "september", "rugsėjis": "september", "rugsėjo": "september", "spal": "october", "spalio": "october", "spalis": "october", "lapkr": "november", "lapkritis": "november", "lapkričio": "november", "gruod": "december", "gruodis": "december", "gruodžio": "december", "jannar": "january", "fra": "february", "frar": "february", "marzu": "march", "mejju": "may", "ġun": "june", "ġunju": "june", "lul": "july", "lulju": "july", "aww": "august", "awwissu": "august", "settembru": "september", "ott": "october", "ottubru": "october", "novembru": "november", "diċ": "december", "diċembru": "december", "január": "january", "I": "january", "február": "february", "II": "february", "márc": "march", "március": "march", "már": "march", "III": "march", "ápr": "april", "április": "april", "IV": "april", "máj": "may", "május": "may", "V": "may", "június": "june", "VI": "june", "július": "july", "VII": "july", "augusztus": "august", "VIII": "august", "szept": "september", "szeptember": "september", "IX": "september", "X": "october", "XI": "november", "XII": "december", "j-guer": "january", "jerrey-geuree": "january", "t-arree": "february", "toshiaght-arree": "february", "mayrnt": "march", "averil": "april", "avrril": "april", "boaldyn": "may", "m-souree": "june", "mean-souree": "june", "j-souree": "july", "jerrey-souree": "july", "luanistyn": "august", "m-fouyir": "september", "mean-fouyir": "september", "j-fouyir": "october", "jerrey-fouyir": "october", "m-houney": "november", "mee houney": "november", "m-nollick": "december", "mee ny nollick": "december", "sān": "january", "sānuali": "january", "fēp": "february", "fēpueli": "february", "ma'a": "march", "ma'asi": "march", "'epe": "april", "'epeleli": "april", "mē": "may", "sun": "june", "sune": "june", "siu": "july", "siulai": "july", "'aok": "august", "'aokosi": "august", "sepitema": "september", "'oka": "october", "'okatopa": "october", "nōv": "november", "nōvema": "november", "tīs": "december", "tīsema": "december", "জানু": "january", "জানুৱাৰী": "january", "ফেব্ৰু": "february", "ফেব্ৰুৱাৰী": "february", "মাৰ্চ": "march", "এপ্ৰিল": "april", "মে": "may", "জুন": "june", "জুলাই": "july", "আগ": "august", "আগষ্ট": "august", "ছেপ্তেম্বৰ": "september", "সেপ্ট": "september", "অক্টো": "october", "অক্টোবৰ": "october", "নভে": "november", "নৱেম্বৰ": "november", "ডিচেম্বৰ": "december", "ডিসে": "december", "di": "january", "dimɔ́di": "january", "ŋgɔn": "february", "ŋgɔndɛ": "february", "sɔŋ": "march", "sɔŋɛ": "march", "diɓ": "april", "diɓáɓá": "april", "emi": "may", "emiasele": "may", "esɔ": "june", "esɔpɛsɔpɛ": "june", "madiɓɛ́díɓɛ́": "july", "diŋ": "august", "diŋgindi": "august", "nyɛt": "september", "nyɛtɛki": "september", "mayésɛ́": "october", "tin": "november", "tiníní": "november", "elá": "december", "eláŋgɛ́": "december", "can": "january", "chanuari": "january", "feburari": "february", "apiriri": "april", "chulai": "july", "cul": "july", "okitoba": "october", "nobemba": "november", "janvier": "january", "févr": "february", "février": "february", "fév": "february", "juin": "june", "juil": "july", "juillet": "july", "août": "august", "aoû": "august", "septembre": "september", "octobre": "october", "novembre": "november", "déc": "december", "décembre": "december", "led": "january", "leden": "january", "ledna": "january", "úno": "february", "únor": "february", "února": "february", "únr": "february", "bře": "march", "březen": "march", "března": "march", "dub": "april", "duben": "april", "dubna": "april", "kvě": "may", "květen": "may", "května": "may", "červen": "june", "června": "june", "čvn": "june", "Čer": "june", "července": "july", "červenec": "july", "čvc": "july", "Črc": "july", "črv": "july", "srpen": "august", "srpna": "august", "zář": "september", "září": "september", "říj": "october", "říjen": "october", "října": "october", "prosince": "december", "prosinec": "december", "មករា": "january", "កុម្ភៈ": "february", "មីនា": "march", "មេសា": "april", "ឧសភា": "may", "មិថុនា": "june", "កក្កដា": "july", "សីហា": "august", "កញ្ញា": "september", "តុលា": "october", "វិច្ឆិកា": "november", "ធ្នូ": "december", "ndi": "january", "ndira": "january", "kuk": "february", "kukadzi": "february", "kurume": "march", "kub": "april", "kubvumbi": "april", "chivabvu": "may", "chv": "may", "chikumi": "june", "chk": "june", "chg": "july", "chikunguru": "july", "nya": "august", "nyamavhuvhu": "august", "gun": "september", "gunyana": "september", "gum": "october", "gumiguru": "october", "mbu": "november", "mbudzi": "november", "zvi": "december", "zvita": "december", "जन॰": "january", "फ़रवरी": "february", "फ़र॰": "february", "अप्रैल": "april", "जुल॰": "july", "अगस्त": "august", "अग॰": "august", "सितंबर": "september", "सित॰": "september", "सितम्बर": "september", "अक्तूबर": "october", "अक्तू॰": "october", "अक्टूबर": "october", "नवंबर": "november", "नव॰": "november", "नवम्बर": "november", "दिसंबर": "december", "दिस॰": "december", "दिसम्बर": "december", "січ": "january", "січень": "january", "січня": "january", "лют": "february", "лютий": "february", "лютого": "february", "бер": "march", "березень": "march", "березня": "march", "берез": "march", "кві": "april", "квіт": "april", "квітень": "april", "квітня": "april", "тра": "may", "трав": "may", "травень": "may", "травня": "may", "чер": "june", "черв": "june", "червень": "june", "червня": "june", "лип": "july", "липень": "july", "липня": "july", "сер": "august", "серп": "august", "серпень": "august", "серпня": "august", "вер": "september", "вересень": "september", "вересня": "september", "верес": "september", "жов": "october", "жовт": "october", "жовтень": "october", "жовтня": "october", "лис": "november", "лист": "november", "листопад": "november", "листопада": "november", "листоп": "november", "гру": "december", "груд": "december", "грудень": "december", "грудня": "december", "saŋ tsetsɛ̀ɛ lùm": "january", "saŋ kàg ngwóŋ": "february", "saŋ lepyè shúm": "march", "saŋ cÿó": "april", "saŋ tsɛ̀ɛ cÿó": "may", "saŋ njÿolá'": "june", "saŋ tyɛ̀b tyɛ̀b mbʉ̀ŋ": "july", "saŋ mbʉ̀ŋ": "august", "saŋ ngwɔ̀' mbÿɛ": "september", "saŋ tàŋa tsetsá'": "october", "saŋ mejwoŋó": "november", "saŋ lùm": "december", "Jun": "june", "Jul": "july", "augusti": "august", "ꋍꆪ": "january", "ꑍꆪ": "february", "ꌕꆪ": "march", "ꇖꆪ": "april", "ꉬꆪ": "may", "ꃘꆪ": "june", "ꏃꆪ": "july", "ꉆꆪ": "august", "ꈬꆪ": "september", "ꊰꆪ": "october", "ꊰꊪꆪ": "november", "ꊰꑋꆪ": "december", "mut": "january", "nzero": "january", "gas": "february", "ruhuhuma": "february", "ntwarante": "march", "wer": "march", "ndamukiza": "april", "gic": "may", "rusama": "may", "kam": "june", "ruheshi": "june", "mukakaro": "july", "nyandagaro": "august", "nyakanga": "july", "nze": "september", "gitugutu": "october", "ukw": "october", "munyonyo": "november", "ugu": "november", "kigarama": "december", "uku": "december", "january": "january", "february": "february", "march": "march", "june": "june", "july": "july", "fur": "february", "fuṛar": "february", "meɣ": "march", "meɣres": "march", "yebrir": "april", "yulyu": "july", "cte": "september", "ctembeṛ": "september", "tub": "october", "tubeṛ": "october", "nun": "november", "nunembeṛ": "november", "duǧ": "december", "duǧembeṛ": "december", "inn": "january", "innayr": "january", "bṛa": "february", "bṛayṛ": "february", "maṛ": "march", "maṛṣ": "march", "ktu": "october", "ktubr": "october", "nuwanbir": "november", "oca": "january", "ocak": "january", "Oc": "january", "şub": "february", "şubat": "february", "Şu": "february", "nis": "april", "nisan": "april", "Ni": "april", "mayıs": "may", "haz": "june", "haziran": "june", "Ha": "june", "tem": "july", "temmuz": "july", "Te": "july", "ağu": "august", "ağustos": "august", "Ağ": "august", "eyl": "september", "eylül": "september", "Ey": "september", "eki": "october", "ekim": "october", "Ek": "october", "kas": "may", "kasım": "november", "Ka": "november", "ara": "december", "aralık": "december", "Ar": "december", "martsi": "march", "maji": "may", "augustusi": "august", "septemberi": "september", "oktoberi": "october", "novemberi": "november", "decemberi": "december", "bisha koobaad": "january", "kob": "january", "bisha labaad": "february", "bisha saddexaad": "march", "sad": "november", "afr": "april", "bisha afraad": "april", "bisha shanaad": "may", "sha": "may", "bisha lixaad": "june", "lix": "june", "bisha todobaad": "july", "tod": "july", "bisha sideedaad": "august", "sid": "august", "bisha sagaalaad": "september", "sag": "september", "bisha tobnaad": "october", "tob": "november", "bisha kow iyo tobnaad": "november", "kit": "november", "bisha laba iyo tobnaad": "december", "lit": "december", "mutarama": "january", "gashyantare": "february", "werurwe": "march", "mata": "april", "gicuransi": "may", "kamena": "june", "kanama": "august", "nzeli": "september", "ukwakira": "october", "ugushyingo": "november", "ukuboza": "december", "mweri wo kwanza": "january", "mweri wo unayeli": "february", "una": "february", "mweri wo uneraru": "march", "che": "april", "mweri wo unecheshe": "april", "mweri wo unethanu": "may", "tha": "june", "moc": "june", "mweri wo thanu na mocha": "june", "mweri wo saba": "july", "mweri wo nane": "august", "mweri wo tisa": "september", "mweri wo kumi": "october", "moj": "november", "mweri wo kumi na moja": "november", "mweri wo kumi na yel'li": "december", "yel": "december", "ian": "january", "ianuarie": "january", "Febr": "february", "martie": "march", "Mart": "march", "aprilie": "april", "iun": "june", "iunie": "june", "iul": "july", "iulie": "july", "septembrie": "september", "Sep": "september", "octombrie": "october", "noiembrie": "november", "Noiem": "november", "decembrie": "december", "januaro": "january", "februaro": "february", "marto": "march", "aprilo": "april", "majo": "may", "junio": "june", "julio": "july", "aŭg": "august", "aŭgusto": "august", "septembro": "september", "oktobro": "october", "جنوري": "january", "فبروري": "february", "مۍ": "may", "اګست": "august", "genver": "january", "c'hwe": "february", "c'hwevrer": "february", "meur": "march", "meurzh": "march", "ebrel": "april", "mae": "may", "mezh": "june", "mezheven": "june", "goue": "july", "gouere": "july", "eost": "august", "gwen": "september", "gwengolo": "september", "here": "october", "ker": "december", "kerzu": "december", "kzu": "december", "ama": "january", "amajjii": "january", "gur": "february", "guraandhala": "february", "bit": "march", "bitooteessa": "march", "elb": "april", "elba": "april", "caamsa": "may", "cam": "may", "wax": "june", "waxabajjii": "june", "ado": "july", "adooleessa": "july", "hag": "august", "hagayya": "august", "fuulbana": "september", "onk": "october", "onkololeessa": "october", "sadaasa": "november", "mud": "december", "muddee": "december", "مئی": "may", "januali": "january", "febluali": "february", "aplili": "april", "febrero": "february", "septiembre": "september", "Setiembre": "september", "octubre": "october", "noviembre": "november", "diciembre": "december", "জানুয়ারী": "january", "জানুয়ারি": "january", "ফেব": "february", "ফেব্রুয়ারী": "february", "ফেব্রুয়ারি": "february", "মার্চ": "march", "এপ্রিল": "april", "আগস্ট": "august", "সেপ্টেম্বর": "september", "অক্টোবর": "october", "নভেম্বর": "november", "ডিসেম্বর": "december", "יאַנ": "january", "יאַנואַר": "january", "פֿעב": "february", "פֿעברואַר": "february", "מערץ": "march", "אַפּר": "april", "אַפּריל": "april", "מיי": "may", "אויג": "august", "אויגוסט": "august", "סעפּ": "september", "סעפּטעמבער": "september", "אקט": "october", "אקטאבער": "october", "נאוו": "november", "נאוועמבער": "november", "דעצ": "december", "דעצעמבער": "december", "januára": "january", "februára": "february", "apríla": "april", "mája": "may", "júna": "june", "júla": "july", "augusta": "august", "októbra": "october", "novembra": "november", "gennaio": "january", "febbraio": "february", "aprile": "april", "mag": "may", "maggio": "may", "giu": "june", "giugno": "june", "lug": "july", "luglio": "july", "settembre": "september", "ottobre": "october", "dicembre": "december", "de xineru": "january", "xin": "january", "xineru": "january", "de febreru": "february", "de marzu": "march", "d'abril": "april", "de mayu": "may", "de xunu": "june", "xun": "june", "xunu": "june", "de xunetu": "july", "xnt": "july", "xunetu": "july", "d'agostu": "august", "de setiembre": "september", "setiembre": "september", "d'ochobre": "october", "och": "october", "ochobre": "october", "de payares": "november", "payares": "november", "avi": "december", "avientu": "december", "d'avientu": "december", "cio": "january", "ciongo": "january", "lùishi": "february", "lus": "march", "lusòlo": "march", "muu": "april", "mùuyà": "april", "lum": "may", "lumùngùlù": "may", "luf": "june", "lufuimi": "june", "kab": "july", "kabàlàshìpù": "july", "lush": "august", "lùshìkà": "august", "lutongolo": "september", "lun": "october", "lungùdi": "october", "kaswèkèsè": "november", "cis": "december", "ciswà": "december", "januwari": "january", "umasingana": "january", "februwari": "february", "mas": "june", "mashi": "march", "eph": "april", "ephreli": "april", "mey": "may", "meyi": "may", "julayi": "july", "septhemba": "september", "okthoba": "october", "يناير": "january", "فبراير": "february", "مارس": "march", "أبريل": "april", "مايو": "may", "يونيو": "june", "يوليو": "july", "أغسطس": "august", "سبتمبر": "september", "أكتوبر": "october", "نوفمبر": "november", "ديسمبر": "december", "ژانویه": "january", "فوریه": "february", "آوریل": "april", "مه": "may", "ژوئن": "june", "ژوئیه": "july", "اوت": "august", "سپتامبر": "september", "اکتبر": "october", "نوامبر": "november", "دسامبر": "december", "ŋ1": "january", "ŋwíí a ntɔ́ntɔ": "january", "ŋ2": "february", "ŋwíí akǝ bɛ́ɛ": "february", "ŋ3": "march", "ŋwíí akǝ ráá": "march", "ŋ4": "april", "ŋwíí akǝ nin": "april", "ŋ5": "may", "ŋwíí akǝ táan": "may", "ŋ6": "june", "ŋwíí akǝ táafɔk": "june", "ŋ7": "july", "ŋwíí akǝ táabɛɛ": "july", "ŋ8": "august", "ŋwíí akǝ táaraa": "august", "ŋ9": "september", "ŋwíí akǝ táanin": "september", "ŋ10": "october", "ŋwíí akǝ ntɛk": "october", "ŋ11": "november", "ŋwíí akǝ ntɛk di bɔ́k": "november", "ŋ12": "december", "ŋwíí akǝ ntɛk di bɛ́ɛ": "december", "am faoilleach": "january", "dhen fhaoilleach": "january", "faoi": "january", "an gearran": "february", "dhen ghearran": "february", "gearr": "february", "am màrt": "march", "dhen mhàrt": "march", "màrt": "march", "an giblean": "april", "dhen ghiblean": "april", "gibl": "april", "an cèitean": "may", "cèit": "may", "dhen chèitean": "may", "an t-ògmhios": "june", "dhen ògmhios": "june", "ògmh": "june", "an t-iuchar": "july", "dhen iuchar": "july", "iuch": "july", "an lùnastal": "august", "dhen lùnastal": "august", "lùna": "august", "an t-sultain": "september", "dhen t-sultain": "september", "sult": "september", "an dàmhair": "october", "dhen dàmhair": "october", "dàmh": "october", "an t-samhain": "november", "dhen t-samhain": "november", "samh": "november", "an dùbhlachd": "december", "dhen dùbhlachd": "december", "dùbh": "december", "сту": "january", "студзень": "january", "студзеня": "january", "Стд": "january", "лютага": "february", "люты": "february", "сак": "march", "сакавік": "march", "сакавіка": "march", "кра": "april", "красавік": "april", "красавіка": "april", "Крс": "april", "Траўня": "may", "Тра": "may", "чэр": "june", "чэрвень": "june", "чэрвеня": "june", "ліп": "july", "ліпень": "july", "ліпеня": "july", "жні": "august", "жнівень": "august", "жніўня": "august", "Жнівеня": "august", "Жнв": "august", "верасень": "september", "верасня": "september", "Врс": "september", "кас": "october", "кастрычнік": "october", "кастрычніка": "october", "Кст": "october", "ліс": "november", "лістапад": "november", "лістапада": "november", "Ліс": "november", "сне": "december", "снежань": "december", "снежня": "december", "Снж": "december", "کانوونی دووەم": "january", "شوبات": "february", "ئازار": "march", "نیسان": "april", "ئایار": "may", "حوزەیران": "june", "تەمووز": "july", "ئاب": "august", "ئەیلوول": "september", "تشرینی یەکەم": "october", "تشرینی دووەم": "november", "کانونی یەکەم": "december", "fevreiro": "february", "marco": "march", "augusto": "august", "otubro": "october", "lapa le obo": "january", "obo": "january", "lapa le waare": "february", "waa": "february", "lapa le okuni": "march", "oku": "march", "lapa le ong'wan": "april", "ong": "april", "ime": "may", "lapa le imet": "may", "ile": "june", "lapa le ile": "june", "lapa le sapa": "july", "sap": "july", "isi": "august", "lapa le isiet": "august", "lapa le saal": "september", "saa": "september", "lapa le tomon": "october", "tom": "october", "lapa le tomon obo": "november", "lapa le tomon waare": "december", "tow": "december", "uđiv": "january", "uđđâivemáánu": "january", "kuovâ": "february", "kuovâmáánu": "february", "njuhčâ": "march", "njuhčâmáánu": "march", "cuáŋui": "april", "cuáŋuimáánu": "april", "vyesi": "may", "vyesimáánu": "may", "kesi": "june", "kesimáánu": "june", "syeini": "july", "syeinimáánu": "july", "porge": "august", "porgemáánu": "august", "čohčâ": "september", "čohčâmáánu": "september", "roovvâd": "october", "roovvâdmáánu": "october", "skammâ": "november", "skammâmáánu": "november", "juovlâ": "december", "juovlâmáánu": "december", "jenụwarị": "january", "febrụwarị": "february", "maachị": "march", "eprel": "april", "juun": "june", "julaị": "july", "ọgọ": "august", "ọgọọst": "august", "ọkt": "october", "ọktoba": "october", "जानुवारी": "january", "मार्स": "march", "एफ्रिल": "april", "जुलाइ": "july", "आगस्थ": "august", "सेबथेज्ब़र": "september", "अखथबर": "october", "नबेज्ब़र": "november", "दिसेज्ब़र": "december", "జన": "january", "జనవరి": "january", "ఫిబ్ర": "february", "ఫిబ్రవరి": "february", "మార్చి": "march", "ఏప్రి": "april", "ఏప్రిల్": "april", "మే": "may", "జూన్": "june", "జులై": "july", "ఆగ": "august", "ఆగస్టు": "august", "సెప్టెం": "september", "సెప్టెంబర్": "september", "అక్టో": "october", "అక్టోబర్": "october", "నవం": "november", "నవంబర్": "november", "డిసెం": "december", "డిసెంబర్": "december", "fúngatɨ": "january", "kʉfúngatɨ": "january", "kʉnaanɨ": "february", "naanɨ": "february", "keenda": "march", "kʉkeenda": "march", "ikúmi": "april", "kwiikumi": "april", "inyambala": "may", "kwiinyambála": "may", "idwaata": "june", "kwiidwaata": "june", "kʉmʉʉnchɨ": "july", "mʉʉnchɨ": "july", "kʉvɨɨrɨ": "august", "vɨɨrɨ": "august", "kʉsaatʉ": "september", "saatʉ": "september", "inyi": "october", "kwiinyi": "october", "kʉsaano": "november", "saano": "november", "kʉsasatʉ": "december", "sasatʉ": "december", "ion": "january", "ionawr": "january", "chw": "february", "chwef": "february", "chwefror": "february", "maw": "march", "mawrth": "march", "ebrill": "april", "meh": "june", "mehefin": "june", "gorff": "july", "gorffennaf": "july", "awst": "august", "medi": "september", "hyd": "october", "hydref": "october", "tach": "november", "tachwedd": "november", "rhag": "december", "rhagfyr": "december", "jenner": "january", "hor": "february", "hornig": "february", "märze": "march", "abrille": "april", "meije": "may", "brá": "june", "bráčet": "june", "hei": "july", "heiwet": "july", "öig": "august", "öigšte": "august", "her": "september", "herbštmánet": "september", "wím": "october", "wímánet": "october", "win": "november", "wintermánet": "november", "chr": "december", "chrištmánet": "december", "ओगस्ट": "august", "सेप्टेंबर": "september", "ओक्टोबर": "october", "ianuali": "january", "pep": "february", "pepeluali": "february", "mal": "march", "malaki": "march", "'ap": "april", "'apelila": "april", "iune": "june", "iulai": "july", "'au": "august", "'aukake": "august", "kep": "september", "kepakemapa": "september", "'ok": "october", "'okakopa": "october", "nowemapa": "november", "kek": "december", "kekemapa": "december", "mäe": "march", "mäerz": "march", "abrëll": "april", "dezember": "december", "ஜன": "january", "ஜனவரி": "january", "பிப்": "february", "பிப்ரவரி": "february", "மார்": "march", "மார்ச்": "march", "ஏப்": "april", "ஏப்ரல்": "april", "மே": "may", "ஜூன்": "june", "ஜூலை": "july", "ஆக": "august", "ஆகஸ்ட்": "august", "செப்": "september", "செப்டம்பர்": "september", "அக்": "october", "அக்டோபர்": "october", "நவ": "november", "நவம்பர்": "november", "டிச": "december", "டிசம்பர்": "december", "de gen": "january", "de gener": "january", "gener": "january", "de febr": "february", "de febrer": "february", "febrer": "february", "de març": "march", "d'abr": "april", "de maig": "may", "maig": "may", "de juny": "june", "juny": "june", "de jul": "july", "de juliol": "july", "juliol": "july", "ag": "august", "agost": "august", "d'ag": "august", "d'agost": "august", "de set": "september", "de setembre": "september", "setembre": "september", "d'oct": "october", "d'octubre": "october", "de nov": "november", "de novembre": "november", "de des": "december", "de desembre": "december", "desembre": "december", "Jänner": "january", "Feber": "february", "Mrz": "march", "ođđajagemánnu": "january", "ođđj": "january", "guov": "february", "guovvamánnu": "february", "njuk": "march", "njukčamánnu": "march", "cuo": "april", "cuoŋománnu": "april", "mies": "may", "miessemánnu": "may", "geas": "june", "geassemánnu": "june", "suoi": "july", "suoidnemánnu": "july", "borg": "august", "borgemánnu": "august", "čakč": "september", "čakčamánnu": "september", "golg": "october", "golggotmánnu": "october", "skáb": "november", "skábmamánnu": "november", "juov": "december", "juovlamánnu": "december", "jannewaris": "january", "febrewaris": "february", "maaie": "may", "septimber": "september", "novimber": "november", "desimber": "december", "ean": "january", "eanáir": "january", "feabh": "february", "feabhra": "february", "márta": "march", "aib": "april", "aibreán": "april", "beal": "may", "bealtaine": "may", "meith": "june", "meitheamh": "june", "iúil": "july", "lún": "august", "lúnasa": "august", "meán fómhair": "september", "mfómh": "september", "deireadh fómhair": "october", "dfómh": "october", "samhain": "november", "noll": "december", "nollaig": "december", "mul": "january", "mulgul": "january", "ng'atyaato": "february", "ngat": "february", "kiptaamo": "march", "iwo": "april", "iwootkuut": "april", "mamuut": "may", "paa": "june", "paagi": "june", "ng'eiyeet": "july", "nge": "september", "roo": "august", "rooptui": "august", "bur": "september", "bureet": "september", "epe": "october", "epeeso": "october", "kipsuunde ne taai": "november", "kpt": "november", "kipsuunde nebo aeng'": "december", "kpa": "december", "mup": "january", "mupalangulwa": "january", "mwi": "february", "mwitope": "february", "msh": "march", "mushende": "march", "mun": "april", "munyi": "april", "mushende magali": "may", "muj": "june", "mujimbi": "june", "msp": "july", "mushipepo": "july", "mpg": "august", "mupuguto": "august", "munyense": "september", "mye": "september", "mok": "october", "mokhu": "october", "mus": "november", "musongandembwe": "november", "muh": "december", "muhaano": "december", "jannewa": "january", "fäb": "february", "fäbrowa": "february", "mäz": "march", "määz": "march", "aprell": "april", "ouj": "august", "oujoß": "august", "säp": "september", "oktohber": "october", "ژانویهٔ": "january", "فوریهٔ": "february", "مهٔ": "may", "ژوئیهٔ": "july", "آگست": "august", "ng1": "january", "ngwɛn matáhra": "january", "ng2": "february", "ngwɛn ńmba": "february", "ng3": "march", "ngwɛn ńlal": "march", "ng4": "april", "ngwɛn ńna": "april", "ng5": "may", "ngwɛn ńtan": "may", "ng6": "june", "ngwɛn ńtuó": "june", "ng7": "july", "ngwɛn hɛmbuɛrí": "july", "ng8": "august", "ngwɛn lɔmbi": "august", "ng9": "september", "ngwɛn rɛbvuâ": "september", "ng10": "october", "ngwɛn wum": "october", "ng11": "november", "ngwɛn wum navǔr": "november", "kris": "december", "krísimin": "december", "dzove": "january", "dzv": "january", "dzd": "february", "dzodze": "february", "ted": "march", "tedoxe": "march", "afɔ": "april", "afɔfĩe": "april", "dam": "may", "dama": "may", "masa": "june", "sia": "july", "siamlɔm": "july", "dea": "august", "deasiamime": "august", "any": "september", "anyɔnyɔ": "september", "kel": "february", "kele": "october", "ade": "november", "adeɛmekpɔxe": "november", "dzm": "december", "dzome": "december", "janoary": "january", "febroary": "february", "martsa": "march", "aprily": "april", "jon": "june", "jona": "june", "jolay": "july", "aog": "august", "aogositra": "august", "septambra": "september", "novambra": "november", "desambra": "december", "imb": "january", "mori ghwa imbiri": "january", "kaw": "february", "mori ghwa kawi": "february", "kad": "march", "mori ghwa kadadu": "march", "mori ghwa kana": "april", "mori ghwa kasanu": "may", "kar": "june", "mori ghwa karandadu": "june", "mori ghwa mfungade": "july", "mori ghwa wunyanya": "august", "wun": "august", "ike": "september", "mori ghwa ikenda": "september", "mori ghwa ikumi": "october", "mori ghwa ikumi na imweri": "november", "iwi": "december", "mori ghwa ikumi na iwi": "december", "mwai wa mbee": "january", "mwai wa kelĩ": "february", "ktũ": "march", "mwai wa katatũ": "march", "mwai wa kana": "april", "mwai wa katano": "may", "mwai wa thanthatũ": "june", "moo": "july", "mwai wa muonza": "july", "mwai wa nyaanya": "august", "knd": "september", "mwai wa kenda": "september", "mwai wa ĩkumi": "october", "ĩku": "october", "mwai wa ĩkumi na ĩmwe": "november", "ĩkm": "november", "mwai wa ĩkumi na ilĩ": "december", "ĩkl": "december", "тохс": "january", "тохсунньу": "january", "олун": "february", "олунньу": "february", "клн": "march", "кулун тутар": "march", "мсу": "april", "муус устар": "april", "ыам": "may", "ыам ыйа": "may", "ыам ыйын": "may", "бэс": "june", "бэс ыйа": "june", "бэс ыйын": "june", "от ыйа": "july", "от ыйын": "july", "отй": "july", "атр": "august", "атырдьых ыйа": "august", "атырдьых ыйын": "august", "балаҕан ыйа": "september", "балаҕан ыйын": "september", "блҕ": "september", "алт": "october", "алтынньы": "october", "сэт": "november", "сэтинньи": "november", "ахс": "december", "ахсынньы": "december", "ngo": "january", "ngɔn osú": "january", "ngɔn bɛ̌": "february", "ngl": "march", "ngɔn lála": "march", "ngn": "april", "ngɔn nyina": "april", "ngt": "may", "ngɔn tána": "may", "ngs": "june", "ngɔn saməna": "june", "ngz": "july", "ngɔn zamgbála": "july", "ngm": "august", "ngɔn mwom": "august", "ngɔn ebulú": "september", "nga": "october", "ngɔn awóm": "october", "ngad": "november", "ngɔn awóm ai dziá": "november", "ngab": "december", "ngɔn awóm ai bɛ̌": "december", "جانڤیە": "january", "فئڤریە": "february", "آڤریل": "april", "جوٙأن": "june", "جوٙلا": "july", "آگوست": "august", "سئپتامر": "september", "ئوکتوڤر": "october", "نوڤامر": "november", "دئسامر": "december"}"#;
+    let seed = r#"{"ม.ค.": "january", "ก.พ.": "february", "มี.ค.": "march", "เม.ย.": "april", "พ.ค.": "may", "มิ.ย.": "june", "ก.ค.": "july", "ส.ค.": "august", "ก.ย.": "september", "ต.ค.": "october", "พ.ย.": "november", "ธ.ค.": "december","zib": "january", "zibandlela": "january", "nhlo": "february", "nhlolanja": "february", "mbi": "june", "mbimbitho": "march", "mab": "april", "mabasa": "april", "nkw": "may", "nkwenkwezi": "may", "nhla": "june", "nhlangula": "june", "ntu": "july", "ntulikazi": "july", "ncw": "august", "ncwabakazi": "august", "mpan": "september", "mpandula": "september", "mfu": "july", "mfumfu": "october", "lwe": "november", "lwezi": "november", "mpal": "december", "mpalakazi": "december", "jan": "january", "janairu": "january", "fab": "february", "faburairu": "february", "mar": "march", "maris": "march", "afi": "april", "afirilu": "april", "may": "may", "mayu": "may", "yun": "june", "yuni": "june", "yul": "july", "yuli": "july", "agu": "august", "agusta": "august", "sat": "september", "satumba": "september", "okt": "october", "oktoba": "october", "nuw": "november", "nuwamba": "november", "dis": "december", "disamba": "december", "januar": "january", "feb": "february", "februar": "february", "mär": "march", "märz": "march", "apr": "april", "april": "april", "mai": "may", "jun": "june", "juni": "june", "jul": "july", "juli": "july", "aug": "august", "auguscht": "august", "sep": "september", "septämber": "september", "oktoober": "october", "nov": "november", "novämber": "november", "dez": "december", "dezämber": "december", "ⵉⵏⵏ": "january", "ⵉⵏⵏⴰⵢⵔ": "january", "ⴱⵕⴰ": "february", "ⴱⵕⴰⵢⵕ": "february", "ⵎⴰⵕ": "march", "ⵎⴰⵕⵚ": "march", "ⵉⴱⵔ": "april", "ⵉⴱⵔⵉⵔ": "april", "ⵎⴰⵢ": "may", "ⵎⴰⵢⵢⵓ": "may", "ⵢⵓⵏ": "june", "ⵢⵓⵏⵢⵓ": "june", "ⵢⵓⵍ": "july", "ⵢⵓⵍⵢⵓⵣ": "july", "ⵖⵓⵛ": "august", "ⵖⵓⵛⵜ": "august", "ⵛⵓⵜ": "september", "ⵛⵓⵜⴰⵏⴱⵉⵔ": "september", "ⴽⵜⵓ": "october", "ⴽⵜⵓⴱⵔ": "october", "ⵏⵓⵡ": "november", "ⵏⵓⵡⴰⵏⴱⵉⵔ": "november", "ⴷⵓⵊ": "december", "ⴷⵓⵊⴰⵏⴱⵉⵔ": "december", "janwaliyo": "january", "febwaliyo": "february", "marisi": "march", "apu": "april", "apuli": "april", "maa": "may", "maayi": "may", "juu": "june", "juuni": "june", "julaayi": "july", "agusito": "august", "seb": "september", "sebuttemba": "september", "oki": "october", "okitobba": "october", "novemba": "november", "des": "december", "desemba": "december", "januari": "january", "februari": "february", "mac": "march", "machi": "march", "aprili": "april", "mei": "may", "julai": "july", "ago": "august", "agosti": "august", "septemba": "september", "dec": "december", "հնվ": "january", "հունվար": "january", "հունվարի": "january", "փետրվար": "february", "փետրվարի": "february", "փտվ": "february", "մարտ": "march", "մարտի": "march", "մրտ": "march", "ապր": "april", "ապրիլ": "april", "ապրիլի": "april", "մայիս": "may", "մայիսի": "may", "մյս": "may", "հնս": "june", "հունիս": "june", "հունիսի": "june", "հլս": "july", "հուլիս": "july", "հուլիսի": "july", "օգոստոս": "august", "օգոստոսի": "august", "օգս": "august", "սեպ": "september", "սեպտեմբեր": "september", "սեպտեմբերի": "september", "հոկ": "october", "հոկտեմբեր": "october", "հոկտեմբերի": "october", "նոյ": "november", "նոյեմբեր": "november", "նոյեմբերի": "november", "դեկ": "december", "դեկտեմբեր": "december", "դեկտեմբերի": "december", "ינואר": "january", "ינו׳": "january", "לינואר": "january", "בינואר": "january", "ינו": "january", "פברואר": "february", "פבר׳": "february", "בפברואר": "february", "לפברואר": "february", "פבר": "february", "מרץ": "march", "במרץ": "march", "למרץ": "march", "מרס": "march", "במרס": "march", "למרס": "march", "מארס": "march", "במארס": "march", "למארס": "march", "אפריל": "april", "אפר׳": "april", "באפריל": "april", "לאפריל": "april", "אפר": "april", "מאי": "may", "במאי": "may", "למאי": "may", "יוני": "june", "ביוני": "june", "ליוני": "june", "יונ": "june", "יולי": "july", "ביולי": "july", "ליולי": "july", "יול": "july", "אוגוסט": "august", "אוג׳": "august", "באוגוסט": "august", "לאוגוסט": "august", "אוג": "august", "ספטמבר": "september", "ספט׳": "september", "בספטמבר": "september", "לספטמבר": "september", "ספט": "september", "אוקטובר": "october", "אוק׳": "october", "באוקטובר": "october", "לאוקטובר": "october", "אוק": "october", "נובמבר": "november", "נוב׳": "november", "בנובמבר": "november", "לנובמבר": "november", "נוב": "november", "דצמבר": "december", "דצמ׳": "december", "בדצמבר": "december", "לדצמבר": "december", "דצמ": "december", "яну": "january", "януари": "january", "ян": "january", "фев": "february", "февруари": "february", "фв": "february", "март": "march", "апр": "april", "април": "april", "ап": "april", "май": "may", "юни": "june", "юн": "june", "юли": "july", "юл": "july", "авг": "august", "август": "august", "сеп": "september", "септември": "september", "септ": "september", "септм": "september", "окт": "october", "октомври": "october", "ное": "november", "ноември": "november", "дек": "december", "декември": "december", "zan": "january", "zanvie": "january", "fev": "february", "fevriye": "february", "mars": "march", "avr": "april", "avril": "april", "me": "may", "zin": "june", "zil": "july", "zilye": "july", "out": "october", "septam": "september", "oktob": "october", "novam": "november", "desam": "december", "يانۋار": "january", "فېۋرال": "february", "مارت": "march", "ئاپرېل": "april", "ماي": "may", "ئىيۇن": "june", "ئىيۇل": "july", "ئاۋغۇست": "august", "سېنتەبىر": "september", "ئۆكتەبىر": "october", "نويابىر": "november", "دېكابىر": "december", "kbz": "january", "okwokubanza": "january", "kbr": "february", "okwakabiri": "february", "kst": "march", "okwakashatu": "march", "kkn": "april", "okwakana": "april", "ktn": "may", "okwakataana": "may", "kmk": "june", "okwamukaaga": "june", "kms": "july", "okwamushanju": "july", "kmn": "august", "okwamunaana": "august", "kmw": "september", "okwamwenda": "september", "kkm": "october", "okwaikumi": "october", "knk": "november", "okwaikumi na kumwe": "november", "knb": "december", "okwaikumi na ibiri": "december", "იან": "january", "იანვარი": "january", "თებ": "february", "თებერვალი": "february", "მარ": "march", "მარტი": "march", "აპრ": "april", "აპრილი": "april", "მაი": "may", "მაისი": "may", "ივნ": "june", "ივნისი": "june", "ივლ": "july", "ივლისი": "july", "აგვ": "august", "აგვისტო": "august", "სექ": "september", "სექტემბერი": "september", "ოქტ": "october", "ოქტომბერი": "october", "ნოე": "november", "ნოემბერი": "november", "დეკ": "december", "დეკემბერი": "december", "1月": "january", "2月": "february", "3月": "march", "4月": "april", "5月": "may", "6月": "june", "7月": "july", "8月": "august", "9月": "september", "10月": "october", "11月": "november", "12月": "december", "一月": "january", "二月": "february", "三月": "march", "四月": "april", "五月": "may", "六月": "june", "七月": "july", "八月": "august", "九月": "september", "十月": "october", "十一月": "november", "十二月": "december", "sii": "january", "siilo": "january", "col": "february", "colte": "february", "mbo": "march", "mbooy": "march", "see": "may", "seeɗto": "april", "duu": "may", "duujal": "may", "kor": "june", "korse": "june", "mor": "july", "morso": "july", "juk": "august", "juko": "august", "siilto": "september", "slt": "september", "yar": "october", "yarkomaa": "october", "jol": "july", "jolal": "november", "bow": "december", "bowte": "december", "yan": "january", "yanvar": "january", "fevral": "february", "mart": "march", "aprel": "april", "iyn": "june", "iyun": "june", "iyl": "july", "iyul": "july", "avg": "august", "avgust": "august", "sen": "september", "sentabr": "september", "oktabr": "october", "noy": "november", "noyabr": "november", "dek": "december", "dekabr": "december", "янв": "january", "январь": "january", "февраль": "february", "мар": "march", "апрель": "april", "июн": "june", "июнь": "june", "июл": "july", "июль": "july", "сен": "september", "сентябрь": "september", "октябрь": "october", "ноя": "november", "ноябрь": "november", "декабрь": "december", "ཟླ་༡": "january", "ཟླ་དངཔ་": "january", "སྤྱི་ཟླ་དངཔ་": "january", "ཟླ་༢": "february", "ཟླ་གཉིས་པ་": "february", "སྤྱི་ཟླ་གཉིས་པ་": "february", "ཟླ་༣": "march", "ཟླ་གསུམ་པ་": "march", "སྤྱི་ཟླ་གསུམ་པ་": "march", "ཟླ་༤": "april", "ཟླ་བཞི་པ་": "april", "སྤྱི་ཟླ་བཞི་པ": "april", "ཟླ་༥": "may", "ཟླ་ལྔ་པ་": "may", "སྤྱི་ཟླ་ལྔ་པ་": "may", "ཟླ་༦": "june", "ཟླ་དྲུག་པ": "june", "སྤྱི་ཟླ་དྲུག་པ": "june", "ཟླ་༧": "july", "ཟླ་བདུན་པ་": "july", "སྤྱི་ཟླ་བདུན་པ་": "july", "ཟླ་༨": "august", "ཟླ་བརྒྱད་པ་": "august", "སྤྱི་ཟླ་བརྒྱད་པ་": "august", "ཟླ་༩": "september", "ཟླ་དགུ་པ་": "september", "སྤྱི་ཟླ་དགུ་པ་": "september", "ཟླ་༡༠": "october", "ཟླ་བཅུ་པ་": "october", "སྤྱི་ཟླ་བཅུ་པ་": "october", "ཟླ་༡༡": "november", "ཟླ་བཅུ་གཅིག་པ་": "november", "སྤྱི་ཟླ་བཅུ་གཅིག་པ་": "november", "ཟླ་༡༢": "december", "ཟླ་བཅུ་གཉིས་པ་": "december", "སྤྱི་ཟླ་བཅུ་གཉིས་པ་": "december", "hut": "january", "pa mwedzi gwa hutala": "january", "pa mwedzi gwa wuvili": "february", "vil": "february", "dat": "march", "pa mwedzi gwa wudatu": "march", "pa mwedzi gwa wutai": "april", "tai": "april", "han": "may", "pa mwedzi gwa wuhanu": "may", "pa mwedzi gwa sita": "june", "sit": "june", "pa mwedzi gwa saba": "july", "sab": "july", "nan": "august", "pa mwedzi gwa nane": "august", "pa mwedzi gwa tisa": "september", "tis": "september", "kum": "october", "pa mwedzi gwa kumi": "october", "kmj": "november", "pa mwedzi gwa kumi na moja": "november", "kmb": "december", "pa mwedzi gwa kumi na mbili": "december", "žan": "january", "žanwiye": "january", "fee": "february", "feewiriye": "february", "marsi": "march", "awi": "april", "awiril": "april", "žuw": "june", "žuweŋ": "june", "žuy": "july", "žuyye": "july", "ut": "august", "sek": "september", "sektanbur": "september", "oktoobur": "october", "noo": "november", "noowanbur": "november", "dee": "december", "deesanbur": "december", "sa": "january", "sanvie": "january", "fe": "february", "fébirie": "february", "ma": "march", "ab": "april", "aburil": "april", "mee": "may", "su": "june", "sueŋ": "june", "sú": "july", "súuyee": "july", "se": "september", "settembar": "september", "ok": "october", "oktobar": "october", "no": "november", "novembar": "november", "de": "december", "disambar": "december", "aprilyi": "april", "junyi": "june", "julyai": "july", "agusti": "august", "ene": "january", "enero": "january", "peb": "february", "pebrero": "february", "marso": "march", "abr": "april", "abril": "april", "mayo": "may", "hun": "june", "hunyo": "june", "hul": "july", "hulyo": "july", "agosto": "august", "set": "september", "setyembre": "september", "oktubre": "october", "nob": "november", "nobyembre": "november", "disyembre": "december", "جنوری": "january", "فروری": "february", "مارچ": "march", "اپریل": "april", "مئ": "may", "جون": "june", "جولائی": "july", "اگست": "august", "ستمبر": "september", "اکتوبر": "october", "نومبر": "november", "دسمبر": "december", "maret": "march", "agt": "august", "agustus": "august", "Agu": "august", "september": "september", "Sept": "september", "oktober": "october", "november": "november", "desember": "december", "i̇yun": "june", "i̇yul": "july", "avq": "august", "avqust": "august", "sentyabr": "september", "oktyabr": "october", "जनवरी": "january", "फेब्रुअरी": "february", "मार्च": "march", "अप्रिल": "april", "मई": "may", "मे": "may", "जुन": "june", "जुलाई": "july", "अगस्ट": "august", "सेप्टेम्बर": "september", "अक्टोबर": "october", "नोभेम्बर": "november", "डिसेम्बर": "december", "nye": "january", "nyenye": "january", "ful": "september", "fulundïgi": "february", "mbä": "march", "mbängü": "march", "ngu": "april", "ngubùe": "april", "bêl": "may", "bêläwü": "may", "fön": "june", "föndo": "june", "len": "july", "lengua": "july", "kük": "august", "kükürü": "august", "mvu": "september", "mvuka": "september", "ngb": "february", "ngberere": "october", "nab": "november", "nabändüru": "november", "kak": "december", "kakauka": "december", "januarie": "january", "februarie": "february", "maart": "march", "mrt": "march", "junie": "june", "julie": "july", "augustus": "august", "ਜਨ": "january", "ਜਨਵਰੀ": "january", "ਫ਼ਰ": "february", "ਫ਼ਰਵਰੀ": "february", "ਮਾਰਚ": "march", "ਅਪ੍ਰੈ": "april", "ਅਪ੍ਰੈਲ": "april", "ਮਈ": "may", "ਜੂਨ": "june", "ਜੁਲਾ": "july", "ਜੁਲਾਈ": "july", "ਅਗ": "august", "ਅਗਸਤ": "august", "ਸਤੰ": "september", "ਸਤੰਬਰ": "september", "ਅਕਤੂ": "october", "ਅਕਤੂਬਰ": "october", "ਨਵੰ": "november", "ਨਵੰਬਰ": "november", "ਦਸੰ": "december", "ਦਸੰਬਰ": "december", "nduŋmbi saŋ": "january", "pɛsaŋ pɛ́pá": "february", "pɛsaŋ pɛ́tát": "march", "pɛsaŋ pɛ́nɛ́kwa": "april", "pɛsaŋ pataa": "may", "pɛsaŋ pɛ́nɛ́ntúkú": "june", "pɛsaŋ saambá": "july", "pɛsaŋ pɛ́nɛ́fɔm": "august", "pɛsaŋ pɛ́nɛ́pfúꞌú": "september", "pɛsaŋ nɛgɛ́m": "october", "pɛsaŋ ntsɔ̌pmɔ́": "november", "pɛsaŋ ntsɔ̌ppá": "december", "mbe": "january", "mweri wa mbere": "january", "kai": "february", "mweri wa kaĩri": "february", "kat": "march", "mweri wa kathatũ": "march", "kan": "april", "mweri wa kana": "april", "gat": "may", "mweri wa gatano": "may", "gan": "june", "mweri wa gatantatũ": "june", "mug": "july", "mweri wa mũgwanja": "july", "knn": "august", "mweri wa kanana": "august", "ken": "september", "mweri wa kenda": "september", "iku": "october", "mweri wa ikũmi": "october", "imw": "november", "mweri wa ikũmi na ũmwe": "november", "igi": "december", "mweri wa ikũmi na kaĩrĩ": "december", "zen": "january", "zenâr": "january", "fevrâr": "february", "març": "march", "avrîl": "april", "jug": "june", "jugn": "june", "lui": "february", "avo": "august", "avost": "august", "setembar": "september", "otu": "october", "otubar": "october", "dic": "december", "dicembar": "december", "flo": "january", "fĩi loo": "january", "cla": "february", "cokcwaklaŋne": "february", "cki": "march", "cokcwaklii": "march", "fmf": "april", "fĩi marfoo": "april", "mad": "july", "madǝǝuutǝbijaŋ": "may", "mamǝŋgwãafahbii": "june", "mamǝŋgwãalii": "july", "mli": "july", "madǝmbii": "august", "mam": "may", "fde": "september", "fĩi dǝɓlii": "september", "fmu": "october", "fĩi mundaŋ": "october", "fgw": "november", "fĩi gwahlle": "november", "fyu": "december", "fĩi yuru": "december", "ဇန်": "january", "ဇန်နဝါရီ": "january", "ဖေ": "february", "ဖေဖော်ဝါရီ": "february", "မတ်": "march", "ဧ": "april", "ဧပြီ": "april", "မေ": "may", "ဇွန်": "june", "ဇူ": "july", "ဇူလိုင်": "july", "ဩ": "august", "ဩဂုတ်": "august", "စက်": "september", "စက်တင်ဘာ": "september", "အောက်": "october", "အောက်တိုဘာ": "october", "နို": "november", "နိုဝင်ဘာ": "november", "ဒီ": "december", "ဒီဇင်ဘာ": "december", "јан": "january", "јануар": "january", "феб": "february", "фебруар": "february", "мај": "may", "јун": "june", "јул": "july", "септембар": "september", "октобар": "october", "нов": "november", "новембар": "november", "дец": "december", "децембар": "december", "janeru": "january", "febreru": "february", "marsu": "march", "maiu": "may", "junhu": "june", "julhu": "july", "agostu": "august", "setenbru": "september", "otubru": "october", "nuv": "november", "nuvenbru": "november", "diz": "december", "dizenbru": "december", "janúar": "january", "febrúar": "february", "apríl": "april", "maí": "may", "jún": "june", "júní": "june", "júl": "july", "júlí": "july", "ágú": "august", "ágúst": "august", "október": "october", "nóv": "november", "nóvember": "november", "ጃንዩ": "january", "ጃንዩወሪ": "january", "ፌብሩ": "february", "ፌብሩወሪ": "february", "ማርች": "march", "ኤፕሪ": "april", "ኤፕሪል": "april", "ሜይ": "may", "ጁን": "june", "ጁላይ": "july", "ኦገስ": "august", "ኦገስት": "august", "ሴፕቴ": "september", "ሴፕቴምበር": "september", "ኦክቶ": "october", "ኦክቶበር": "october", "ኖቬም": "november", "ኖቬምበር": "november", "ዲሴም": "december", "ዲሴምበር": "december", "marec": "march", "maj": "may", "junij": "june", "julij": "july", "december": "december", "ǃkhanni": "january", "ǃkhanǀgôab": "february", "ǀkhuuǁkhâb": "march", "ǃhôaǂkhaib": "april", "ǃkhaitsâb": "may", "gamaǀaeb": "june", "ǂkhoesaob": "july", "aoǁkhuumûǁkhâb": "august", "taraǀkhuumûǁkhâb": "september", "oct": "october", "ǂnûǁnâiseb": "october", "ǀhooǂgaeb": "november", "hôasoreǁkhâb": "december", "1월": "january", "2월": "february", "3월": "march", "4월": "april", "5월": "may", "6월": "june", "7월": "july", "8월": "august", "9월": "september", "10월": "october", "11월": "november", "12월": "december", "august": "august", "jaan": "january", "jaanuar": "january", "veebr": "february", "veebruar": "february", "märts": "march", "aprill": "april", "juuli": "july", "sept": "september", "dets": "december", "detsember": "december", "yen": "january", "yennayer": "january", "yeb": "april", "yebrayer": "february", "ibr": "april", "ibrir": "april", "mayyu": "may", "yunyu": "june", "yulyuz": "july", "ɣuc": "august", "ɣuct": "august", "cut": "september", "cutanbir": "september", "kṭu": "october", "kṭuber": "october", "nwa": "november", "nwanbir": "november", "duj": "december", "dujanbir": "december", "gen": "january", "mis genver": "january", "hwe": "february", "mis hwevrer": "february", "meu": "march", "mis meurth": "march", "ebr": "april", "mis ebrel": "april", "mis me": "may", "met": "june", "mis metheven": "june", "gor": "july", "mis gortheren": "july", "est": "august", "mis est": "august", "gwn": "september", "mis gwynngala": "september", "hed": "october", "mis hedra": "october", "du": "november", "mis du": "november", "kev": "december", "mis kevardhu": "december", "ጥሪ": "january", "ለካ": "february", "ለካቲት": "february", "መጋ": "march", "መጋቢት": "march", "ሚያ": "april", "ሚያዝያ": "april", "ግን": "may", "ግንቦት": "may", "ሰነ": "june", "ሓም": "july", "ሓምለ": "july", "ነሓ": "august", "ነሓሰ": "august", "መስ": "september", "መስከረም": "september", "ጥቅ": "october", "ጥቅምቲ": "october", "ሕዳ": "november", "ሕዳር": "november", "ታሕ": "december", "ታሕሳስ": "december", "जाने": "january", "जानेवारी": "january", "फेब्रु": "february", "फेब्रुवारी": "february", "एप्रि": "april", "एप्रिल": "april", "जून": "june", "जुलै": "july", "ऑग": "august", "ऑगस्ट": "august", "सप्टें": "september", "सप्टेंबर": "september", "ऑक्टो": "october", "ऑक्टोबर": "october", "नोव्हें": "november", "नोव्हेंबर": "november", "डिसें": "december", "डिसेंबर": "december", "ιαν": "january", "ιανουάριος": "january", "ιανουαρίου": "january", "φεβ": "february", "φεβρουάριος": "february", "φεβρουαρίου": "february", "μάρ": "march", "μάρτιος": "march", "μαρ": "march", "μαρτίου": "march", "απρ": "april", "απρίλιος": "april", "απριλίου": "april", "μάι": "may", "μάιος": "may", "μαΐ": "may", "μαΐου": "may", "ιουν": "june", "ιουνίου": "june", "ιούν": "june", "ιούνιος": "june", "ιουλ": "july", "ιουλίου": "july", "ιούλ": "july", "ιούλιος": "july", "αυγ": "august", "αυγούστου": "august", "αύγ": "august", "αύγουστος": "august", "σεπ": "september", "σεπτέμβριος": "september", "σεπτεμβρίου": "september", "οκτ": "october", "οκτωβρίου": "october", "οκτώβριος": "october", "νοέ": "november", "νοέμβριος": "november", "νοε": "november", "νοεμβρίου": "november", "δεκ": "december", "δεκέμβριος": "december", "δεκεμβρίου": "december", "ꭴꮓ": "january", "ꭴꮓꮈꮤꮕ": "january", "ꭷꭶ": "february", "ꭷꭶꮅ": "february", "ꭰꮕ": "march", "ꭰꮕᏹ": "march", "ꭷꮼ": "april", "ꭷꮼꮒ": "april", "ꭰꮒ": "may", "ꭰꮒꮝꭼꮨ": "may", "ꮥꭽ": "june", "ꮥꭽꮇᏹ": "june", "ꭻᏸ": "july", "ꭻᏸꮙꮒ": "july", "ꭶꮆ": "august", "ꭶꮆꮒ": "august", "ꮪꮅ": "september", "ꮪꮅꮝꮧ": "september", "ꮪꮒ": "october", "ꮪꮒꮕꮧ": "october", "ꮕꮣ": "november", "ꮕꮣꮥꮖ": "november", "ꭵꮝ": "december", "ꭵꮝꭹᏹ": "december", "januara": "january", "februara": "february", "měr": "march", "měrc": "march", "měrca": "march", "apryl": "april", "apryla": "april", "maja": "may", "junija": "june", "julija": "july", "awg": "august", "awgust": "august", "awgusta": "august", "septembra": "september", "oktobra": "october", "now": "november", "nowember": "november", "nowembra": "november", "decembra": "december", "zanwuye": "january", "feburuye": "february", "awirili": "april", "mɛ": "may", "zuw": "june", "zuwɛn": "june", "zul": "july", "zuluye": "july", "uti": "august", "sɛt": "september", "sɛtanburu": "september", "ɔku": "october", "ɔkutɔburu": "october", "nowanburu": "november", "desanburu": "december", "sty": "january", "styczeń": "january", "stycznia": "january", "Styczeń": "january", "Styczen": "january", "Stycznia": "january", "Styczniu": "january", "Sty": "january", "lut": "september", "lutego": "february", "luty": "february", "Luty": "february", "Lutego": "february", "Lutym": "february", "Lut": "february", "marca": "march", "marzec": "march", "Marzec": "march", "Marca": "march", "Marcu": "march", "Mar": "march", "kwi": "april", "kwiecień": "april", "kwietnia": "april", "Kwiecień": "april", "Kwiecien": "april", "Kwietnia": "april", "Kwietniu": "april", "Kwi": "april", "Kwie": "april", "Maj": "may", "Maja": "may", "Maju": "may", "cze": "june", "czerwca": "june", "czerwiec": "june", "Czerwiec": "june", "Czerwca": "june", "Czerwcu": "june", "Cze": "june", "lip": "june", "lipca": "july", "lipiec": "july", "Lipiec": "july", "Lipca": "july", "Lipcu": "july", "Lip": "july", "sie": "august", "sierpień": "august", "sierpnia": "august", "Sierpień": "august", "Sierpien": "august", "Sierpnia": "august", "Sierpniu": "august", "Sie": "august", "wrz": "september", "wrzesień": "september", "września": "september", "Wrzesień": "september", "Wrzesien": "september", "Września": "september", "Wrzesnia": "september", "Wrześniu": "september", "Wrzesniu": "september", "Wrz": "september", "paź": "october", "październik": "october", "października": "october", "Październik": "october", "Pazdziernik": "october", "Października": "october", "Pazdziernika": "october", "Październiku": "october", "Pazdzierniku": "october", "Paź": "october", "Paz": "october", "lis": "november", "listopad": "november", "listopada": "october", "Listopad": "november", "Listopada": "november", "Listopadzie": "november", "Lis": "november", "gru": "december", "grudnia": "december", "grudzień": "december", "Grudzień": "december", "Grudzien": "december", "Grudnia": "december", "Grudniu": "december", "Gru": "december", "xan": "january", "xaneiro": "january", "febreiro": "february", "marzo": "march", "maio": "may", "xuño": "june", "xul": "july", "xullo": "july", "setembro": "september", "outubro": "october", "novembro": "november", "decembro": "december", "јанвар": "january", "феврал": "february", "апрел": "april", "ијн": "june", "ијун": "june", "ијл": "july", "ијул": "july", "сентјабр": "september", "октјабр": "october", "ној": "november", "нојабр": "november", "декабр": "december", "dac": "january", "dwe mar achiel": "january", "dar": "february", "dwe mar ariyo": "february", "dad": "march", "dwe mar adek": "march", "dan": "april", "dwe mar ang'wen": "april", "dah": "may", "dwe mar abich": "may", "dau": "june", "dwe mar auchiel": "june", "dao": "july", "dwe mar abiriyo": "july", "dab": "august", "dwe mar aboro": "august", "doc": "september", "dwe mar ochiko": "september", "dap": "october", "dwe mar apar": "october", "dgi": "november", "dwe mar gi achiel": "november", "dag": "december", "dwe mar apar gi ariyo": "december", "ଜାନୁଆରୀ": "january", "ଫେବୃଆରୀ": "february", "ମାର୍ଚ୍ଚ": "march", "ଅପ୍ରେଲ": "april", "ମଇ": "may", "ଜୁନ": "june", "ଜୁଲାଇ": "july", "ଅଗଷ୍ଟ": "august", "ସେପ୍ଟେମ୍ବର": "september", "ଅକ୍ଟୋବର": "october", "ନଭେମ୍ବର": "november", "ଡିସେମ୍ବର": "december", "1-р сар": "january", "нэгдүгээр сар": "january", "2-р сар": "february", "хоёрдугаар сар": "february", "3-р сар": "march", "гуравдугаар сар": "march", "4-р сар": "april", "дөрөвдүгээр сар": "april", "5-р сар": "may", "тавдугаар сар": "may", "6-р сар": "june", "зургадугаар сар": "june", "7-р сар": "july", "долдугаар сар": "july", "8-р сар": "august", "наймдугаар сар": "august", "9-р сар": "september", "есдүгээр сар": "september", "10-р сар": "october", "аравдугаар сар": "october", "11-р сар": "november", "арван нэгдүгээр сар": "november", "12-р сар": "december", "арван хоёрдугаар сар": "december", "thg 1": "january", "tháng 1": "january", "Tháng một": "january", "thg 2": "february", "tháng 2": "february", "Tháng hai": "february", "thg 3": "march", "tháng 3": "march", "Tháng ba": "march", "thg 4": "april", "tháng 4": "april", "Tháng tư": "april", "thg 5": "may", "tháng 5": "may", "Tháng năm": "may", "thg 6": "june", "tháng 6": "june", "Tháng sáu": "june", "thg 7": "july", "tháng 7": "july", "Tháng bảy": "july", "thg 8": "august", "tháng 8": "august", "Tháng tám": "august", "thg 9": "september", "tháng 9": "september", "Tháng chín": "september", "thg 10": "october", "tháng 10": "october", "Tháng mười": "october", "thg 11": "november", "tháng 11": "november", "Tháng mười một": "november", "thg 12": "december", "tháng 12": "december", "Tháng mười hai": "december", "janv": "january", "janvāris": "january", "febr": "february", "februāris": "february", "marts": "march", "aprīlis": "april", "maijs": "may", "jūn": "june", "jūnijs": "june", "jūl": "july", "jūlijs": "july", "augusts": "august", "septembris": "september", "oktobris": "october", "novembris": "november", "decembris": "december", "қаң": "january", "қаңтар": "january", "ақп": "february", "ақпан": "february", "нау": "march", "наурыз": "march", "сәу": "april", "сәуір": "april", "мам": "may", "мамыр": "may", "мау": "june", "маусым": "june", "шіл": "july", "шілде": "july", "там": "august", "тамыз": "august", "қыр": "september", "қыркүйек": "september", "қаз": "october", "қазан": "october", "қар": "november", "қараша": "november", "жел": "december", "желтоқсан": "december", "janeiro": "january", "fevereiro": "february", "março": "march", "junho": "june", "julho": "july", "Septembro": "september", "dezembro": "december", "orara": "january", "rar": "march", "muk": "february", "omuk": "february", "kwa": "january", "okwamg'": "march", "dun": "april", "odung'el": "april", "omaruk": "may", "mod": "june", "omodok'king'ol": "june", "ojola": "july", "opedel": "august", "ped": "august", "osokosokoma": "september", "sok": "september", "otibar": "october", "tib": "october", "lab": "february", "olabor": "november", "opoo": "december", "poo": "december", "sánzá ya yambo": "january", "fbl": "february", "sánzá ya míbalé": "february", "msi": "march", "sánzá ya mísáto": "march", "apl": "april", "sánzá ya mínei": "april", "sánzá ya mítáno": "may", "sánzá ya motóbá": "june", "sánzá ya nsambo": "july", "sánzá ya mwambe": "august", "stb": "september", "sánzá ya libwa": "september", "sánzá ya zómi": "october", "ɔtb": "october", "nvb": "november", "sánzá ya zómi na mɔ̌kɔ́": "november", "dsb": "december", "sánzá ya zómi na míbalé": "december", "septembar": "september", "decembar": "december", "iməg mbegtug": "january", "mbegtug": "january", "imeg àbùbì": "february", "imeg mbəŋchubi": "march", "iməg ngwə̀t": "april", "iməg fog": "may", "iməg ichiibɔd": "june", "iməg àdùmbə̀ŋ": "july", "iməg ichika": "august", "iməg kud": "september", "iməg tèsi'e": "october", "iməg zò": "november", "iməg krizmed": "december", "جنؤری": "january", "فرؤری": "february", "مارٕچ": "march", "میٔ": "may", "جوٗن": "june", "جوٗلایی": "july", "اکتوٗبر": "october", "mej": "may", "meja": "may", "meje": "may", "mwedi ntandi": "january", "mwedi wa pili": "february", "mwedi wa tatu": "march", "mwedi wa nchechi": "april", "mwedi wa nnyano": "may", "mwedi wa nnyano na umo": "june", "mwedi wa nnyano na mivili": "july", "mwedi wa nnyano na mitatu": "august", "mwedi wa nnyano na nchechi": "september", "mwedi wa nnyano na nnyano": "october", "mwedi wa nnyano na nnyano na u": "november", "mwedi wa nnyano na nnyano na m": "december", "январы": "january", "февр": "february", "февралы": "february", "мартъи": "march", "мартъийы": "march", "апрелы": "april", "майы": "may", "июны": "june", "июлы": "july", "августы": "august", "сент": "september", "сентябры": "september", "октябры": "october", "нояб": "november", "ноябры": "november", "декабры": "december", "dal": "january", "oladalʉ́": "january", "ará": "february", "arát": "february", "ɔɛn": "march", "ɔɛnɨ́ɔɨŋɔk": "march", "doy": "april", "olodoyíóríê inkókúâ": "april", "lép": "may", "oloilépūnyīē inkókúâ": "may", "kújúɔrɔk": "june", "rok": "june", "mórusásin": "july", "sás": "july", "bɔ́r": "august", "ɔlɔ́ɨ́bɔ́rárɛ": "august", "kús": "september", "kúshîn": "september", "gís": "october", "olgísan": "october", "pʉshʉ́ka": "november", "shʉ́": "november", "ntʉ́": "december", "ntʉ́ŋʉ́s": "december", "ජන": "january", "ජනවාරි": "january", "පෙබ": "february", "පෙබරවාරි": "february", "මාර්": "march", "මාර්තු": "march", "අප්‍රේල්": "april", "මැයි": "may", "ජූනි": "june", "ජූලි": "july", "අගෝ": "august", "අගෝස්තු": "august", "සැප්": "september", "සැප්තැම්බර්": "september", "ඔක්": "october", "ඔක්තෝබර්": "october", "නොවැ": "november", "නොවැම්බර්": "november", "දෙසැ": "december", "දෙසැම්බර්": "december", "qul": "january", "qulla puquy": "january", "hat": "february", "hatun puquy": "february", "pau": "march", "pauqar waray": "march", "ayr": "april", "ayriwa": "april", "aym": "may", "aymuray": "may", "int": "june", "inti raymi": "june", "ant": "july", "anta sitwa": "july", "qha": "august", "qhapaq sitwa": "august", "uma": "september", "uma raymi": "september", "kantaray": "october", "aya": "november", "ayamarq'a": "november", "kap": "december", "kapaq raymi": "december", "જાન્યુ": "january", "જાન્યુઆરી": "january", "ફેબ્રુ": "february", "ફેબ્રુઆરી": "february", "માર્ચ": "march", "એપ્રિલ": "april", "મે": "may", "જૂન": "june", "જુલાઈ": "july", "ઑગસ્ટ": "august", "સપ્ટે": "september", "સપ્ટેમ્બર": "september", "ઑક્ટો": "october", "ઑક્ટોબર": "october", "નવે": "november", "નવેમ્બર": "november", "ડિસે": "december", "ડિસેમ્બર": "december", "ມກ": "january", "ມັງກອນ": "january", "ກພ": "february", "ກຸມພາ": "february", "ມນ": "march", "ມີນາ": "march", "ມສ": "april", "ເມສາ": "april", "ພພ": "may", "ພຶດສະພາ": "may", "ມິຖ": "june", "ມິຖຸນາ": "june", "ກລ": "july", "ກໍລະກົດ": "july", "ສຫ": "august", "ສິງຫາ": "august", "ກຍ": "september", "ກັນຍາ": "september", "ຕລ": "october", "ຕຸລາ": "october", "ພຈ": "november", "ພະຈິກ": "november", "ທວ": "december", "ທັນວາ": "december", "januarĩ": "january", "feburuarĩ": "february", "ĩpu": "april", "ĩpurũ": "april", "mĩĩ": "may", "nju": "june", "njuni": "june", "njr": "july", "njuraĩ": "july", "aga": "august", "agasti": "august", "spt": "september", "oktũba": "october", "dicemba": "december", "janar": "january", "shk": "february", "shkurt": "february", "pri": "april", "prill": "april", "qer": "june", "qershor": "june", "korrik": "july", "gsh": "august", "gusht": "august", "sht": "september", "shtator": "september", "tet": "october", "tetor": "october", "nën": "november", "nëntor": "november", "dhj": "december", "dhjetor": "december", "Enero": "january", "Ene": "january", "Pebrero": "february", "Peb": "february", "Marso": "march", "Abril": "april", "Abr": "april", "Mayo": "may", "May": "may", "Hunyo": "june", "Hun": "june", "Hulyo": "july", "Hul": "july", "Agosto": "august", "Ago": "august", "Setyembre": "september", "Set": "september", "Oktubre": "october", "Okt": "october", "Nobyembre": "november", "Nob": "november", "Disyembre": "december", "Dis": "december", "جنو": "january", "فبر": "february", "فبروری": "february", "مار": "march", "اپر": "april", "می": "may", "جول": "july", "جولای": "july", "اگس": "august", "سپت": "september", "سپتمبر": "september", "اکت": "october", "نوم": "november", "دسم": "december", "epr": "april", "epreo": "april", "oga": "august", "ogasti": "august", "disemba": "december", "o1": "january", "pikítíkítie, oólí ú kutúan": "january", "o2": "february", "siɛyɛ́, oóli ú kándíɛ": "february", "o3": "march", "ɔnsúmbɔl, oóli ú kátátúɛ": "march", "mesiŋ, oóli ú kénie": "april", "o4": "april", "ensil, oóli ú kátánuɛ": "may", "o5": "may", "o6": "june", "ɔsɔn": "june", "efute": "july", "o7": "july", "o8": "august", "pisuyú": "august", "imɛŋ i puɔs": "september", "o9": "september", "imɛŋ i putúk,oóli ú kátíɛ": "october", "o10": "october", "makandikɛ": "november", "o11": "november", "o12": "december", "pilɔndɔ́": "december", "ಜನ": "january", "ಜನವರಿ": "january", "ಫೆಬ್ರ": "february", "ಫೆಬ್ರವರಿ": "february", "ಮಾರ್ಚ್": "march", "ಏಪ್ರಿ": "april", "ಏಪ್ರಿಲ್": "april", "ಮೇ": "may", "ಜೂನ್": "june", "ಜುಲೈ": "july", "ಆಗ": "august", "ಆಗಸ್ಟ್": "august", "ಸೆಪ್ಟೆಂ": "september", "ಸೆಪ್ಟೆಂಬರ್": "september", "ಅಕ್ಟೋ": "october", "ಅಕ್ಟೋಬರ್": "october", "ನವೆಂ": "november", "ನವೆಂಬರ್": "november", "ಡಿಸೆಂ": "december", "ಡಿಸೆಂಬರ್": "december", "oṣù ṣẹ́rẹ́": "january", "ṣẹ́rẹ́": "january", "oṣù èrèlè": "february", "èrèlè": "february", "oṣù ẹrẹ̀nà": "march", "ẹrẹ̀nà": "march", "oṣù ìgbé": "april", "ìgbé": "april", "oṣù ẹ̀bibi": "may", "ẹ̀bibi": "may", "oṣù òkúdu": "june", "òkúdu": "june", "agẹmọ": "july", "oṣù agẹmọ": "july", "oṣù ògún": "august", "ògún": "august", "owewe": "september", "oṣù owewe": "september", "oṣù ọ̀wàrà": "october", "ọ̀wàrà": "october", "bélú": "november", "oṣù bélú": "november", "oṣù ọ̀pẹ̀": "december", "ọ̀pẹ̀": "december", "urt": "january", "urtarrila": "january", "ots": "february", "otsaila": "february", "martxoa": "march", "api": "april", "apirila": "april", "maiatza": "may", "eka": "june", "ekaina": "june", "uzt": "july", "uztaila": "july", "abu": "august", "abuztua": "august", "ira": "september", "iraila": "september", "urr": "october", "urria": "october", "aza": "november", "azaroa": "november", "abe": "december", "abendua": "december", "јануари": "january", "јуни": "june", "јули": "july", "ноем": "november", "января": "january", "февраля": "february", "Фев": "february", "марта": "march", "апреля": "april", "мая": "may", "июня": "june", "июля": "july", "августа": "august", "сентября": "september", "Сен": "september", "октября": "october", "ноября": "november", "Ноя": "november", "декабря": "december", "wiótheȟika wí": "january", "thiyóȟeyuŋka wí": "february", "ištáwičhayazaŋ wí": "march", "pȟežítȟo wí": "april", "čhaŋwápetȟo wí": "may", "wípazukȟa-wašté wí": "june", "čhaŋpȟásapa wí": "july", "wasútȟuŋ wí": "august", "čhaŋwápeǧi wí": "september", "čhaŋwápe-kasná wí": "october", "waníyetu wí": "november", "tȟahékapšuŋ wí": "december", "มกราคม": "january", "มค": "january", "เดือนมกราคม": "january", "มกรา": "january", "มกราคม": "january", "กพ": "february", "กุมภาพันธ์": "february", "เดือนกุมภาพันธ์": "february", "กุมภา": "february", "มีค": "march", "มีนาคม": "march", "เดือนมีนาคม": "march", "มีนา": "march", "เมย": "april", "เมษายน": "april", "เมษา": "april", "เดือนเมษายน": "april", "พค": "may", "พฤษภาคม": "may", "เดือนพฤษภาคม": "may", "พฤษภา": "may", "มิถุนายน": "june", "มิย": "june", "เดือนมิถุนายน": "june", "มิถุนา": "june", "กค": "july", "กรกฎาคม": "july", "เดือนกรกฏาคม": "july", "เดือนกรกฎาคม": "july", "กรกฎา": "july", "สค": "august", "สิงหาคม": "august", "สิงหา": "august", "เดือนสิงหาคม": "august", "กย": "september", "กันยายน": "september", "กันยา": "september", "เดือนกันยายน": "september", "ตค": "october", "ตุลาคม": "october", "เดือนตุลาคม": "october", "ตุลา": "october", "พย": "november", "พฤศจิกายน": "november", "เดือนพฤศจิกายน": "november", "พฤศจิ": "november", "ธค": "december", "ธันวาคม": "december", "เดือนธันวาคม": "december", "ธันวา": "december", "tammi": "january", "tammik": "january", "tammikuu": "january", "tammikuuta": "january", "tammikuussa": "january", "helmi": "february", "helmik": "february", "helmikuu": "february", "helmikuuta": "february", "helmikuussa": "february", "maalis": "march", "maalisk": "march", "maaliskuu": "march", "maaliskuuta": "march", "maaliskuussa": "march", "huhti": "april", "huhtik": "april", "huhtikuu": "april", "huhtikuuta": "april", "huhtikuussa": "april", "touko": "may", "toukok": "may", "toukokuu": "may", "toukokuuta": "may", "toukokuussa": "may", "kesä": "june", "kesäk": "june", "kesäkuu": "june", "kesäkuuta": "june", "kesäkuussa": "june", "heinä": "july", "heinäk": "july", "heinäkuu": "july", "heinäkuuta": "july", "heinäkuussa": "july", "elo": "august", "elok": "august", "elokuu": "august", "elokuuta": "august", "elokuussa": "august", "syys": "september", "syysk": "september", "syyskuu": "september", "syyskuuta": "september", "Syyskuussa": "september", "loka": "october", "lokak": "october", "lokakuu": "october", "lokakuuta": "october", "Lokakuussa": "october", "marras": "november", "marrask": "november", "marraskuu": "november", "marraskuuta": "november", "Marraskuussa": "november", "joulu": "december", "jouluk": "december", "joulukuu": "december", "joulukuuta": "december", "Joulukuussa": "december", "январ": "january", "сентябр": "september", "октябр": "october", "ноябр": "november", "ogo": "august", "ogos": "august", "disember": "december", "schan": "january", "schaner": "january", "favr": "february", "favrer": "february", "avrigl": "april", "matg": "may", "zercl": "june", "zercladur": "june", "fan": "july", "fanadur": "july", "avust": "august", "sett": "september", "settember": "september", "october": "october", "sij": "january", "siječanj": "january", "siječnja": "january", "siječnju": "january", "siječnjem": "january", "velj": "february", "veljača": "february", "veljače": "february", "veljači": "february", "veljačom": "february", "ožu": "march", "ožujak": "march", "ožujka": "march", "ožujku": "march", "ožujkom": "march", "tra": "april", "travanj": "april", "travnja": "april", "travnju": "april", "travnjem": "april", "svi": "may", "svibanj": "may", "svibnja": "may", "svibnju": "may", "svibnjem": "may", "lipanj": "june", "lipnja": "june", "lipnju": "june", "lipnjem": "june", "srp": "august", "srpanj": "july", "srpnja": "july", "srpnju": "july", "srpnjem": "july", "kol": "august", "kolovoz": "august", "kolovoza": "august", "kolovozu": "august", "kolovozom": "august", "ruj": "september", "rujan": "september", "rujna": "september", "rujnu": "september", "rujnom": "september", "listopadu": "november", "listopadom": "october", "stu": "november", "studeni": "november", "studenoga": "november", "studenom": "november", "studenomu": "november", "studenome": "november", "studenog": "november", "studenim": "november", "pro": "december", "prosinac": "december", "prosinca": "december", "prosincu": "december", "prosincem": "december", "mweri wa kwanza": "january", "mweri wa kaili": "february", "mweri wa katatu": "march", "mweri wa kaana": "april", "mweri wa tanu": "may", "mweri wa sita": "june", "mweri wa saba": "july", "mweri wa nane": "august", "mweri wa tisa": "september", "mweri wa ikumi": "october", "mweri wa ikumi na moja": "november", "mweri wa ikumi na mbili": "december", "ജനു": "january", "ജനുവരി": "january", "ഫെബ്രു": "february", "ഫെബ്രുവരി": "february", "മാർ": "march", "മാർച്ച്": "march", "ഏപ്രി": "april", "ഏപ്രിൽ": "april", "മേയ്": "may", "ജൂൺ": "june", "ജൂലൈ": "july", "ഓഗ": "august", "ഓഗസ്റ്റ്": "august", "സെപ്റ്റം": "september", "സെപ്റ്റംബർ": "september", "ഒക്ടോ": "october", "ഒക്‌ടോബർ": "october", "നവം": "november", "നവംബർ": "november", "ഡിസം": "december", "ഡിസംബർ": "december", "kɔn": "january", "kɔndɔŋ": "january", "màcɛ̂l": "february", "mat": "april", "màtùmb": "march", "mto": "april", "màtop": "april", "mpu": "may", "m̀puyɛ": "may", "hil": "june", "hìlòndɛ̀": "june", "nje": "july", "njèbà": "july", "hik": "august", "hìkaŋ": "august", "dip": "september", "dìpɔ̀s": "september", "bio": "october", "bìòôm": "october", "màyɛsèp": "november", "liɓ": "december", "lìbuy li ńyèe": "december", "ཟླ་བ་དང་པོ": "january", "ཟླ་བ་དང་པོ་": "january", "ཟླ་བ་གཉིས་པ": "february", "ཟླ་བ་གཉིས་པ་": "february", "ཟླ་བ་གསུམ་པ": "march", "ཟླ་བ་གསུམ་པ་": "march", "ཟླ་བ་བཞི་པ": "april", "ཟླ་བ་བཞི་པ་": "april", "ཟླ་བ་ལྔ་པ": "may", "ཟླ་བ་ལྔ་པ་": "may", "ཟླ་བ་དྲུག་པ": "june", "ཟླ་བ་དྲུག་པ་": "june", "ཟླ་བ་བདུན་པ": "july", "ཟླ་བ་བདུན་པ་": "july", "ཟླ་བ་བརྒྱད་པ": "august", "ཟླ་བ་བརྒྱད་པ་": "august", "ཟླ་བ་དགུ་པ": "september", "ཟླ་བ་དགུ་པ་": "september", "ཟླ་བ་བཅུ་པ": "october", "ཟླ་བ་བཅུ་པ་": "october", "ཟླ་བ་བཅུ་གཅིག་པ": "november", "ཟླ་བ་བཅུ་གཅིག་པ་": "november", "ཟླ་བ་བཅུ་གཉིས་པ": "december", "ཟླ་བ་བཅུ་གཉིས་པ་": "december", "tiop": "january", "tiop thar pɛt": "january", "pɛt": "february", "duɔ̱ɔ̱": "march", "duɔ̱ɔ̱ŋ": "march", "guak": "april", "duä": "may", "duät": "may", "kornyoot": "june", "pay": "november", "pay yie̱tni": "july", "thoo": "august", "tho̱o̱r": "august", "tɛɛ": "september", "tɛɛr": "september", "laa": "october", "laath": "october", "kur": "march", "tid": "december", "tio̱p in di̱i̱t": "december", "ndzɔ̀ŋɔ̀nùm": "january", "nùm": "january", "kɨz": "february", "ndzɔ̀ŋɔ̀kɨ̀zùʔ": "february", "ndzɔ̀ŋɔ̀tɨ̀dʉ̀ghà": "march", "tɨd": "march", "ndzɔ̀ŋɔ̀tǎafʉ̄ghā": "april", "taa": "march", "ndzɔ̀ŋèsèe": "may", "ndzɔ̀ŋɔ̀nzùghò": "june", "nzu": "june", "dum": "july", "ndzɔ̀ŋɔ̀dùmlo": "july", "fɔe": "august", "ndzɔ̀ŋɔ̀kwîfɔ̀e": "august", "dzu": "september", "ndzɔ̀ŋɔ̀tɨ̀fʉ̀ghàdzughù": "september", "lɔm": "october", "ndzɔ̀ŋɔ̀ghǔuwelɔ̀m": "october", "kaa": "november", "ndzɔ̀ŋɔ̀chwaʔàkaa wo": "november", "fwo": "december", "ndzɔ̀ŋèfwòo": "december", "jen": "january", "njenuarĩ": "january", "mwere wa kerĩ": "february", "wkr": "february", "mwere wa gatatũ": "march", "wgt": "march", "mwere wa kana": "april", "wkn": "april", "mwere wa gatano": "may", "wtn": "may", "mwere wa gatandatũ": "june", "wtd": "june", "mwere wa mũgwanja": "july", "wmj": "july", "mwere wa kanana": "august", "wnn": "august", "mwere wa kenda": "september", "wkd": "september", "mwere wa ikũmi": "october", "wik": "october", "mwere wa ikũmi na ũmwe": "november", "wmw": "november", "dit": "december", "ndithemba": "december", "s-ɔ": "january", "sanda-ɔpɛpɔn": "january", "k-ɔ": "february", "kwakwar-ɔgyefuo": "february", "e-ɔ": "march", "ebɔw-ɔbenem": "march", "e-o": "april", "ebɔbira-oforisuo": "april", "e-k": "may", "esusow aketseaba-kɔtɔnimba": "may", "o-a": "june", "obirade-ayɛwohomumu": "june", "a-k": "july", "ayɛwoho-kitawonsa": "july", "d-ɔ": "august", "difuu-ɔsandaa": "august", "f-ɛ": "september", "fankwa-ɛbɔ": "september", "ɔ-a": "october", "ɔbɛsɛ-ahinime": "october", "ɔ-o": "november", "ɔberɛfɛw-obubuo": "november", "m-ɔ": "december", "mumu-ɔpɛnimba": "december", "saus": "january", "sausio": "january", "sausis": "january", "vas": "february", "vasario": "february", "vasaris": "february", "kov": "march", "kovas": "march", "kovo": "march", "bal": "april", "balandis": "april", "balandžio": "april", "geg": "may", "gegužė": "may", "gegužės": "may", "birž": "june", "birželio": "june", "birželis": "june", "liep": "july", "liepa": "july", "liepos": "july", "rugp": "august", "rugpjūtis": "august", "rugpjūčio": "august", "rugs": "september", "rugsėjis": "september", "rugsėjo": "september", "spal": "october", "spalio": "october", "spalis": "october", "lapkr": "november", "lapkritis": "november", "lapkričio": "november", "gruod": "december", "gruodis": "december", "gruodžio": "december", "jannar": "january", "fra": "february", "frar": "february", "marzu": "march", "mejju": "may", "ġun": "june", "ġunju": "june", "lul": "july", "lulju": "july", "aww": "august", "awwissu": "august", "settembru": "september", "ott": "october", "ottubru": "october", "novembru": "november", "diċ": "december", "diċembru": "december", "január": "january", "I": "january", "február": "february", "II": "february", "márc": "march", "március": "march", "már": "march", "III": "march", "ápr": "april", "április": "april", "IV": "april", "máj": "may", "május": "may", "V": "may", "június": "june", "VI": "june", "július": "july", "VII": "july", "augusztus": "august", "VIII": "august", "szept": "september", "szeptember": "september", "IX": "september", "X": "october", "XI": "november", "XII": "december", "j-guer": "january", "jerrey-geuree": "january", "t-arree": "february", "toshiaght-arree": "february", "mayrnt": "march", "averil": "april", "avrril": "april", "boaldyn": "may", "m-souree": "june", "mean-souree": "june", "j-souree": "july", "jerrey-souree": "july", "luanistyn": "august", "m-fouyir": "september", "mean-fouyir": "september", "j-fouyir": "october", "jerrey-fouyir": "october", "m-houney": "november", "mee houney": "november", "m-nollick": "december", "mee ny nollick": "december", "sān": "january", "sānuali": "january", "fēp": "february", "fēpueli": "february", "ma'a": "march", "ma'asi": "march", "'epe": "april", "'epeleli": "april", "mē": "may", "sun": "june", "sune": "june", "siu": "july", "siulai": "july", "'aok": "august", "'aokosi": "august", "sepitema": "september", "'oka": "october", "'okatopa": "october", "nōv": "november", "nōvema": "november", "tīs": "december", "tīsema": "december", "জানু": "january", "জানুৱাৰী": "january", "ফেব্ৰু": "february", "ফেব্ৰুৱাৰী": "february", "মাৰ্চ": "march", "এপ্ৰিল": "april", "মে": "may", "জুন": "june", "জুলাই": "july", "আগ": "august", "আগষ্ট": "august", "ছেপ্তেম্বৰ": "september", "সেপ্ট": "september", "অক্টো": "october", "অক্টোবৰ": "october", "নভে": "november", "নৱেম্বৰ": "november", "ডিচেম্বৰ": "december", "ডিসে": "december", "di": "january", "dimɔ́di": "january", "ŋgɔn": "february", "ŋgɔndɛ": "february", "sɔŋ": "march", "sɔŋɛ": "march", "diɓ": "april", "diɓáɓá": "april", "emi": "may", "emiasele": "may", "esɔ": "june", "esɔpɛsɔpɛ": "june", "madiɓɛ́díɓɛ́": "july", "diŋ": "august", "diŋgindi": "august", "nyɛt": "september", "nyɛtɛki": "september", "mayésɛ́": "october", "tin": "november", "tiníní": "november", "elá": "december", "eláŋgɛ́": "december", "can": "january", "chanuari": "january", "feburari": "february", "apiriri": "april", "chulai": "july", "cul": "july", "okitoba": "october", "nobemba": "november", "janvier": "january", "févr": "february", "février": "february", "fév": "february", "juin": "june", "juil": "july", "juillet": "july", "août": "august", "aoû": "august", "septembre": "september", "octobre": "october", "novembre": "november", "déc": "december", "décembre": "december", "led": "january", "leden": "january", "ledna": "january", "úno": "february", "únor": "february", "února": "february", "únr": "february", "bře": "march", "březen": "march", "března": "march", "dub": "april", "duben": "april", "dubna": "april", "kvě": "may", "květen": "may", "května": "may", "červen": "june", "června": "june", "čvn": "june", "Čer": "june", "července": "july", "červenec": "july", "čvc": "july", "Črc": "july", "črv": "july", "srpen": "august", "srpna": "august", "zář": "september", "září": "september", "říj": "october", "říjen": "october", "října": "october", "prosince": "december", "prosinec": "december", "មករា": "january", "កុម្ភៈ": "february", "មីនា": "march", "មេសា": "april", "ឧសភា": "may", "មិថុនា": "june", "កក្កដា": "july", "សីហា": "august", "កញ្ញា": "september", "តុលា": "october", "វិច្ឆិកា": "november", "ធ្នូ": "december", "ndi": "january", "ndira": "january", "kuk": "february", "kukadzi": "february", "kurume": "march", "kub": "april", "kubvumbi": "april", "chivabvu": "may", "chv": "may", "chikumi": "june", "chk": "june", "chg": "july", "chikunguru": "july", "nya": "august", "nyamavhuvhu": "august", "gun": "september", "gunyana": "september", "gum": "october", "gumiguru": "october", "mbu": "november", "mbudzi": "november", "zvi": "december", "zvita": "december", "जन॰": "january", "फ़रवरी": "february", "फ़र॰": "february", "अप्रैल": "april", "जुल॰": "july", "अगस्त": "august", "अग॰": "august", "सितंबर": "september", "सित॰": "september", "सितम्बर": "september", "अक्तूबर": "october", "अक्तू॰": "october", "अक्टूबर": "october", "नवंबर": "november", "नव॰": "november", "नवम्बर": "november", "दिसंबर": "december", "दिस॰": "december", "दिसम्बर": "december", "січ": "january", "січень": "january", "січня": "january", "лют": "february", "лютий": "february", "лютого": "february", "бер": "march", "березень": "march", "березня": "march", "берез": "march", "кві": "april", "квіт": "april", "квітень": "april", "квітня": "april", "тра": "may", "трав": "may", "травень": "may", "травня": "may", "чер": "june", "черв": "june", "червень": "june", "червня": "june", "лип": "july", "липень": "july", "липня": "july", "сер": "august", "серп": "august", "серпень": "august", "серпня": "august", "вер": "september", "вересень": "september", "вересня": "september", "верес": "september", "жов": "october", "жовт": "october", "жовтень": "october", "жовтня": "october", "лис": "november", "лист": "november", "листопад": "november", "листопада": "november", "листоп": "november", "гру": "december", "груд": "december", "грудень": "december", "грудня": "december", "saŋ tsetsɛ̀ɛ lùm": "january", "saŋ kàg ngwóŋ": "february", "saŋ lepyè shúm": "march", "saŋ cÿó": "april", "saŋ tsɛ̀ɛ cÿó": "may", "saŋ njÿolá'": "june", "saŋ tyɛ̀b tyɛ̀b mbʉ̀ŋ": "july", "saŋ mbʉ̀ŋ": "august", "saŋ ngwɔ̀' mbÿɛ": "september", "saŋ tàŋa tsetsá'": "october", "saŋ mejwoŋó": "november", "saŋ lùm": "december", "Jun": "june", "Jul": "july", "augusti": "august", "ꋍꆪ": "january", "ꑍꆪ": "february", "ꌕꆪ": "march", "ꇖꆪ": "april", "ꉬꆪ": "may", "ꃘꆪ": "june", "ꏃꆪ": "july", "ꉆꆪ": "august", "ꈬꆪ": "september", "ꊰꆪ": "october", "ꊰꊪꆪ": "november", "ꊰꑋꆪ": "december", "mut": "january", "nzero": "january", "gas": "february", "ruhuhuma": "february", "ntwarante": "march", "wer": "march", "ndamukiza": "april", "gic": "may", "rusama": "may", "kam": "june", "ruheshi": "june", "mukakaro": "july", "nyandagaro": "august", "nyakanga": "july", "nze": "september", "gitugutu": "october", "ukw": "october", "munyonyo": "november", "ugu": "november", "kigarama": "december", "uku": "december", "january": "january", "february": "february", "march": "march", "june": "june", "july": "july", "fur": "february", "fuṛar": "february", "meɣ": "march", "meɣres": "march", "yebrir": "april", "yulyu": "july", "cte": "september", "ctembeṛ": "september", "tub": "october", "tubeṛ": "october", "nun": "november", "nunembeṛ": "november", "duǧ": "december", "duǧembeṛ": "december", "inn": "january", "innayr": "january", "bṛa": "february", "bṛayṛ": "february", "maṛ": "march", "maṛṣ": "march", "ktu": "october", "ktubr": "october", "nuwanbir": "november", "oca": "january", "ocak": "january", "Oc": "january", "şub": "february", "şubat": "february", "Şu": "february", "nis": "april", "nisan": "april", "Ni": "april", "mayıs": "may", "haz": "june", "haziran": "june", "Ha": "june", "tem": "july", "temmuz": "july", "Te": "july", "ağu": "august", "ağustos": "august", "Ağ": "august", "eyl": "september", "eylül": "september", "Ey": "september", "eki": "october", "ekim": "october", "Ek": "october", "kas": "may", "kasım": "november", "Ka": "november", "ara": "december", "aralık": "december", "Ar": "december", "martsi": "march", "maji": "may", "augustusi": "august", "septemberi": "september", "oktoberi": "october", "novemberi": "november", "decemberi": "december", "bisha koobaad": "january", "kob": "january", "bisha labaad": "february", "bisha saddexaad": "march", "sad": "november", "afr": "april", "bisha afraad": "april", "bisha shanaad": "may", "sha": "may", "bisha lixaad": "june", "lix": "june", "bisha todobaad": "july", "tod": "july", "bisha sideedaad": "august", "sid": "august", "bisha sagaalaad": "september", "sag": "september", "bisha tobnaad": "october", "tob": "november", "bisha kow iyo tobnaad": "november", "kit": "november", "bisha laba iyo tobnaad": "december", "lit": "december", "mutarama": "january", "gashyantare": "february", "werurwe": "march", "mata": "april", "gicuransi": "may", "kamena": "june", "kanama": "august", "nzeli": "september", "ukwakira": "october", "ugushyingo": "november", "ukuboza": "december", "mweri wo kwanza": "january", "mweri wo unayeli": "february", "una": "february", "mweri wo uneraru": "march", "che": "april", "mweri wo unecheshe": "april", "mweri wo unethanu": "may", "tha": "june", "moc": "june", "mweri wo thanu na mocha": "june", "mweri wo saba": "july", "mweri wo nane": "august", "mweri wo tisa": "september", "mweri wo kumi": "october", "moj": "november", "mweri wo kumi na moja": "november", "mweri wo kumi na yel'li": "december", "yel": "december", "ian": "january", "ianuarie": "january", "Febr": "february", "martie": "march", "Mart": "march", "aprilie": "april", "iun": "june", "iunie": "june", "iul": "july", "iulie": "july", "septembrie": "september", "Sep": "september", "octombrie": "october", "noiembrie": "november", "Noiem": "november", "decembrie": "december", "januaro": "january", "februaro": "february", "marto": "march", "aprilo": "april", "majo": "may", "junio": "june", "julio": "july", "aŭg": "august", "aŭgusto": "august", "septembro": "september", "oktobro": "october", "جنوري": "january", "فبروري": "february", "مۍ": "may", "اګست": "august", "genver": "january", "c'hwe": "february", "c'hwevrer": "february", "meur": "march", "meurzh": "march", "ebrel": "april", "mae": "may", "mezh": "june", "mezheven": "june", "goue": "july", "gouere": "july", "eost": "august", "gwen": "september", "gwengolo": "september", "here": "october", "ker": "december", "kerzu": "december", "kzu": "december", "ama": "january", "amajjii": "january", "gur": "february", "guraandhala": "february", "bit": "march", "bitooteessa": "march", "elb": "april", "elba": "april", "caamsa": "may", "cam": "may", "wax": "june", "waxabajjii": "june", "ado": "july", "adooleessa": "july", "hag": "august", "hagayya": "august", "fuulbana": "september", "onk": "october", "onkololeessa": "october", "sadaasa": "november", "mud": "december", "muddee": "december", "مئی": "may", "januali": "january", "febluali": "february", "aplili": "april", "febrero": "february", "septiembre": "september", "Setiembre": "september", "octubre": "october", "noviembre": "november", "diciembre": "december", "জানুয়ারী": "january", "জানুয়ারি": "january", "ফেব": "february", "ফেব্রুয়ারী": "february", "ফেব্রুয়ারি": "february", "মার্চ": "march", "এপ্রিল": "april", "আগস্ট": "august", "সেপ্টেম্বর": "september", "অক্টোবর": "october", "নভেম্বর": "november", "ডিসেম্বর": "december", "יאַנ": "january", "יאַנואַר": "january", "פֿעב": "february", "פֿעברואַר": "february", "מערץ": "march", "אַפּר": "april", "אַפּריל": "april", "מיי": "may", "אויג": "august", "אויגוסט": "august", "סעפּ": "september", "סעפּטעמבער": "september", "אקט": "october", "אקטאבער": "october", "נאוו": "november", "נאוועמבער": "november", "דעצ": "december", "דעצעמבער": "december", "januára": "january", "februára": "february", "apríla": "april", "mája": "may", "júna": "june", "júla": "july", "augusta": "august", "októbra": "october", "novembra": "november", "gennaio": "january", "febbraio": "february", "aprile": "april", "mag": "may", "maggio": "may", "giu": "june", "giugno": "june", "lug": "july", "luglio": "july", "settembre": "september", "ottobre": "october", "dicembre": "december", "de xineru": "january", "xin": "january", "xineru": "january", "de febreru": "february", "de marzu": "march", "d'abril": "april", "de mayu": "may", "de xunu": "june", "xun": "june", "xunu": "june", "de xunetu": "july", "xnt": "july", "xunetu": "july", "d'agostu": "august", "de setiembre": "september", "setiembre": "september", "d'ochobre": "october", "och": "october", "ochobre": "october", "de payares": "november", "payares": "november", "avi": "december", "avientu": "december", "d'avientu": "december", "cio": "january", "ciongo": "january", "lùishi": "february", "lus": "march", "lusòlo": "march", "muu": "april", "mùuyà": "april", "lum": "may", "lumùngùlù": "may", "luf": "june", "lufuimi": "june", "kab": "july", "kabàlàshìpù": "july", "lush": "august", "lùshìkà": "august", "lutongolo": "september", "lun": "october", "lungùdi": "october", "kaswèkèsè": "november", "cis": "december", "ciswà": "december", "januwari": "january", "umasingana": "january", "februwari": "february", "mas": "june", "mashi": "march", "eph": "april", "ephreli": "april", "mey": "may", "meyi": "may", "julayi": "july", "septhemba": "september", "okthoba": "october", "يناير": "january", "فبراير": "february", "مارس": "march", "أبريل": "april", "مايو": "may", "يونيو": "june", "يوليو": "july", "أغسطس": "august", "سبتمبر": "september", "أكتوبر": "october", "نوفمبر": "november", "ديسمبر": "december", "ژانویه": "january", "فوریه": "february", "آوریل": "april", "مه": "may", "ژوئن": "june", "ژوئیه": "july", "اوت": "august", "سپتامبر": "september", "اکتبر": "october", "نوامبر": "november", "دسامبر": "december", "ŋ1": "january", "ŋwíí a ntɔ́ntɔ": "january", "ŋ2": "february", "ŋwíí akǝ bɛ́ɛ": "february", "ŋ3": "march", "ŋwíí akǝ ráá": "march", "ŋ4": "april", "ŋwíí akǝ nin": "april", "ŋ5": "may", "ŋwíí akǝ táan": "may", "ŋ6": "june", "ŋwíí akǝ táafɔk": "june", "ŋ7": "july", "ŋwíí akǝ táabɛɛ": "july", "ŋ8": "august", "ŋwíí akǝ táaraa": "august", "ŋ9": "september", "ŋwíí akǝ táanin": "september", "ŋ10": "october", "ŋwíí akǝ ntɛk": "october", "ŋ11": "november", "ŋwíí akǝ ntɛk di bɔ́k": "november", "ŋ12": "december", "ŋwíí akǝ ntɛk di bɛ́ɛ": "december", "am faoilleach": "january", "dhen fhaoilleach": "january", "faoi": "january", "an gearran": "february", "dhen ghearran": "february", "gearr": "february", "am màrt": "march", "dhen mhàrt": "march", "màrt": "march", "an giblean": "april", "dhen ghiblean": "april", "gibl": "april", "an cèitean": "may", "cèit": "may", "dhen chèitean": "may", "an t-ògmhios": "june", "dhen ògmhios": "june", "ògmh": "june", "an t-iuchar": "july", "dhen iuchar": "july", "iuch": "july", "an lùnastal": "august", "dhen lùnastal": "august", "lùna": "august", "an t-sultain": "september", "dhen t-sultain": "september", "sult": "september", "an dàmhair": "october", "dhen dàmhair": "october", "dàmh": "october", "an t-samhain": "november", "dhen t-samhain": "november", "samh": "november", "an dùbhlachd": "december", "dhen dùbhlachd": "december", "dùbh": "december", "сту": "january", "студзень": "january", "студзеня": "january", "Стд": "january", "лютага": "february", "люты": "february", "сак": "march", "сакавік": "march", "сакавіка": "march", "кра": "april", "красавік": "april", "красавіка": "april", "Крс": "april", "Траўня": "may", "Тра": "may", "чэр": "june", "чэрвень": "june", "чэрвеня": "june", "ліп": "july", "ліпень": "july", "ліпеня": "july", "жні": "august", "жнівень": "august", "жніўня": "august", "Жнівеня": "august", "Жнв": "august", "верасень": "september", "верасня": "september", "Врс": "september", "кас": "october", "кастрычнік": "october", "кастрычніка": "october", "Кст": "october", "ліс": "november", "лістапад": "november", "лістапада": "november", "Ліс": "november", "сне": "december", "снежань": "december", "снежня": "december", "Снж": "december", "کانوونی دووەم": "january", "شوبات": "february", "ئازار": "march", "نیسان": "april", "ئایار": "may", "حوزەیران": "june", "تەمووز": "july", "ئاب": "august", "ئەیلوول": "september", "تشرینی یەکەم": "october", "تشرینی دووەم": "november", "کانونی یەکەم": "december", "fevreiro": "february", "marco": "march", "augusto": "august", "otubro": "october", "lapa le obo": "january", "obo": "january", "lapa le waare": "february", "waa": "february", "lapa le okuni": "march", "oku": "march", "lapa le ong'wan": "april", "ong": "april", "ime": "may", "lapa le imet": "may", "ile": "june", "lapa le ile": "june", "lapa le sapa": "july", "sap": "july", "isi": "august", "lapa le isiet": "august", "lapa le saal": "september", "saa": "september", "lapa le tomon": "october", "tom": "october", "lapa le tomon obo": "november", "lapa le tomon waare": "december", "tow": "december", "uđiv": "january", "uđđâivemáánu": "january", "kuovâ": "february", "kuovâmáánu": "february", "njuhčâ": "march", "njuhčâmáánu": "march", "cuáŋui": "april", "cuáŋuimáánu": "april", "vyesi": "may", "vyesimáánu": "may", "kesi": "june", "kesimáánu": "june", "syeini": "july", "syeinimáánu": "july", "porge": "august", "porgemáánu": "august", "čohčâ": "september", "čohčâmáánu": "september", "roovvâd": "october", "roovvâdmáánu": "october", "skammâ": "november", "skammâmáánu": "november", "juovlâ": "december", "juovlâmáánu": "december", "jenụwarị": "january", "febrụwarị": "february", "maachị": "march", "eprel": "april", "juun": "june", "julaị": "july", "ọgọ": "august", "ọgọọst": "august", "ọkt": "october", "ọktoba": "october", "जानुवारी": "january", "मार्स": "march", "एफ्रिल": "april", "जुलाइ": "july", "आगस्थ": "august", "सेबथेज्ब़र": "september", "अखथबर": "october", "नबेज्ब़र": "november", "दिसेज्ब़र": "december", "జన": "january", "జనవరి": "january", "ఫిబ్ర": "february", "ఫిబ్రవరి": "february", "మార్చి": "march", "ఏప్రి": "april", "ఏప్రిల్": "april", "మే": "may", "జూన్": "june", "జులై": "july", "ఆగ": "august", "ఆగస్టు": "august", "సెప్టెం": "september", "సెప్టెంబర్": "september", "అక్టో": "october", "అక్టోబర్": "october", "నవం": "november", "నవంబర్": "november", "డిసెం": "december", "డిసెంబర్": "december", "fúngatɨ": "january", "kʉfúngatɨ": "january", "kʉnaanɨ": "february", "naanɨ": "february", "keenda": "march", "kʉkeenda": "march", "ikúmi": "april", "kwiikumi": "april", "inyambala": "may", "kwiinyambála": "may", "idwaata": "june", "kwiidwaata": "june", "kʉmʉʉnchɨ": "july", "mʉʉnchɨ": "july", "kʉvɨɨrɨ": "august", "vɨɨrɨ": "august", "kʉsaatʉ": "september", "saatʉ": "september", "inyi": "october", "kwiinyi": "october", "kʉsaano": "november", "saano": "november", "kʉsasatʉ": "december", "sasatʉ": "december", "ion": "january", "ionawr": "january", "chw": "february", "chwef": "february", "chwefror": "february", "maw": "march", "mawrth": "march", "ebrill": "april", "meh": "june", "mehefin": "june", "gorff": "july", "gorffennaf": "july", "awst": "august", "medi": "september", "hyd": "october", "hydref": "october", "tach": "november", "tachwedd": "november", "rhag": "december", "rhagfyr": "december", "jenner": "january", "hor": "february", "hornig": "february", "märze": "march", "abrille": "april", "meije": "may", "brá": "june", "bráčet": "june", "hei": "july", "heiwet": "july", "öig": "august", "öigšte": "august", "her": "september", "herbštmánet": "september", "wím": "october", "wímánet": "october", "win": "november", "wintermánet": "november", "chr": "december", "chrištmánet": "december", "ओगस्ट": "august", "सेप्टेंबर": "september", "ओक्टोबर": "october", "ianuali": "january", "pep": "february", "pepeluali": "february", "mal": "march", "malaki": "march", "'ap": "april", "'apelila": "april", "iune": "june", "iulai": "july", "'au": "august", "'aukake": "august", "kep": "september", "kepakemapa": "september", "'ok": "october", "'okakopa": "october", "nowemapa": "november", "kek": "december", "kekemapa": "december", "mäe": "march", "mäerz": "march", "abrëll": "april", "dezember": "december", "ஜன": "january", "ஜனவரி": "january", "பிப்": "february", "பிப்ரவரி": "february", "மார்": "march", "மார்ச்": "march", "ஏப்": "april", "ஏப்ரல்": "april", "மே": "may", "ஜூன்": "june", "ஜூலை": "july", "ஆக": "august", "ஆகஸ்ட்": "august", "செப்": "september", "செப்டம்பர்": "september", "அக்": "october", "அக்டோபர்": "october", "நவ": "november", "நவம்பர்": "november", "டிச": "december", "டிசம்பர்": "december", "de gen": "january", "de gener": "january", "gener": "january", "de febr": "february", "de febrer": "february", "febrer": "february", "de març": "march", "d'abr": "april", "de maig": "may", "maig": "may", "de juny": "june", "juny": "june", "de jul": "july", "de juliol": "july", "juliol": "july", "ag": "august", "agost": "august", "d'ag": "august", "d'agost": "august", "de set": "september", "de setembre": "september", "setembre": "september", "d'oct": "october", "d'octubre": "october", "de nov": "november", "de novembre": "november", "de des": "december", "de desembre": "december", "desembre": "december", "Jänner": "january", "Feber": "february", "Mrz": "march", "ođđajagemánnu": "january", "ođđj": "january", "guov": "february", "guovvamánnu": "february", "njuk": "march", "njukčamánnu": "march", "cuo": "april", "cuoŋománnu": "april", "mies": "may", "miessemánnu": "may", "geas": "june", "geassemánnu": "june", "suoi": "july", "suoidnemánnu": "july", "borg": "august", "borgemánnu": "august", "čakč": "september", "čakčamánnu": "september", "golg": "october", "golggotmánnu": "october", "skáb": "november", "skábmamánnu": "november", "juov": "december", "juovlamánnu": "december", "jannewaris": "january", "febrewaris": "february", "maaie": "may", "septimber": "september", "novimber": "november", "desimber": "december", "ean": "january", "eanáir": "january", "feabh": "february", "feabhra": "february", "márta": "march", "aib": "april", "aibreán": "april", "beal": "may", "bealtaine": "may", "meith": "june", "meitheamh": "june", "iúil": "july", "lún": "august", "lúnasa": "august", "meán fómhair": "september", "mfómh": "september", "deireadh fómhair": "october", "dfómh": "october", "samhain": "november", "noll": "december", "nollaig": "december", "mul": "january", "mulgul": "january", "ng'atyaato": "february", "ngat": "february", "kiptaamo": "march", "iwo": "april", "iwootkuut": "april", "mamuut": "may", "paa": "june", "paagi": "june", "ng'eiyeet": "july", "nge": "september", "roo": "august", "rooptui": "august", "bur": "september", "bureet": "september", "epe": "october", "epeeso": "october", "kipsuunde ne taai": "november", "kpt": "november", "kipsuunde nebo aeng'": "december", "kpa": "december", "mup": "january", "mupalangulwa": "january", "mwi": "february", "mwitope": "february", "msh": "march", "mushende": "march", "mun": "april", "munyi": "april", "mushende magali": "may", "muj": "june", "mujimbi": "june", "msp": "july", "mushipepo": "july", "mpg": "august", "mupuguto": "august", "munyense": "september", "mye": "september", "mok": "october", "mokhu": "october", "mus": "november", "musongandembwe": "november", "muh": "december", "muhaano": "december", "jannewa": "january", "fäb": "february", "fäbrowa": "february", "mäz": "march", "määz": "march", "aprell": "april", "ouj": "august", "oujoß": "august", "säp": "september", "oktohber": "october", "ژانویهٔ": "january", "فوریهٔ": "february", "مهٔ": "may", "ژوئیهٔ": "july", "آگست": "august", "ng1": "january", "ngwɛn matáhra": "january", "ng2": "february", "ngwɛn ńmba": "february", "ng3": "march", "ngwɛn ńlal": "march", "ng4": "april", "ngwɛn ńna": "april", "ng5": "may", "ngwɛn ńtan": "may", "ng6": "june", "ngwɛn ńtuó": "june", "ng7": "july", "ngwɛn hɛmbuɛrí": "july", "ng8": "august", "ngwɛn lɔmbi": "august", "ng9": "september", "ngwɛn rɛbvuâ": "september", "ng10": "october", "ngwɛn wum": "october", "ng11": "november", "ngwɛn wum navǔr": "november", "kris": "december", "krísimin": "december", "dzove": "january", "dzv": "january", "dzd": "february", "dzodze": "february", "ted": "march", "tedoxe": "march", "afɔ": "april", "afɔfĩe": "april", "dam": "may", "dama": "may", "masa": "june", "sia": "july", "siamlɔm": "july", "dea": "august", "deasiamime": "august", "any": "september", "anyɔnyɔ": "september", "kel": "february", "kele": "october", "ade": "november", "adeɛmekpɔxe": "november", "dzm": "december", "dzome": "december", "janoary": "january", "febroary": "february", "martsa": "march", "aprily": "april", "jon": "june", "jona": "june", "jolay": "july", "aog": "august", "aogositra": "august", "septambra": "september", "novambra": "november", "desambra": "december", "imb": "january", "mori ghwa imbiri": "january", "kaw": "february", "mori ghwa kawi": "february", "kad": "march", "mori ghwa kadadu": "march", "mori ghwa kana": "april", "mori ghwa kasanu": "may", "kar": "june", "mori ghwa karandadu": "june", "mori ghwa mfungade": "july", "mori ghwa wunyanya": "august", "wun": "august", "ike": "september", "mori ghwa ikenda": "september", "mori ghwa ikumi": "october", "mori ghwa ikumi na imweri": "november", "iwi": "december", "mori ghwa ikumi na iwi": "december", "mwai wa mbee": "january", "mwai wa kelĩ": "february", "ktũ": "march", "mwai wa katatũ": "march", "mwai wa kana": "april", "mwai wa katano": "may", "mwai wa thanthatũ": "june", "moo": "july", "mwai wa muonza": "july", "mwai wa nyaanya": "august", "knd": "september", "mwai wa kenda": "september", "mwai wa ĩkumi": "october", "ĩku": "october", "mwai wa ĩkumi na ĩmwe": "november", "ĩkm": "november", "mwai wa ĩkumi na ilĩ": "december", "ĩkl": "december", "тохс": "january", "тохсунньу": "january", "олун": "february", "олунньу": "february", "клн": "march", "кулун тутар": "march", "мсу": "april", "муус устар": "april", "ыам": "may", "ыам ыйа": "may", "ыам ыйын": "may", "бэс": "june", "бэс ыйа": "june", "бэс ыйын": "june", "от ыйа": "july", "от ыйын": "july", "отй": "july", "атр": "august", "атырдьых ыйа": "august", "атырдьых ыйын": "august", "балаҕан ыйа": "september", "балаҕан ыйын": "september", "блҕ": "september", "алт": "october", "алтынньы": "october", "сэт": "november", "сэтинньи": "november", "ахс": "december", "ахсынньы": "december", "ngo": "january", "ngɔn osú": "january", "ngɔn bɛ̌": "february", "ngl": "march", "ngɔn lála": "march", "ngn": "april", "ngɔn nyina": "april", "ngt": "may", "ngɔn tána": "may", "ngs": "june", "ngɔn saməna": "june", "ngz": "july", "ngɔn zamgbála": "july", "ngm": "august", "ngɔn mwom": "august", "ngɔn ebulú": "september", "nga": "october", "ngɔn awóm": "october", "ngad": "november", "ngɔn awóm ai dziá": "november", "ngab": "december", "ngɔn awóm ai bɛ̌": "december", "جانڤیە": "january", "فئڤریە": "february", "آڤریل": "april", "جوٙأن": "june", "جوٙلا": "july", "آگوست": "august", "سئپتامر": "september", "ئوکتوڤر": "october", "نوڤامر": "november", "دئسامر": "december"}"#;
     let m = serde_json::from_str(seed).unwrap();
     m
 });
@@ -64,17 +221,55 @@ static ARRAY_RELATIVE:Lazy<Vec<&str>> = Lazy::new(|| {
 
 /// 泰语月份缩写
 static TEXT_MONTH_THAI:Lazy<HashMap<&str, String>> = Lazy::new(|| {
-    let seed = r#"{"ม.ค.": "january", "ก.พ.": "february", "มี.ค.": "march", "เม.ย.": "april", "พ.ค.": "may", "มิ.ย.": "june", "ก.ค.": "july", "ส.ค.": "august", "ก.ย.": "september", "ต.ค.": "october", "พ.ย.": "november", "ธ.ค.": "december", "เดือนมกราคม": "january", "มกรา": "january", "กพ": "february", "กุมภาพันธ์": "february", "เดือนกุมภาพันธ์": "february", "กุมภา": "february", "มีค": "march", "มีนาคม": "march", "เดือนมีนาคม": "march", "มีนา": "march", "เมย": "april", "เมษายน": "april", "เมษา": "april", "เดือนเมษายน": "april", "พค": "may", "พฤษภาคม": "may", "เดือนพฤษภาคม": "may", "พฤษภา": "may", "มิถุนายน": "june", "มิย": "june", "เดือนมิถุนายน": "june", "มิถุนา": "june", "กค": "july", "กรกฎาคม": "july", "เดือนกรกฏาคม": "july", "กรกฎา": "july", "สค": "august", "สิงหาคม": "august", "สิงหา": "august", "เดือนสิงหาคม": "august", "กย": "september", "กันยายน": "september", "กันยา": "september", "เดือนกันยายน": "september", "ตค": "october", "ตุลาคม": "october", "เดือนตุลาคม": "october", "ตุลา": "october", "พย": "november", "พฤศจิกายน": "november", "เดือนพฤศจิกายน": "november", "พฤศจิ": "november", "ธค": "december", "ธันวาคม": "december", "เดือนธันวาคม": "december", "ธันวา": "december"}"#;
+    let seed = r#"{"ม.ค.": "january", "ก.พ.": "february", "มี.ค.": "march", "เม.ย.": "april", "พ.ค.": "may", "มิ.ย.": "june", "ก.ค.": "july", "ส.ค.": "august", "ก.ย.": "september", "ต.ค.": "october", "พ.ย.": "november", "ธ.ค.": "december", "เดือนมกราคม": "january", "มกรา": "january", "มกราคม": "january", "กพ": "february", "กุมภาพันธ์": "february", "เดือนกุมภาพันธ์": "february", "กุมภา": "february", "มีค": "march", "มีนาคม": "march", "เดือนมีนาคม": "march", "มีนา": "march", "เมย": "april", "เมษายน": "april", "เมษา": "april", "เดือนเมษายน": "april", "พค": "may", "พฤษภาคม": "may", "เดือนพฤษภาคม": "may", "พฤษภา": "may", "มิถุนายน": "june", "มิย": "june", "เดือนมิถุนายน": "june", "มิถุนา": "june", "กค": "july", "กรกฎาคม": "july", "เดือนกรกฏาคม": "july", "เดือนกรกฎาคม": "july", "กรกฎา": "july", "สค": "august", "สิงหาคม": "august", "สิงหา": "august", "เดือนสิงหาคม": "august", "กย": "september", "กันยายน": "september", "กันยา": "september", "เดือนกันยายน": "september", "ตค": "october", "ตุลาคม": "october", "เดือนตุลาคม": "october", "ตุลา": "october", "พย": "november", "พฤศจิกายน": "november", "เดือนพฤศจิกายน": "november", "พฤศจิ": "november", "ธค": "december", "ธันวาคม": "december", "เดือนธันวาคม": "december", "ธันวา": "december"}"#;
     let m = serde_json::from_str(seed).unwrap();
     m
 });
 
+/// 纪元标志｜明确标注的纪元优先于基于月份和文字的推断
+static TEXT_ERA_MARKER:Lazy<Vec<(&str, &str)>> = Lazy::new(|| {
+    let marker = vec![("พุทธศักราช", "buddhist"), ("พ.ศ.", "buddhist"), ("พ.ศ", "buddhist"),
+                      ("คริสต์ศักราช", "gregorian"), ("ค.ศ.", "gregorian"), ("ค.ศ", "gregorian"),
+                      ("هجری شمسی", "jalali"), ("هجری خورشیدی", "jalali"), ("ه.ش.", "jalali"), ("ه.ش", "jalali"),
+                      ("中華民國", "minguo"), ("中华民国", "minguo"), ("民國", "minguo"), ("民国", "minguo")];
+    marker
+});
+
+/// 台湾地区常见的使用民国纪年的站点
+static DOMAIN_MINGUO:Lazy<Vec<&str>> = Lazy::new(|| {
+    let domain = vec![".tw", "udn.com", "chinatimes.com", "ettoday.net", "setn.com", "nownews.com",
+                      "storm.mg", "upmedia.mg", "mirrormedia.mg", "thenewslens.com"];
+    domain
+});
+
+/// 伊朗历月份｜映射为相同序号的月份名称 换算在生成日期时完成
+static TEXT_MONTH_JALALI:Lazy<HashMap<&str, String>> = Lazy::new(|| {
+    let seed = r#"{"فروردین": "january", "اردیبهشت": "february", "خرداد": "march", "تیر": "april", "مرداد": "may", "امرداد": "may", "شهریور": "june", "مهر": "july", "آبان": "august", "آذر": "september", "دی": "october", "بهمن": "november", "اسفند": "december", "farvardin": "january", "ordibehesht": "february", "khordad": "march", "mordad": "may", "shahrivar": "june", "bahman": "november", "esfand": "december"}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+const MIN_YEAR_JALALI: u32 = 1300;  // 伊朗历 1300 年即公历 1921 年
+const MAX_YEAR_JALALI: u32 = 1500;
+
 /// 为处理连续字符型文本预设可用符号
 static SYMBOL_SERIES:Lazy<Vec<&str>> = Lazy::new(|| {
     let symbol = vec![".", "/", "_", "-"];
     return symbol
 });
 
+/// 各书写系统十进制数字 0 的码位
+/// 阿拉伯-印度、波斯、天城文、孟加拉、泰文、老挝、藏文、缅甸、高棉、蒙古、全角等
+static DIGIT_ZERO:Lazy<Vec<u32>> = Lazy::new(|| {
+    let zero = vec![
+        0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66,
+        0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810,
+        0x1946, 0x19D0, 0x1A80, 0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0,
+        0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x11066, 0x110F0, 0x11136,
+        0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0, 0x11730, 0x118E0, 0x11C50, 0x11D50,
+        0x16A60, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E950];
+    zero
+});
+
 /// 周
 static TEXT_WEEKDAY:Lazy<HashMap<&str, String>> = Lazy::new(|| {
     let seed = r#"{"mvu": "monday", "mvulo": "monday", "sib": "saturday", "sibili": "tuesday", "sit": "wednesday", "sithathu": "wednesday", "sin": "thursday", "sine": "thursday", "sih": "friday", "sihlanu": "friday", "mgq": "saturday", "mgqibelo": "saturday", "son": "sunday", "sonto": "sunday", "lit": "monday", "litinin": "monday", "tal": "tuesday", "talata": "tuesday", "lar": "wednesday", "laraba": "wednesday", "alh": "thursday", "alhamis": "thursday", "jum": "sunday", "jumma'a": "friday", "asa": "sunday", "asabar": "saturday", "lah": "sunday", "lahadi": "sunday", "mä": "monday", "määntig": "monday", "zi": "tuesday", "ziischtig": "tuesday", "mi": "wednesday", "mittwuch": "wednesday", "du": "thursday", "dunschtig": "thursday", "fr": "friday", "friitig": "friday", "sa": "saturday", "samschtig": "saturday", "su": "sunday", "sunntig": "sunday", "ⴰⵢⵏ": "monday", "ⴰⵢⵏⴰⵙ": "monday", "ⴰⵙⵉ": "tuesday", "ⴰⵙⵉⵏⴰⵙ": "tuesday", "ⴰⴽⵕ": "wednesday", "ⴰⴽⵕⴰⵙ": "wednesday", "ⴰⴽⵡ": "thursday", "ⴰⴽⵡⴰⵙ": "thursday", "ⴰⵙⵉⵎ": "friday", "ⵙⵉⵎⵡⴰⵙ": "friday", "ⴰⵙⵉⴹ": "saturday", "ⴰⵙⵉⴹⵢⴰⵙ": "saturday", "ⴰⵙⴰ": "sunday", "ⴰⵙⴰⵎⴰⵙ": "sunday", "bala": "monday", "balaza": "monday", "kubi": "tuesday", "owokubili": "tuesday", "kusa": "wednesday", "owokusatu": "wednesday", "kuna": "thursday", "olokuna": "thursday", "kuta": "friday", "olokutaanu": "friday", "muka": "saturday", "olomukaaga": "saturday", "sabi": "sunday", "sabiiti": "sunday", "jtt": "monday", "jumatatu": "monday", "jnn": "tuesday", "jumanne": "tuesday", "jtn": "wednesday", "jumatano": "wednesday", "alhamisi": "thursday", "ijm": "friday", "ijumaa": "friday", "jmo": "saturday", "jumamosi": "saturday", "jpi": "sunday", "jumapili": "sunday", "երկ": "monday", "երկուշաբթի": "monday", "երեքշաբթի": "tuesday", "երք": "tuesday", "չորեքշաբթի": "wednesday", "չրք": "wednesday", "հինգշաբթի": "thursday", "հնգ": "thursday", "ուր": "friday", "ուրբաթ": "friday", "շաբաթ": "saturday", "շբթ": "saturday", "կիր": "sunday", "կիրակի": "sunday", "j3": "monday", "j4": "tuesday", "j5": "wednesday", "al": "monday", "murwa wa kanne": "thursday", "ij": "friday", "murwa wa katano": "friday", "j1": "saturday", "j2": "sunday", "jumapiri": "sunday", "יום ב׳": "monday", "יום שני": "monday", "שני": "monday", "יום ב": "monday", "יום ג׳": "tuesday", "יום שלישי": "tuesday", "שלישי": "tuesday", "יום ג": "tuesday", "יום ד׳": "wednesday", "יום רביעי": "wednesday", "רביעי": "wednesday", "יום ד": "wednesday", "יום ה׳": "thursday", "יום חמישי": "thursday", "חמישי": "thursday", "יום ה": "thursday", "יום ו׳": "friday", "יום שישי": "friday", "שישי": "friday", "יום ו": "friday", "יום שבת": "saturday", "שבת": "saturday", "יום א׳": "sunday", "יום ראשון": "sunday", "ראשון": "sunday", "יום א": "sunday", "пн": "monday", "понеделник": "monday", "пон": "monday", "вт": "tuesday", "вторник": "tuesday", "вто": "tuesday", "ср": "wednesday", "сряда": "wednesday", "сря": "wednesday", "четвъртък": "thursday", "чт": "thursday", "петък": "friday", "пт": "friday", "сб": "saturday", "събота": "saturday", "нд": "sunday", "неделя": "sunday", "lin": "sunday", "lindi": "monday", "mar": "tuesday", "mardi": "tuesday", "mer": "wednesday", "merkredi": "wednesday", "ze": "thursday", "zedi": "thursday", "van": "friday", "vandredi": "friday", "sam": "saturday", "samdi": "saturday", "dim": "sunday", "dimans": "sunday", "دۈ": "monday", "دۈشەنبە": "monday", "سە": "tuesday", "سەيشەنبە": "tuesday", "چا": "wednesday", "چارشەنبە": "wednesday", "پە": "thursday", "پەيشەنبە": "thursday", "جۈ": "friday", "جۈمە": "friday", "شە": "saturday", "شەنبە": "saturday", "يە": "sunday", "يەكشەنبە": "sunday", "ork": "monday", "orwokubanza": "monday", "okb": "tuesday", "orwakabiri": "tuesday", "oks": "wednesday", "orwakashatu": "wednesday", "okn": "thursday", "orwakana": "thursday", "okt": "friday", "orwakataano": "friday", "omk": "saturday", "orwamukaaga": "saturday", "san": "saturday", "sande": "sunday", "ორშ": "monday", "ორშაბათი": "monday", "სამ": "tuesday", "სამშაბათი": "tuesday", "ოთხ": "wednesday", "ოთხშაბათი": "wednesday", "ხუთ": "thursday", "ხუთშაბათი": "thursday", "პარ": "friday", "პარასკევი": "friday", "შაბ": "saturday", "შაბათი": "saturday", "კვი": "sunday", "კვირა": "sunday", "星期一": "monday", "週一": "monday", "星期二": "tuesday", "週二": "tuesday", "星期三": "wednesday", "週三": "wednesday", "星期四": "thursday", "週四": "thursday", "星期五": "friday", "週五": "friday", "星期六": "saturday", "週六": "saturday", "星期日": "sunday", "週日": "sunday", "月": "monday", "月曜日": "monday", "(月)": "monday", "火": "tuesday", "火曜日": "tuesday", "(火)": "tuesday", "水": "wednesday", "水曜日": "wednesday", "(水)": "wednesday", "木": "thursday", "木曜日": "thursday", "(木)": "thursday", "金": "friday", "金曜日": "friday", "(金)": "friday", "土": "saturday", "土曜日": "saturday", "(土)": "saturday", "日": "sunday", "日曜日": "sunday", "(日)": "sunday", "aaɓ": "monday", "aaɓnde": "monday", "maw": "tuesday", "mawbaare": "tuesday", "nje": "sunday", "njeslaare": "wednesday", "naa": "thursday", "naasaande": "thursday", "mawnde": "friday", "mwd": "friday", "hbi": "saturday", "hoore-biir": "saturday", "dew": "sunday", "dewo": "sunday", "dush": "monday", "dushanba": "monday", "sesh": "tuesday", "seshanba": "tuesday", "chor": "wednesday", "chorshanba": "wednesday", "pay": "thursday", "payshanba": "thursday", "juma": "friday", "shan": "saturday", "shanba": "saturday", "yak": "sunday", "yakshanba": "sunday", "дүй": "monday", "дүйшөмбү": "monday", "шейш": "tuesday", "шейшемби": "tuesday", "шарш": "wednesday", "шаршемби": "wednesday", "бейш": "thursday", "бейшемби": "thursday", "жума": "friday", "ишемби": "saturday", "ишм": "saturday", "жек": "sunday", "жекшемби": "sunday", "གཟའ་མིག་དམར་": "tuesday", "མིར་": "monday", "གཟའ་ལྷག་པ་": "wednesday", "ལྷག་": "tuesday", "གཟའ་ཕུར་བུ་": "thursday", "ཕུར་": "wednesday", "གཟའ་པ་སངས་": "friday", "སངས་": "thursday", "གཟའ་སྤེན་པ་": "saturday", "སྤེན་": "friday", "གཟའ་ཉི་མ་": "sunday", "ཉི་": "saturday", "གཟའ་ཟླ་བ་": "monday", "ཟླ་": "sunday", "pa shahuviluha": "monday", "vil": "monday", "hiv": "tuesday", "pa hivili": "tuesday", "hid": "wednesday", "pa hidatu": "wednesday", "hit": "thursday", "pa hitayi": "thursday", "hih": "friday", "pa hihanu": "friday", "lem": "saturday", "pa shahulembela": "saturday", "mul": "sunday", "pa mulungu": "sunday", "ati": "monday", "atini": "monday", "ata": "tuesday", "atalata": "tuesday", "ala": "wednesday", "alarba": "wednesday", "alhamiisa": "thursday", "alm": "thursday", "alj": "friday", "aljuma": "friday", "ass": "saturday", "assabdu": "saturday", "alhadi": "sunday", "atinni": "monday", "atalaata": "tuesday", "alz": "friday", "alzuma": "friday", "asi": "saturday", "asibti": "saturday", "ten": "monday", "teneŋ": "monday", "alarbay": "wednesday", "ara": "thursday", "aramisay": "thursday", "arj": "friday", "arjuma": "friday", "sibiti": "saturday", "dimas": "sunday", "jumatatuu": "monday", "jumatanu": "wednesday", "iju": "friday", "jumapilyi": "sunday", "lun": "monday", "lunes": "monday", "martes": "tuesday", "miy": "wednesday", "miyerkules": "wednesday", "huw": "thursday", "huwebes": "thursday", "biy": "friday", "biyernes": "friday", "sab": "sunday", "sabado": "saturday", "linggo": "sunday", "پیر": "monday", "منگل": "tuesday", "بُدھ": "wednesday", "جمعرات": "thursday", "جمعہ": "friday", "ہفتہ": "saturday", "اتوار": "sunday", "sen": "monday", "senin": "monday", "sel": "tuesday", "selasa": "tuesday", "rab": "wednesday", "rabu": "wednesday", "kam": "thursday", "kamis": "thursday", "jumat": "friday", "sabtu": "saturday", "min": "thursday", "minggu": "sunday", "Ahad": "sunday", "bazar ertəsi": "monday", "be": "monday", "ça": "tuesday", "çərşənbə axşamı": "tuesday", "ç": "wednesday", "çərşənbə": "wednesday", "ca": "thursday", "cümə axşamı": "thursday", "c": "friday", "cümə": "friday", "ş": "saturday", "şənbə": "saturday", "b": "sunday", "bazar": "sunday", "सोम": "monday", "सोमबार": "monday", "मङ्गल": "tuesday", "मङ्गलबार": "tuesday", "बुध": "wednesday", "बुधबार": "wednesday", "बिहि": "thursday", "बिहिबार": "thursday", "शुक्र": "friday", "शुक्रबार": "friday", "शनि": "saturday", "शनिबार": "saturday", "आइत": "sunday", "आइतबार": "sunday", "bk2": "monday", "bïkua-ûse": "monday", "bk3": "tuesday", "bïkua-ptâ": "tuesday", "bk4": "wednesday", "bïkua-usïö": "wednesday", "bk5": "thursday", "bïkua-okü": "thursday", "lâp": "friday", "lâpôsö": "friday", "lây": "saturday", "lâyenga": "saturday", "bikua-ôko": "sunday", "bk1": "sunday", "ma": "tuesday", "maandag": "monday", "di": "tuesday", "dinsdag": "tuesday", "wo": "wednesday", "woensdag": "wednesday", "do": "thursday", "donderdag": "thursday", "vr": "friday", "vrydag": "friday", "saterdag": "saturday", "so": "saturday", "sondag": "sunday", "ਸੋਮ": "monday", "ਸੋਮਵਾਰ": "monday", "ਮੰਗਲ": "tuesday", "ਮੰਗਲਵਾਰ": "tuesday", "ਬੁੱਧ": "wednesday", "ਬੁੱਧਵਾਰ": "wednesday", "ਵੀਰ": "thursday", "ਵੀਰਵਾਰ": "thursday", "ਸ਼ੁੱਕਰ": "friday", "ਸ਼ੁੱਕਰਵਾਰ": "friday", "ਸ਼ਨਿੱਚਰ": "saturday", "ਸ਼ਨਿੱਚਰਵਾਰ": "saturday", "ਐਤ": "sunday", "ਐਤਵਾਰ": "sunday", "mɔ́ndi": "monday", "ápta mɔ́ndi": "tuesday", "wɛ́nɛsɛdɛ": "wednesday", "tɔ́sɛdɛ": "thursday", "fɛlâyɛdɛ": "friday", "sásidɛ": "saturday", "sɔ́ndi": "sunday", "njumatatu": "monday", "tat": "wednesday", "ine": "wednesday", "njumaine": "tuesday", "njumatano": "wednesday", "tan": "thursday", "aramithi": "thursday", "arm": "thursday", "maa": "tuesday", "njumaa": "friday", "njumamothii": "saturday", "nmm": "saturday", "kiumia": "sunday", "kma": "sunday", "lunis": "monday", "martars": "tuesday", "mie": "wednesday", "miercus": "wednesday", "joi": "thursday", "joibe": "thursday", "vin": "friday", "vinars": "friday", "sabide": "saturday", "dom": "sunday", "domenie": "sunday", "cla": "monday", "comlaaɗii": "monday", "comzyiiɗii": "tuesday", "czi": "tuesday", "cko": "wednesday", "comkolle": "wednesday", "cka": "thursday", "comkaldǝɓlii": "thursday", "cga": "friday", "comgaisuu": "friday", "comzyeɓsuu": "saturday", "cze": "saturday", "com'yakke": "sunday", "cya": "sunday", "တနင်္လာ": "monday", "အင်္ဂါ": "tuesday", "ဗုဒ္ဓဟူး": "wednesday", "ကြာသပတေး": "thursday", "သောကြာ": "friday", "စနေ": "saturday", "တနင်္ဂနွေ": "sunday", "понедељак": "monday", "уто": "tuesday", "уторак": "tuesday", "сре": "wednesday", "среда": "wednesday", "чет": "thursday", "четвртак": "thursday", "пет": "friday", "петак": "friday", "суб": "saturday", "субота": "saturday", "нед": "sunday", "недеља": "sunday", "sig": "monday", "sigunda-fera": "monday", "ter": "tuesday", "tersa-fera": "tuesday", "kua": "wednesday", "kuarta-fera": "wednesday", "kin": "thursday", "kinta-fera": "thursday", "ses": "friday", "sesta-fera": "friday", "sabadu": "saturday", "sábadu": "saturday", "dum": "sunday", "dumingu": "sunday", "mán": "monday", "mánudagur": "monday", "þri": "tuesday", "þriðjudagur": "tuesday", "mið": "wednesday", "miðvikudagur": "wednesday", "fim": "thursday", "fimmtudagur": "thursday", "fös": "friday", "föstudagur": "friday", "lau": "saturday", "laugardagur": "saturday", "sun": "sunday", "sunnudagur": "sunday", "ሰኞ": "monday", "ማክሰ": "tuesday", "ማክሰኞ": "tuesday", "ረቡዕ": "wednesday", "ሐሙስ": "thursday", "ዓርብ": "friday", "ቅዳሜ": "saturday", "እሑድ": "sunday", "pon": "monday", "ponedeljek": "monday", "tor": "thursday", "torek": "tuesday", "sre": "wednesday", "sreda": "wednesday", "čet": "thursday", "četrtek": "thursday", "pet": "friday", "petek": "friday", "sob": "saturday", "sobota": "saturday", "ned": "sunday", "nedelja": "sunday", "mantaxtsees": "monday", "de": "tuesday", "denstaxtsees": "tuesday", "wu": "wednesday", "wunstaxtsees": "wednesday", "dondertaxtsees": "thursday", "fraitaxtsees": "friday", "sat": "saturday", "satertaxtsees": "saturday", "sontaxtsees": "sunday", "월": "monday", "월요일": "monday", "화": "tuesday", "화요일": "tuesday", "수": "wednesday", "수요일": "wednesday", "목": "thursday", "목요일": "thursday", "금": "friday", "금요일": "friday", "토": "saturday", "토요일": "saturday", "일": "sunday", "일요일": "sunday", "må": "monday", "mån": "monday", "måndag": "monday", "ty": "tuesday", "tys": "tuesday", "tysdag": "tuesday", "on": "wednesday", "ons": "wednesday", "onsdag": "wednesday", "to": "thursday", "torsdag": "thursday", "fre": "friday", "fredag": "friday", "la": "saturday", "laurdag": "saturday", "sø": "sunday", "søn": "sunday", "søndag": "sunday", "e": "monday", "esmaspäev": "monday", "t": "tuesday", "teisipäev": "tuesday", "k": "tuesday", "kolmapäev": "wednesday", "n": "thursday", "neljapäev": "thursday", "r": "friday", "reede": "friday", "l": "saturday", "laupäev": "saturday", "p": "friday", "pühapäev": "sunday", "ayn": "monday", "aynas": "monday", "asinas": "tuesday", "asn": "tuesday", "akr": "wednesday", "akras": "wednesday", "akw": "thursday", "akwas": "thursday", "asimwas": "friday", "asm": "friday", "asiḍyas": "saturday", "asḍ": "saturday", "asamas": "sunday", "dy lun": "monday", "dy meurth": "tuesday", "mth": "tuesday", "dy merher": "wednesday", "mhr": "wednesday", "dy yow": "thursday", "yow": "thursday", "dy gwener": "friday", "gwe": "friday", "dy sadorn": "saturday", "sad": "saturday", "dy sul": "sunday", "sul": "sunday", "ሰኑ": "monday", "ሰኑይ": "monday", "ሠሉስ": "tuesday", "ሰሉ": "tuesday", "ሰሉስ": "tuesday", "ረቡ": "wednesday", "ሓሙ": "thursday", "ሓሙስ": "thursday", "ኃሙስ": "thursday", "ዓር": "friday", "ዓርቢ": "friday", "ቀዳ": "saturday", "ቀዳም": "saturday", "ሰን": "sunday", "ሰንበት": "sunday", "सोमवार": "monday", "मंगळ": "tuesday", "मंगळवार": "tuesday", "बुधवार": "wednesday", "गुरु": "thursday", "गुरुवार": "thursday", "शुक्रवार": "friday", "शनिवार": "saturday", "रवि": "sunday", "रविवार": "sunday", "δευ": "monday", "δευτέρα": "monday", "τρί": "tuesday", "τρίτη": "tuesday", "τετ": "wednesday", "τετάρτη": "wednesday", "πέμ": "thursday", "πέμπτη": "thursday", "παρ": "friday", "παρασκευή": "friday", "σάβ": "saturday", "σάββατο": "saturday", "κυρ": "sunday", "κυριακή": "sunday", "ꭴꮎꮩꮣꮙꮕꭿ": "monday", "ꮙꮕꭿ": "monday", "ꮤꮅꮑ": "tuesday", "ꮤꮅꮑꭲꭶ": "tuesday", "ꮶꭲꮑ": "wednesday", "ꮶꭲꮑꭲꭶ": "wednesday", "ꮕꭹꮑ": "thursday", "ꮕꭹꮑꭲꭶ": "thursday", "ꮷꮎꭹ": "friday", "ꮷꮎꭹꮆꮝꮧ": "friday", "ꭴꮎꮩꮣꮘꮥꮎ": "saturday", "ꮘꮥꮎ": "saturday", "ꭴꮎꮩꮣꮖꮝꭼ": "sunday", "ꮖꮝꭼ": "sunday", "pón": "monday", "pónjeźele": "monday", "wał": "tuesday", "wałtora": "tuesday", "srj": "wednesday", "srjoda": "wednesday", "stw": "thursday", "stwórtk": "thursday", "pět": "friday", "pětk": "friday", "njeźela": "sunday", "ntɛ": "monday", "ntɛnɛ": "monday", "tar": "tuesday", "tarata": "tuesday", "araba": "wednesday", "alamisa": "thursday", "sibiri": "saturday", "kar": "sunday", "kari": "sunday", "poniedziałek": "monday", "Poniedziałek": "monday", "Poniedzialek": "monday", "Pon": "monday", "Pn": "monday", "wt": "tuesday", "wtorek": "tuesday", "Wtorek": "tuesday", "Wt": "tuesday", "Wto": "tuesday", "śr": "wednesday", "środa": "wednesday", "Środa": "wednesday", "Sroda": "wednesday", "Środę": "wednesday", "Srode": "wednesday", "Śro": "wednesday", "Sro": "wednesday", "Śr": "wednesday", "Sr": "wednesday", "czw": "thursday", "czwartek": "thursday", "Czwartek": "thursday", "Czw": "thursday", "Cz": "thursday", "piątek": "friday", "pt": "friday", "Piątek": "friday", "Piatek": "friday", "Pią": "friday", "Pia": "friday", "Pi": "friday", "Pt": "friday", "Sobota": "saturday", "Sobotę": "saturday", "Sobote": "saturday", "Sob": "saturday", "So": "saturday", "Sb": "saturday", "niedz": "sunday", "niedziela": "sunday", "Niedziela": "sunday", "Niedzielę": "sunday", "Niedziele": "sunday", "Nie": "sunday", "Nd": "sunday", "luns": "monday", "mér": "wednesday", "mércores": "wednesday", "xov": "thursday", "xoves": "thursday", "ven": "friday", "venres": "friday", "sáb": "saturday", "sábado": "saturday", "domingo": "sunday", "базар ертәси": "monday", "бе": "monday", "ча": "tuesday", "чәршәнбә ахшамы": "tuesday", "ч": "wednesday", "чәршәнбә": "wednesday", "ҹа": "thursday", "ҹүмә ахшамы": "thursday", "ҹ": "friday", "ҹүмә": "friday", "ш": "saturday", "шәнбә": "saturday", "б": "sunday", "базар": "sunday", "wuok tich": "monday", "wut": "tuesday", "tich ariyo": "tuesday", "tad": "wednesday", "tich adek": "wednesday", "tich ang'wen": "thursday", "tab": "friday", "tich abich": "friday", "ngeso": "saturday", "ngs": "saturday", "jmp": "sunday", "jumapil": "sunday", "ସୋମ": "monday", "ସୋମବାର": "monday", "ମଙ୍ଗଳ": "tuesday", "ମଙ୍ଗଳବାର": "tuesday", "ବୁଧ": "wednesday", "ବୁଧବାର": "wednesday", "ଗୁରୁ": "thursday", "ଗୁରୁବାର": "thursday", "ଶୁକ୍ର": "friday", "ଶୁକ୍ରବାର": "friday", "ଶନି": "saturday", "ଶନିବାର": "saturday", "ରବି": "sunday", "ରବିବାର": "sunday", "да": "monday", "даваа": "monday", "мя": "tuesday", "мягмар": "tuesday", "лх": "wednesday", "лхагва": "wednesday", "пү": "thursday", "пүрэв": "thursday", "ба": "friday", "баасан": "friday", "бя": "saturday", "бямба": "saturday", "ня": "sunday", "ням": "sunday", "th 2": "monday", "thứ hai": "monday", "Thứ 2": "monday", "th 3": "tuesday", "thứ ba": "tuesday", "Thứ 3": "tuesday", "th 4": "wednesday", "thứ tư": "wednesday", "Thứ 4": "wednesday", "th 5": "thursday", "thứ năm": "thursday", "Thứ 5": "thursday", "th 6": "friday", "thứ sáu": "friday", "Thứ 6": "friday", "th 7": "saturday", "thứ bảy": "saturday", "Thứ 7": "saturday", "chủ nhật": "sunday", "cn": "sunday", "Thứ 1": "sunday", "pirmd": "monday", "pirmdiena": "monday", "otrd": "tuesday", "otrdiena": "tuesday", "trešd": "wednesday", "trešdiena": "wednesday", "ceturtd": "thursday", "ceturtdiena": "thursday", "piektd": "friday", "piektdiena": "friday", "sestd": "saturday", "sestdiena": "saturday", "svētd": "sunday", "svētdiena": "sunday", "дс": "monday", "дүйсенбі": "monday", "сейсенбі": "tuesday", "сс": "tuesday", "сәрсенбі": "wednesday", "бейсенбі": "thursday", "бс": "sunday", "жм": "friday", "жұма": "friday", "сенбі": "saturday", "жексенбі": "sunday", "жс": "sunday", "seg": "monday", "segunda-feira": "monday", "Segunda": "monday", "terça-feira": "tuesday", "Terça": "tuesday", "qua": "wednesday", "quarta-feira": "wednesday", "Quarta": "wednesday", "qui": "thursday", "quinta-feira": "thursday", "Quinta": "thursday", "sex": "friday", "sexta-feira": "friday", "Sexta": "friday", "Sab": "saturday", "bar": "monday", "nakaebarasa": "monday", "aar": "tuesday", "nakaare": "tuesday", "nakauni": "wednesday", "uni": "wednesday", "nakaung'on": "thursday", "ung": "thursday", "kan": "thursday", "nakakany": "friday", "nakasabiti": "saturday", "nakaejuma": "sunday", "mokɔlɔ mwa yambo": "monday", "ybo": "monday", "mbl": "tuesday", "mokɔlɔ mwa míbalé": "tuesday", "mokɔlɔ mwa mísáto": "wednesday", "mst": "wednesday", "mokɔlɔ ya mínéi": "thursday", "mokɔlɔ ya mítáno": "friday", "mtn": "friday", "mps": "saturday", "mpɔ́sɔ": "saturday", "eye": "sunday", "eyenga": "sunday", "ponedeljak": "monday", "uto": "tuesday", "utorak": "tuesday", "četvrtak": "thursday", "petak": "friday", "sub": "saturday", "subota": "saturday", "aneg 2": "monday", "aneg 3": "tuesday", "aneg 4": "wednesday", "aneg 5": "thursday", "aneg 6": "friday", "aneg 7": "saturday", "aneg 1": "sunday", "ژٔنٛدرٕروار": "monday", "ژٔنٛدٕروار": "monday", "بوٚموار": "tuesday", "بودوار": "wednesday", "برٛٮ۪سوار": "thursday", "جُمہ": "friday", "بٹوار": "saturday", "آتھوار": "sunday", "اَتھوار": "sunday", "póndźela": "monday", "wutora": "tuesday", "srjeda": "wednesday", "štw": "thursday", "štwórtk": "thursday", "pja": "friday", "pjatk": "friday", "njedźela": "sunday", "liduva lyatatu": "monday", "ll3": "monday", "liduva lyanchechi": "tuesday", "ll4": "tuesday", "liduva lyannyano": "wednesday", "ll5": "wednesday", "liduva lyannyano na linji": "thursday", "ll6": "thursday", "liduva lyannyano na mavili": "friday", "ll7": "friday", "liduva litandi": "saturday", "ll1": "saturday", "liduva lyapili": "sunday", "ll2": "sunday", "крс": "monday", "къуырисӕр": "monday", "дцг": "tuesday", "дыццӕг": "tuesday", "ӕрт": "wednesday", "ӕртыццӕг": "wednesday", "цпр": "thursday", "цыппӕрӕм": "thursday", "майрӕмбон": "friday", "мрб": "friday", "сабат": "saturday", "сбт": "saturday", "хуыцаубон": "sunday", "хцб": "sunday", "jumatátu": "monday", "jumane": "tuesday", "jumatánɔ": "wednesday", "alaámisi": "thursday", "jumáa": "friday", "jumamósi": "saturday", "jumapílí": "sunday", "සඳුදා": "monday", "අඟහ": "tuesday", "අඟහරුවාදා": "tuesday", "බදාදා": "wednesday", "බ්‍රහස්": "thursday", "බ්‍රහස්පතින්දා": "thursday", "සිකු": "friday", "සිකුරාදා": "friday", "සෙන": "saturday", "සෙනසුරාදා": "saturday", "ඉරිදා": "sunday", "mié": "wednesday", "miércoles": "wednesday", "jue": "thursday", "jueves": "thursday", "vie": "friday", "viernes": "friday", "સોમ": "monday", "સોમવાર": "monday", "મંગળ": "tuesday", "મંગળવાર": "tuesday", "બુધ": "wednesday", "બુધવાર": "wednesday", "ગુરુ": "thursday", "ગુરુવાર": "thursday", "શુક્ર": "friday", "શુક્રવાર": "friday", "શનિ": "saturday", "શનિવાર": "saturday", "રવિ": "sunday", "રવિવાર": "sunday", "ຈັນ": "monday", "ວັນຈັນ": "monday", "ວັນອັງຄານ": "tuesday", "ອັງຄານ": "tuesday", "ພຸດ": "wednesday", "ວັນພຸດ": "wednesday", "ພະຫັດ": "thursday", "ວັນພະຫັດ": "thursday", "ວັນສຸກ": "friday", "ສຸກ": "friday", "ວັນເສົາ": "saturday", "ເສົາ": "saturday", "ວັນອາທິດ": "sunday", "ອາທິດ": "sunday", "mra": "monday", "muramuko": "monday", "wai": "tuesday", "wairi": "tuesday", "wet": "wednesday", "wethatu": "wednesday", "wen": "wednesday", "wena": "thursday", "wetano": "friday", "wtn": "friday", "kiu": "sunday", "e hënë": "monday", "hën": "monday", "e martë": "tuesday", "e mërkurë": "wednesday", "mër": "wednesday", "e enjte": "thursday", "enj": "thursday", "e premte": "friday", "pre": "friday", "e shtunë": "saturday", "sht": "saturday", "die": "sunday", "e diel": "sunday", "Lunes": "monday", "Lun": "monday", "Martes": "tuesday", "Miyerkules": "wednesday", "Miy": "wednesday", "Huwebes": "thursday", "Huw": "thursday", "Biyernes": "friday", "Biy": "friday", "Sabado": "saturday", "Linggo": "sunday", "Lin": "sunday", "ⴰⵙⵉⵎⵡⴰⵙ": "friday", "د": "saturday", "دوشنبه": "monday", "س": "tuesday", "سه‌شنبه": "tuesday", "چ": "wednesday", "چهارشنبه": "wednesday", "پ": "thursday", "پنجشنبه": "thursday", "ج": "friday", "جمعه": "friday", "ش": "saturday", "شنبه": "saturday", "ی": "sunday", "یکشنبه": "sunday", "palichimo": "monday", "palichibuli": "tuesday", "palichitatu": "wednesday", "palichine": "thursday", "palichisano": "friday", "pachibelushi": "saturday", "md": "monday", "móndie": "monday", "muányáŋmóndie": "tuesday", "mw": "tuesday", "et": "wednesday", "metúkpíápɛ": "wednesday", "kl": "thursday", "kúpélimetúkpiapɛ": "thursday", "feléte": "friday", "fl": "friday", "ss": "saturday", "séselé": "saturday", "sd": "sunday", "sɔ́ndiɛ": "sunday", "ಸೋಮ": "monday", "ಸೋಮವಾರ": "monday", "ಮಂಗಳ": "tuesday", "ಮಂಗಳವಾರ": "tuesday", "ಬುಧ": "wednesday", "ಬುಧವಾರ": "wednesday", "ಗುರು": "thursday", "ಗುರುವಾರ": "thursday", "ಶುಕ್ರ": "friday", "ಶುಕ್ರವಾರ": "friday", "ಶನಿ": "saturday", "ಶನಿವಾರ": "saturday", "ಭಾನು": "sunday", "ಭಾನುವಾರ": "sunday", "ajé": "monday", "ọjọ́ ajé": "monday", "ìsẹ́gun": "tuesday", "ọjọ́ ìsẹ́gun": "tuesday", "ọjọ́rú": "wednesday", "ọjọ́bọ": "thursday", "ẹtì": "friday", "ọjọ́ ẹtì": "friday", "àbámẹ́ta": "saturday", "ọjọ́ àbámẹ́ta": "saturday", "àìkú": "sunday", "ọjọ́ àìkú": "sunday", "astelehena": "monday", "ar": "tuesday", "asteartea": "tuesday", "asteazkena": "wednesday", "az": "wednesday", "og": "thursday", "osteguna": "thursday", "or": "friday", "ostirala": "friday", "larunbata": "saturday", "lr": "saturday", "ig": "sunday", "igandea": "sunday", "четврток": "thursday", "петок": "friday", "саб": "saturday", "сабота": "saturday", "недела": "sunday", "понедельник": "monday", "пнд": "monday", "втр": "tuesday", "срд": "wednesday", "среду": "wednesday", "четверг": "thursday", "чтв": "thursday", "пятница": "friday", "птн": "friday", "пятницу": "friday", "суббота": "saturday", "субботу": "saturday", "воскресенье": "sunday", "вс": "sunday", "воскресение": "sunday", "вск": "sunday", "aŋpétuwaŋži": "monday", "aŋpétunuŋpa": "tuesday", "aŋpétuyamni": "wednesday", "aŋpétutopa": "thursday", "aŋpétuzaptaŋ": "friday", "owáŋgyužažapi": "saturday", "aŋpétuwakȟaŋ": "sunday", "จ": "monday", "วันจันทร์": "monday", "จันทร์": "monday", "วันจันทร์ที่": "monday", "วันอังคาร": "tuesday", "อ": "tuesday", "อังคาร": "tuesday", "วันอังคารที่": "tuesday", "พ": "wednesday", "วันพุธ": "wednesday", "พุธ": "wednesday", "วันพุธที่": "wednesday", "พฤ": "thursday", "วันพฤหัสบดี": "thursday", "พฤหัสบดี": "thursday", "พฤหัส": "thursday", "วันพฤหัสบดีที่": "thursday", "วันศุกร์": "friday", "ศ": "friday", "ศุกร์": "friday", "วันศุกร์ที่": "friday", "วันเสาร์": "saturday", "ส": "saturday", "เสาร์": "saturday", "วันเสาร์ที่": "saturday", "วันอาทิตย์": "sunday", "อา": "sunday", "อาทิตย์": "sunday", "วันอาทิตย์ที่": "sunday", "maanantai": "monday", "maanantaina": "monday", "ti": "tuesday", "tiistai": "tuesday", "tiistaina": "tuesday", "ke": "wednesday", "keskiviikko": "wednesday", "keskiviikkona": "wednesday", "torstai": "thursday", "torstaina": "thursday", "pe": "friday", "perjantai": "friday", "perjantaina": "friday", "lauantai": "saturday", "lauantaina": "saturday", "sunnuntai": "sunday", "sunnuntaina": "sunday", "душ": "monday", "душанба": "monday", "сеш": "tuesday", "сешанба": "tuesday", "чор": "wednesday", "чоршанба": "wednesday", "пай": "thursday", "пайшанба": "thursday", "жум": "friday", "шан": "saturday", "шанба": "saturday", "якш": "sunday", "якшанба": "sunday", "isn": "monday", "isnin": "monday", "kha": "thursday", "khamis": "thursday", "jumaat": "friday", "ahad": "sunday", "ahd": "sunday", "gli": "monday", "glindesdi": "monday", "me": "wednesday", "mesemna": "wednesday", "gie": "thursday", "gievgia": "thursday", "ve": "friday", "venderdi": "friday", "sonda": "saturday", "dumengia": "sunday", "ponedjeljak": "monday", "sri": "wednesday", "srijeda": "wednesday", "nedjelja": "sunday", "ijt": "monday", "ijumatatu": "monday", "ijn": "tuesday", "ijumanne": "tuesday", "ijtn": "wednesday", "ijumatano": "wednesday", "ijumamosi": "saturday", "ijp": "sunday", "ijumapili": "sunday", "തിങ്കളാഴ്‌ച": "monday", "തിങ്കൾ": "monday", "ചൊവ്വ": "tuesday", "ചൊവ്വാഴ്ച": "tuesday", "ചൊവ്വാഴ്‌ച": "tuesday", "ബുധനാഴ്‌ച": "wednesday", "ബുധൻ": "wednesday", "വ്യാഴം": "thursday", "വ്യാഴാഴ്‌ച": "thursday", "വെള്ളി": "friday", "വെള്ളിയാഴ്‌ച": "friday", "ശനി": "saturday", "ശനിയാഴ്‌ച": "saturday", "ഞായറാഴ്‌ച": "sunday", "ഞായർ": "sunday", "nja": "monday", "ŋgwà njaŋgumba": "monday", "uum": "tuesday", "ŋgwà ûm": "tuesday", "ŋge": "wednesday", "ŋgwà ŋgê": "wednesday", "mbɔ": "thursday", "ŋgwà mbɔk": "thursday", "kɔɔ": "friday", "ŋgwà kɔɔ": "friday", "jon": "saturday", "ŋgwà jôn": "saturday", "nɔy": "sunday", "ŋgwà nɔ̂y": "sunday", "ཟླ་བ་": "monday", "མིག་དམར་": "tuesday", "ལྷག་པ་": "wednesday", "ཕུར་བུ་": "thursday", "པ་སངས་": "friday", "སྤེན་པ་": "saturday", "ཉི་མ་": "sunday", "jiec": "monday", "jiec la̱t": "monday", "rɛw": "tuesday", "rɛw lätni": "tuesday", "diɔ̱k": "wednesday", "diɔ̱k lätni": "wednesday", "ŋuaan": "thursday", "ŋuaan lätni": "thursday", "dhieec": "friday", "dhieec lätni": "friday", "bäkɛl": "saturday", "bäkɛl lätni": "saturday", "cäŋ": "sunday", "cäŋ kuɔth": "sunday", "kpa": "monday", "tsuʔukpà": "monday", "ghɔ": "tuesday", "tsuʔughɔe": "tuesday", "tsuʔutɔ̀mlò": "wednesday", "tɔm": "wednesday", "tsuʔumè": "thursday", "ume": "thursday", "ghɨ": "friday", "tsuʔughɨ̂m": "friday", "dzk": "saturday", "tsuʔndzɨkɔʔɔ": "saturday", "nts": "sunday", "tsuʔntsɨ": "sunday", "njumatatũ": "monday", "ntt": "monday", "nmn": "tuesday", "njumatana": "wednesday", "nmt": "wednesday", "art": "thursday", "nma": "friday", "njumamothi": "saturday", "dwo": "monday", "dwowda": "monday", "ben": "tuesday", "benada": "tuesday", "wuk": "wednesday", "wukuda": "wednesday", "yaw": "thursday", "yawda": "thursday", "fia": "friday", "fida": "friday", "mem": "saturday", "memeneda": "saturday", "kwe": "saturday", "kwesida": "sunday", "pirmadienis": "monday", "pr": "monday", "an": "tuesday", "antradienis": "tuesday", "tr": "wednesday", "trečiadienis": "wednesday", "ketvirtadienis": "thursday", "kt": "thursday", "penktadienis": "friday", "pn": "friday", "šeštadienis": "saturday", "št": "thursday", "sekmadienis": "sunday", "sk": "sunday", "it-tnejn": "monday", "tne": "monday", "it-tlieta": "tuesday", "tli": "tuesday", "erb": "wednesday", "l-erbgħa": "wednesday", "il-ħamis": "thursday", "ħam": "thursday", "il-ġimgħa": "friday", "ġim": "friday", "is-sibt": "saturday", "il-ħadd": "sunday", "ħad": "sunday", "h": "monday", "hétfő": "monday", "kedd": "tuesday", "sze": "wednesday", "szerda": "wednesday", "cs": "thursday", "csütörtök": "thursday", "péntek": "friday", "szo": "saturday", "szombat": "saturday", "v": "sunday", "vasárnap": "sunday", "vas": "sunday", "jel": "monday", "jelhein": "monday", "jem": "tuesday", "jemayrt": "tuesday", "jerc": "wednesday", "jercean": "wednesday", "jerd": "thursday", "jerdein": "thursday", "jeh": "friday", "jeheiney": "friday", "jes": "saturday", "jesarn": "saturday", "jed": "sunday", "jedoonee": "sunday", "mōn": "monday", "mōnite": "monday", "tūs": "tuesday", "tūsite": "tuesday", "pul": "wednesday", "pulelulu": "wednesday", "tu'a": "thursday", "tu'apulelulu": "thursday", "fal": "friday", "falaite": "friday", "tok": "saturday", "tokonaki": "saturday", "sāp": "sunday", "sāpate": "sunday", "সোম": "monday", "সোমবাৰ": "monday", "মঙ্গল": "tuesday", "মঙ্গলবাৰ": "tuesday", "বুধ": "wednesday", "বুধবাৰ": "wednesday", "বৃহষ্পতি": "thursday", "বৃহষ্পতিবাৰ": "thursday", "শুক্ৰ": "friday", "শুক্ৰবাৰ": "friday", "শনি": "saturday", "শনিবাৰ": "saturday", "দেওবাৰ": "sunday", "ৰবি": "sunday", "mɔ́s": "monday", "mɔ́sú": "monday", "kwa": "tuesday", "kwasú": "tuesday", "muk": "wednesday", "mukɔ́sú": "wednesday", "ŋgi": "thursday", "ŋgisú": "thursday", "ɗón": "friday", "ɗónɛsú": "friday", "esa": "saturday", "esaɓasú": "saturday", "ét": "sunday", "éti": "sunday", "chumatato": "monday", "ctt": "monday", "chumaine": "tuesday", "cmn": "tuesday", "chumatano": "wednesday", "cmt": "saturday", "aramisi": "thursday", "ars": "thursday", "ichuma": "friday", "icm": "friday", "esabato": "saturday", "est": "saturday", "chumapiri": "sunday", "cpr": "sunday", "lundi": "monday", "lu": "monday", "mercredi": "wednesday", "jeu": "thursday", "jeudi": "thursday", "je": "thursday", "vendredi": "friday", "samedi": "saturday", "dimanche": "sunday", "vrijdag": "friday", "za": "saturday", "zaterdag": "saturday", "zo": "sunday", "zondag": "sunday", "po": "monday", "pondělí": "monday", "út": "tuesday", "úterý": "tuesday", "Úte": "tuesday", "st": "wednesday", "středa": "wednesday", "Stř": "wednesday", "Středu": "wednesday", "čt": "thursday", "čtvrtek": "thursday", "Čtv": "thursday", "pá": "friday", "pátek": "friday", "Pát": "friday", "Sobotu": "saturday", "ne": "sunday", "neděle": "sunday", "Ned": "sunday", "Neděli": "sunday", "ច័ន្ទ": "monday", "អង្គារ": "tuesday", "ពុធ": "wednesday", "ព្រហស្បតិ៍": "thursday", "សុក្រ": "friday", "សៅរ៍": "saturday", "អាទិត្យ": "sunday", "muv": "monday", "muvhuro": "monday", "chipiri": "tuesday", "chp": "tuesday", "chitatu": "wednesday", "cht": "wednesday", "china": "thursday", "chn": "thursday", "chishanu": "friday", "chs": "friday", "mug": "saturday", "mugovera": "saturday", "svo": "sunday", "svondo": "sunday", "मंगल": "tuesday", "मंगलवार": "tuesday", "周一": "monday", "礼拜一": "monday", "周二": "tuesday", "礼拜二": "tuesday", "周三": "wednesday", "礼拜三": "wednesday", "周四": "thursday", "礼拜四": "thursday", "周五": "friday", "礼拜五": "friday", "周六": "saturday", "礼拜六": "saturday", "周日": "sunday", "星期天": "sunday", "礼拜日": "sunday", "礼拜天": "sunday", "понеділок": "monday", "понед": "monday", "вівторок": "tuesday", "вів": "tuesday", "вівтор": "tuesday", "середа": "wednesday", "сер": "wednesday", "серед": "wednesday", "середу": "wednesday", "четвер": "thursday", "п'ятниця": "friday", "пят": "friday", "субот": "saturday", "суботу": "saturday", "неділя": "sunday", "неділ": "sunday", "неділю": "sunday", "mvfò lyɛ̌'": "monday", "mbɔ́ɔntè mvfò lyɛ̌'": "tuesday", "tsètsɛ̀ɛ lyɛ̌'": "wednesday", "mbɔ́ɔntè tsetsɛ̀ɛ lyɛ̌'": "thursday", "mvfò màga lyɛ̌'": "friday", "màga lyɛ̌'": "saturday", "lyɛ'ɛ́ sẅíŋtè": "sunday", "Månd": "monday", "tis": "tuesday", "tisdag": "tuesday", "Tisd": "tuesday", "Onsd": "wednesday", "tors": "thursday", "Torsd": "thursday", "Fred": "friday", "lör": "saturday", "lördag": "saturday", "Lörd": "saturday", "sön": "sunday", "söndag": "sunday", "Sönd": "sunday", "ꆏꊂꋍ": "monday", "ꆏꋍ": "monday", "ꆏꊂꑍ": "tuesday", "ꆏꑍ": "tuesday", "ꆏꊂꌕ": "wednesday", "ꆏꌕ": "wednesday", "ꆏꇖ": "thursday", "ꆏꊂꇖ": "thursday", "ꆏꉬ": "friday", "ꆏꊂꉬ": "friday", "ꆏꃘ": "saturday", "ꆏꊂꃘ": "saturday", "ꑭꆏ": "sunday", "ꑭꆏꑍ": "sunday", "ku wa mbere": "monday", "mbe": "monday", "kab": "tuesday", "ku wa kabiri": "tuesday", "gtu": "wednesday", "ku wa gatatu": "wednesday", "ku wa kane": "thursday", "gnu": "friday", "ku wa gatanu": "friday", "gnd": "saturday", "ku wa gatandatu": "saturday", "cu": "sunday", "ku w'indwi": "sunday", "mon": "monday", "monday": "monday", "tue": "tuesday", "tuesday": "tuesday", "Tues": "tuesday", "wed": "wednesday", "wednesday": "wednesday", "thu": "thursday", "thursday": "thursday", "fri": "friday", "friday": "friday", "saturday": "saturday", "sunday": "sunday", "sanass": "monday", "kraḍ": "tuesday", "kraḍass": "tuesday", "kuẓ": "wednesday", "kuẓass": "wednesday", "samass": "thursday", "sḍis": "friday", "sḍisass": "friday", "say": "saturday", "sayass": "saturday", "yan": "sunday", "yanass": "sunday", "akṛ": "wednesday", "akṛas": "wednesday", "asim": "friday", "asiḍ": "saturday", "pazartesi": "monday", "pzt": "monday", "sal": "tuesday", "salı": "tuesday", "çar": "wednesday", "çarşamba": "wednesday", "Çrs": "wednesday", "per": "thursday", "perşembe": "thursday", "Prs": "thursday", "cum": "friday", "cuma": "friday", "cumartesi": "saturday", "paz": "sunday", "pazar": "sunday", "ataasinngorneq": "monday", "marlunngorneq": "tuesday", "pin": "wednesday", "pingasunngorneq": "wednesday", "sis": "thursday", "sisamanngorneq": "thursday", "tallimanngorneq": "friday", "arf": "saturday", "arfininngorneq": "saturday", "sabaat": "sunday", "isniin": "monday", "talaado": "tuesday", "arb": "wednesday", "arbaco": "wednesday", "khamiis": "thursday", "jim": "monday", "jimco": "friday", "sabti": "saturday", "axad": "sunday", "axd": "sunday", "kuwa mbere": "monday", "kuwa kabiri": "tuesday", "kuwa gatatu": "wednesday", "kuwa kane": "thursday", "kuwa gatanu": "friday", "kuwa gatandatu": "saturday", "cyu": "sunday", "ku cyumweru": "sunday", "arahamisi": "thursday", "sabato": "saturday", "luni": "monday", "marți": "tuesday", "miercuri": "wednesday", "Mi": "wednesday", "vineri": "friday", "sâm": "saturday", "sâmbătă": "saturday", "duminică": "sunday", "lundo": "monday", "mardo": "tuesday", "merkredo": "wednesday", "ĵa": "thursday", "ĵaŭdo": "thursday", "vendredo": "friday", "dimanĉo": "sunday", "meu": "tuesday", "meurzh": "tuesday", "merc'her": "wednesday", "yaou": "thursday", "gwener": "friday", "sadorn": "saturday", "wiixata": "monday", "wix": "monday", "qib": "tuesday", "qibxata": "tuesday", "rob": "wednesday", "roobii": "wednesday", "kamiisa": "thursday", "jimaata": "friday", "sanbata": "saturday", "dil": "monday", "dilbata": "sunday", "سوموار": "monday", "بدھ": "wednesday", "jumaatatu": "monday", "jmn": "tuesday", "jumaane": "tuesday", "jumaatano": "wednesday", "jumaamosi": "saturday", "jumaapii": "sunday", "Lu": "monday", "Ju": "thursday", "Vi": "friday", "Sa": "saturday", "Do": "sunday", "সোমবার": "monday", "মঙ্গলবার": "tuesday", "বুধবার": "wednesday", "বৃহষ্পতিবার": "thursday", "বৃহস্পতি": "thursday", "বৃহস্পতিবার": "thursday", "শুক্র": "friday", "শুক্রবার": "friday", "শনিবার": "saturday", "রবি": "sunday", "রবিবার": "sunday", "מאָנטיק": "monday", "דינסטיק": "tuesday", "מיטוואך": "wednesday", "דאנערשטיק": "thursday", "פֿרײַטיק": "friday", "זונטיק": "sunday", "pondelok": "monday", "ut": "tuesday", "utorok": "tuesday", "streda": "wednesday", "stredu": "wednesday", "štvrtok": "thursday", "pi": "friday", "piatok": "friday", "sobotu": "saturday", "nedeľa": "sunday", "nedeľu": "sunday", "lunedì": "monday", "martedì": "tuesday", "mercoledì": "wednesday", "gio": "thursday", "giovedì": "thursday", "venerdì": "friday", "domenica": "sunday", "llu": "monday", "llunes": "monday", "xue": "thursday", "xueves": "thursday", "vienres": "friday", "domingu": "sunday", "nko": "monday", "nkodya": "monday", "ndy": "tuesday", "ndàayà": "tuesday", "ndangù": "wednesday", "ndg": "wednesday", "njw": "thursday", "njòwa": "thursday", "ngv": "friday", "ngòvya": "friday", "lub": "saturday", "lubingu": "saturday", "lum": "sunday", "lumingu": "sunday", "mso": "monday", "umsombuluko": "monday", "bil": "tuesday", "ulwesibili": "tuesday", "tha": "wednesday", "ulwesithathu": "wednesday", "ulwesine": "thursday", "hla": "friday", "ulwesihlanu": "friday", "umgqibelo": "saturday", "isonto": "sunday", "mánadagur": "monday", "týs": "tuesday", "týsdagur": "tuesday", "mik": "wednesday", "mikudagur": "wednesday", "hós": "thursday", "hósdagur": "thursday", "frí": "friday", "fríggjadagur": "friday", "ley": "saturday", "leygardagur": "saturday", "الاثنين": "monday", "الإثنين": "monday", "الثلاثاء": "tuesday", "الأربعاء": "wednesday", "الخميس": "thursday", "الجمعة": "friday", "السبت": "saturday", "الأحد": "sunday", "lǝn": "monday", "lǝndí": "monday", "maadí": "tuesday", "mɛk": "wednesday", "mɛkrɛdí": "wednesday", "jǝǝ": "thursday", "jǝǝdí": "thursday", "júm": "friday", "júmbá": "friday", "samdí": "saturday", "sɔ́n": "sunday", "sɔ́ndǝ": "sunday", "diluain": "monday", "dimàirt": "tuesday", "dic": "wednesday", "diciadain": "wednesday", "dia": "thursday", "diardaoin": "thursday", "dih": "friday", "dihaoine": "friday", "dis": "saturday", "disathairne": "saturday", "did": "sunday", "didòmhnaich": "sunday", "панядзелак": "monday", "Пнд": "monday", "аў": "tuesday", "аўторак": "tuesday", "Аўт": "tuesday", "серада": "wednesday", "чацвер": "thursday", "чц": "thursday", "Чцв": "thursday", "Чв": "thursday", "пятніца": "friday", "Пят": "friday", "Суб": "saturday", "нядзеля": "sunday", "Няд": "sunday", "دووشەممە": "monday", "سێشەممە": "tuesday", "چوارشەممە": "wednesday", "پێنجشەممە": "thursday", "ھەینی": "friday", "شەممە": "saturday", "یەکشەممە": "sunday", "man": "monday", "mandag": "monday", "tir": "tuesday", "tirsdag": "tuesday", "lør": "saturday", "lørdag": "saturday", "chiposi": "monday", "pos": "monday", "pir": "tuesday", "chinai": "thursday", "nai": "thursday", "sha": "friday", "sabudu": "saturday", "dimingu": "sunday", "kun": "monday", "mderot ee kuni": "monday", "mderot ee ong'wan": "tuesday", "ong": "tuesday", "mderot ee inet": "wednesday", "ile": "thursday", "mderot ee ile": "thursday", "mderot ee sapa": "friday", "sap": "friday", "mderot ee kwe": "saturday", "are": "sunday", "mderot ee are": "sunday", "vuo": "monday", "vuossaargâ": "monday", "vuossargâ": "monday", "maj": "tuesday", "majebaargâ": "tuesday", "majebargâ": "tuesday", "kos": "wednesday", "koskoho": "wednesday", "koskokko": "wednesday", "tuo": "thursday", "tuorâstuv": "thursday", "tuorâstâh": "thursday", "vás": "friday", "vástuppeeivi": "friday", "vástuppeivi": "friday", "láv": "saturday", "lávurduv": "saturday", "lávurdâh": "saturday", "pas": "sunday", "pasepeeivi": "sunday", "pasepeivi": "sunday", "mọn": "monday", "mọnde": "monday", "tiu": "tuesday", "tiuzdee": "tuesday", "wenezdee": "wednesday", "tọọ": "thursday", "tọọzdee": "thursday", "fraị": "friday", "fraịdee": "friday", "satọdee": "saturday", "mbọsị ụka": "sunday", "ụka": "sunday", "सम": "monday", "समबार": "monday", "मंगलबार": "tuesday", "बुद": "wednesday", "बुदबार": "wednesday", "बिसथि": "thursday", "बिसथिबार": "thursday", "सुखुर": "friday", "सुखुरबार": "friday", "सुनि": "saturday", "सुनिबार": "saturday", "रबि": "sunday", "रबिबार": "sunday", "оршотан де": "monday", "шинарин де": "tuesday", "кхаарин де": "wednesday", "еарин де": "thursday", "пӏераскан де": "friday", "шот де": "saturday", "кӏиранан де": "sunday", "సోమ": "monday", "సోమవారం": "monday", "మంగళ": "tuesday", "మంగళవారం": "tuesday", "బుధ": "wednesday", "బుధవారం": "wednesday", "గురు": "thursday", "గురువారం": "thursday", "శుక్ర": "friday", "శుక్రవారం": "friday", "శని": "saturday", "శనివారం": "saturday", "ఆది": "sunday", "ఆదివారం": "sunday", "táatu": "monday", "jumaíne": "tuesday", "íne": "tuesday", "jumatáano": "wednesday", "táano": "wednesday", "alamíisi": "thursday", "ijumáa": "friday", "jumamóosi": "saturday", "móosi": "saturday", "jumapíiri": "sunday", "píili": "sunday", "dydd llun": "monday", "llun": "monday", "dydd mawrth": "tuesday", "dydd mercher": "wednesday", "dydd iau": "thursday", "iau": "thursday", "dydd gwener": "friday", "gwen": "friday", "dydd sadwrn": "saturday", "dydd sul": "sunday", "män": "monday", "mäntag": "monday", "ziš": "tuesday", "zištag": "tuesday", "mit": "wednesday", "mittwuč": "wednesday", "fró": "thursday", "fróntag": "thursday", "fritag": "friday", "samštag": "saturday", "sunntag": "sunday", "मंगळार": "tuesday", "आदित्यवार": "sunday", "p1": "monday", "po'akahi": "monday", "p2": "tuesday", "po'alua": "tuesday", "p3": "wednesday", "po'akolu": "wednesday", "p4": "thursday", "po'ahā": "thursday", "p5": "friday", "po'alima": "friday", "p6": "saturday", "po'aono": "saturday", "lp": "sunday", "lāpule": "sunday", "méi": "monday", "méindeg": "monday", "dën": "tuesday", "dënschdeg": "tuesday", "mët": "wednesday", "mëttwoch": "wednesday", "don": "thursday", "donneschdeg": "thursday", "freideg": "friday", "samschdeg": "saturday", "sonndeg": "sunday", "திங்": "monday", "திங்கள்": "monday", "செவ்": "tuesday", "செவ்வாய்": "tuesday", "புத": "wednesday", "புதன்": "wednesday", "வியா": "thursday", "வியாழன்": "thursday", "வெள்": "friday", "வெள்ளி": "friday", "சனி": "saturday", "ஞாயி": "sunday", "ஞாயிறு": "sunday", "dilluns": "monday", "dl": "monday", "dimarts": "tuesday", "dt": "tuesday", "dc": "wednesday", "dimecres": "wednesday", "dijous": "thursday", "dj": "thursday", "divendres": "friday", "dv": "friday", "dissabte": "saturday", "ds": "saturday", "dg": "sunday", "diumenge": "sunday", "mo": "monday", "montag": "monday", "Mon": "monday", "dienstag": "tuesday", "Die": "tuesday", "mittwoch": "wednesday", "Mit": "wednesday", "donnerstag": "thursday", "Don": "thursday", "freitag": "friday", "Fre": "friday", "samstag": "saturday", "Sam": "saturday", "sonntag": "sunday", "Son": "sunday", "vuos": "monday", "vuossárga": "monday", "maŋ": "tuesday", "maŋŋebárga": "tuesday", "gask": "wednesday", "gaskavahkku": "wednesday", "duor": "thursday", "duorasdat": "thursday", "bear": "friday", "bearjadat": "friday", "lávvardat": "saturday", "sotn": "sunday", "sotnabeaivi": "sunday", "moandei": "monday", "tiisdei": "tuesday", "woansdei": "wednesday", "tongersdei": "thursday", "freed": "friday", "sneon": "saturday", "si": "sunday", "snein": "sunday", "dé luain": "monday", "luan": "monday", "dé máirt": "tuesday", "máirt": "tuesday", "céad": "wednesday", "dé céadaoin": "wednesday", "déar": "thursday", "déardaoin": "thursday", "aoine": "friday", "dé haoine": "friday", "dé sathairn": "saturday", "sath": "saturday", "domh": "sunday", "dé domhnaigh": "sunday", "kot": "monday", "kotaai": "monday", "koaeng'": "tuesday", "koo": "tuesday", "kosomok": "wednesday", "koa": "thursday", "koang'wan": "thursday", "kom": "friday", "komuut": "friday", "kol": "saturday", "kolo": "saturday", "kotisap": "sunday", "kts": "sunday", "alahamisi": "thursday", "mulungu": "sunday", "mohndaach": "monday", "dinnsdaach": "tuesday", "metwoch": "wednesday", "dunnersdaach": "thursday", "friidaach": "friday", "samsdaach": "saturday", "sunndaach": "sunday", "سهشنبه": "tuesday", "چهار شنبه": "wednesday", "پنج شنبه": "thursday", "روز شنبه": "saturday", "دو شنبه": "saturday", "mɔ́n": "monday", "mɔ́ndɔ": "monday", "smb": "tuesday", "sɔ́ndɔ mafú mába": "tuesday", "sml": "wednesday", "sɔ́ndɔ mafú málal": "wednesday", "smn": "thursday", "sɔ́ndɔ mafú mána": "thursday", "mabágá má sukul": "friday", "mbs": "friday", "sas": "saturday", "sásadi": "saturday", "sɔ́ndɔ": "sunday", "dzo": "monday", "dzoɖa": "monday", "bla": "tuesday", "blaɖa": "tuesday", "kuɖ": "wednesday", "kuɖa": "wednesday", "yawoɖa": "thursday", "fiɖ": "friday", "fiɖa": "friday", "memleɖa": "saturday", "kɔs": "sunday", "kɔsiɖa": "sunday", "alats": "monday", "alatsinainy": "monday", "alar": "wednesday", "alarobia": "wednesday", "alak": "thursday", "alakamisy": "thursday", "zom": "friday", "zoma": "friday", "asab": "saturday", "asabotsy": "saturday", "alah": "sunday", "alahady": "sunday", "сри": "wednesday", "сриједа": "wednesday", "kuramuka jimweri": "monday", "kaw": "tuesday", "kuramuka kawi": "tuesday", "kad": "wednesday", "kuramuka kadadu": "wednesday", "kuramuka kana": "thursday", "kas": "friday", "kuramuka kasanu": "friday", "kifula nguwo": "saturday", "ngu": "saturday", "ituku ja jumwa": "sunday", "wa kwambĩlĩlya": "monday", "wkw": "monday", "wa kelĩ": "tuesday", "wkl": "tuesday", "wa katatũ": "wednesday", "wtũ": "wednesday", "wa kana": "thursday", "wkn": "thursday", "wa katano": "friday", "wa thanthatũ": "saturday", "wth": "saturday", "wa kyumwa": "sunday", "wky": "sunday", "бн": "monday", "бэнидиэнньик": "monday", "оп": "tuesday", "оптуорунньук": "tuesday", "сэ": "wednesday", "сэрэдэ": "wednesday", "чп": "thursday", "чэппиэр": "thursday", "бэ": "friday", "бээтиҥсэ": "friday", "субуота": "saturday", "баскыһыанньа": "sunday", "sɔ́ndɔ məlú mə́bɛ̌": "tuesday", "sɔ́ndɔ məlú mə́lɛ́": "wednesday", "sɔ́ndɔ məlú mə́nyi": "thursday", "fúl": "friday", "fúladé": "friday", "sér": "saturday", "séradé": "saturday", "bal": "monday", "lw2": "tuesday", "lwakubiri": "tuesday", "lw3": "wednesday", "lwakusatu": "wednesday", "lw4": "thursday", "lwakuna": "thursday", "lw5": "friday", "lwakutaano": "friday", "lw6": "saturday", "lwamukaaga": "saturday", "sabbiiti": "sunday"}"#;
@@ -93,6 +288,21 @@ static TEXT_MERIDIAN:Lazy<HashMap<&str, String>> = Lazy::new(|| {
     return serde_json::from_str(seed).unwrap()
 });
 
+/// 内置词库｜未指定 Param.lexicon 时使用
+static LEXICON:Lazy<Arc<Lexicon>> = Lazy::new(|| {
+    let text = |items: &HashMap<&str, String>| items.iter().map(|(k, v)| (k.to_string(), v.clone())).collect();
+    let lexicon = Lexicon{
+        month: text(&TEXT_MONTH),
+        relative: text(&TEXT_RELATIVE),
+        relative_order: ARRAY_RELATIVE.iter().map(|v| v.to_string()).collect(),
+        weekday: text(&TEXT_WEEKDAY),
+        meridian: text(&TEXT_MERIDIAN),
+        timezone: TIMEZONE_OFFSET.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        holiday: TEXT_HOLIDAY.clone(),
+    };
+    Arc::new(lexicon)
+});
+
 static TEXT_MONTH_FULL:Lazy<HashMap<&str, &str>> = Lazy::new(|| {
     let seed = r#"{"january": "01", "february": "02", "march": "03", "april": "04", "may": "05", "june": "06", "july": "07", "august": "08", "september": "09", "october": "10", "november": "11", "december": "12"}"#;
     return serde_json::from_str(seed).unwrap()
@@ -122,44 +332,367 @@ static REGEX_SPECIAL_EXPRESS_TIME:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
 });
 
 
+/// 不是时间的词语｜(钟点单位, 其后的字) 例如 点赞 時間 钟头
+const TEXT_HOUR_STOP: [(&str, &str); 3] = [("点點", "赞讚击擊评評"), ("时時", "间間长長辰"), ("钟鐘", "头頭")];
+
+/// 英文的钟点数字
+static TEXT_HOUR_EN:Lazy<HashMap<&str, u32>> = Lazy::new(|| {
+    let seed = r#"{"one": 1, "two": 2, "three": 3, "four": 4, "five": 5, "six": 6, "seven": 7, "eight": 8, "nine": 9, "ten": 10, "eleven": 11, "twelve": 12}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 仅有钟点的时间表达正则表达式
+/// meridian 紧贴数字或汉字的中日韩午线 例如 日下午3点 -> 日 下午 3点
+/// unit 钟点单位 例如 3点 15時 3点钟 -> 3:00 同时兼容 3点30分 -> 3:30
+/// meridian_en 钟点+午线 例如 3pm 3 p.m. -> 3:00 pm
+/// h 法语等以 h 作为钟点单位 例如 9h -> 9:00
+/// half_past 例如 half past three -> 3:30
+/// clock 例如 3 o'clock -> 3:00
+static REGEX_HOUR:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let hours: Vec<&str> = TEXT_HOUR_EN.keys().copied().collect();
+    let hours = hours.join("|");
+    items.insert("meridian", Regex::new(r"(上午|下午|午前|午後|오전|오후)").unwrap());
+    items.insert("unit", Regex::new(r"(\d{1,2})\s*(点|點|时|時)(钟|鐘|整)?").unwrap());
+    items.insert("minute", Regex::new(r"^(?:\s*(\d{1,2})\s*分|(\d{1,2})(?:\D|$))").unwrap());
+    items.insert("meridian_en", Regex::new(r"(^|[^\d:])(\d{1,2})\s*([ap])\.?m\.?([^a-z]|$)").unwrap());
+    items.insert("h", Regex::new(r"(^|[^\d:])(\d{1,2})h([^a-z0-9]|$)").unwrap());
+    items.insert("half_past", Regex::new(&(r"half\s+past\s+(\d{1,2}|".to_string() + &hours + r")\b")).unwrap());
+    items.insert("clock", Regex::new(&(r"(\d{1,2}|".to_string() + &hours + r")\s*o'?\s*clock\b")).unwrap());
+    items
+});
+
+/// 一天中的时段
+/// 值为 [起始钟点, 结束钟点, 缺省钟点] 钟点落在 [起始, 结束) 之内 跨越午夜时起始大于结束
+/// 起始与结束相同表示确切的时刻 例如 noon midnight
+static TEXT_DAY_PERIOD:Lazy<HashMap<&str, (u32, u32, u32)>> = Lazy::new(|| {
+    let seed = r#"{"凌晨": [0, 6, 3], "清晨": [4, 9, 6], "早晨": [5, 10, 8], "早上": [5, 10, 8], "早": [5, 10, 8], "中午": [11, 14, 12], "正午": [12, 12, 12], "傍晚": [16, 20, 18], "晚上": [18, 1, 20], "晚间": [18, 1, 20], "晚間": [18, 1, 20], "今晚": [18, 1, 20], "晚": [18, 1, 20], "夜里": [18, 5, 22], "夜裡": [18, 5, 22], "夜间": [18, 5, 22], "夜間": [18, 5, 22], "深夜": [22, 4, 23], "半夜": [22, 4, 0], "午夜": [0, 0, 0], "子夜": [0, 0, 0], "朝": [4, 11, 8], "夕方": [16, 20, 18], "夜": [18, 5, 20], "새벽": [0, 6, 3], "아침": [5, 10, 8], "정오": [12, 12, 12], "저녁": [17, 23, 19], "밤": [18, 5, 21], "자정": [0, 0, 0], "this morning": [4, 12, 9], "in the morning": [4, 12, 9], "morning": [4, 12, 9], "in the afternoon": [12, 18, 15], "this afternoon": [12, 18, 15], "afternoon": [12, 18, 15], "in the evening": [16, 24, 19], "this evening": [16, 24, 19], "evening": [16, 24, 19], "tonight": [18, 5, 20], "at night": [18, 5, 21], "night": [18, 5, 21], "noon": [12, 12, 12], "midday": [12, 12, 12], "midnight": [0, 0, 0]}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 时段正则表达式
+/// after 时段在钟点之后 例如 8:00 tonight 12 noon
+/// before 时段在钟点之前 例如 凌晨2:00 晚上8:00
+/// time 文本中已有钟点
+static REGEX_DAY_PERIOD:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let mut names: Vec<&str> = TEXT_DAY_PERIOD.keys().copied().collect();
+    names.sort_by_key(|v| std::cmp::Reverse(v.chars().count()));
+    let names = names.join("|");
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    items.insert("after", Regex::new(&(r"(^|[^\d:\-/.])(\d{1,2})(?::(\d{2}))?\s*(?:at\s+)?(".to_string() + &names + ")")).unwrap());
+    items.insert("before", Regex::new(&("(".to_string() + &names + r")\s*(?:的|at\s+)?(\d{1,2}):(\d{2})")).unwrap());
+    items.insert("name", Regex::new(&names).unwrap());
+    items.insert("time", Regex::new(r"\d{1,2}:\d{2}").unwrap());
+    items
+});
+
+/// 韩文时间表达正则表达式
+/// time 시/분/초 时间单位 例如 3시 20분 15초 -> 3:20:15 排除 3시간 这类时长
+/// short_date 两位年份的 年.月.日 例如 23.10.05 -> 2023.10.05
+static REGEX_KOREAN:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    items.insert("time", Regex::new(r"(\d{1,2})\s*시(?:\s*(\d{1,2})\s*분)?(?:\s*(\d{1,2})\s*초)?([^간]|$)").unwrap());
+    items.insert("short_date", Regex::new(r"(^|[^\d.])(\d{2})\.\s?(\d{1,2})\.\s?(\d{1,2})\.?([^\d.]|$)").unwrap());
+    items
+});
+
+/// 日本年号
+/// full 年号+年[+月[+日]] 例如 令和5年10月5日、令和元年5月1日、㍻31年4月30日
+/// dot 年号+年.月.日 例如 令和5.10.5
+/// abbr 年号缩写+年.月.日 例如 R5.10.5、H31/4/30
 static REGEX_ERA_JAPAN:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
     let mut items: HashMap<&str, Regex> = HashMap::new();
-    items.insert("明治", Regex::new(r"明治\s*(\d+)\s*年").unwrap());
-    items.insert("大正", Regex::new(r"大正\s*(\d+)\s*年").unwrap());
-    items.insert("昭和", Regex::new(r"昭和\s*(\d+)\s*年").unwrap());
-    items.insert("平成", Regex::new(r"平成\s*(\d+)\s*年").unwrap());
-    items.insert("令和", Regex::new(r"令和\s*(\d+)\s*年").unwrap());
+    items.insert("full", Regex::new(r"(明治|大正|昭和|平成|令和|㍾|㍽|㍼|㍻|㋿)\s*(\d{1,2}|元)\s*年(?:\s*(\d{1,2})\s*月(?:\s*(\d{1,2})\s*日)?)?").unwrap());
+    items.insert("dot", Regex::new(r"(明治|大正|昭和|平成|令和|㍾|㍽|㍼|㍻|㋿)\s*(\d{1,2}|元)[./\-](\d{1,2})[./\-](\d{1,2})").unwrap());
+    items.insert("abbr", Regex::new(r"(?i)(^|[^a-z0-9])([mtshr])\.?\s*(\d{1,2}|元)[./\-](\d{1,2})[./\-](\d{1,2})").unwrap());
     return items
 });
 
-static TEXT_ERA_JAPAN_YEAR:Lazy<HashMap<&str, i32>> = Lazy::new(|| {
-    let seed = r#"{"明治": 1868, "大正": 1912, "昭和": 1926, "平成": 1989, "令和": 2019}"#;
-    return serde_json::from_str(seed).unwrap()
+/// 日本年号及其开始日期｜按时间先后排列 下一个年号的开始日期即为上一个年号的结束
+static TEXT_ERA_JAPAN:Lazy<Vec<(&str, &str)>> = Lazy::new(|| {
+    let era = vec![("明治", "1868-10-23"), ("大正", "1912-07-30"), ("昭和", "1926-12-25"),
+                   ("平成", "1989-01-08"), ("令和", "2019-05-01")];
+    era
 });
 
+/// 日本年号的合字和罗马字缩写
+static TEXT_ERA_JAPAN_ALIAS:Lazy<HashMap<&str, &str>> = Lazy::new(|| {
+    let seed = r#"{"㍾": "明治", "m": "明治", "㍽": "大正", "t": "大正", "㍼": "昭和", "s": "昭和", "㍻": "平成", "h": "平成", "㋿": "令和", "r": "令和"}"#;
+    serde_json::from_str(seed).unwrap()
+});
 
-pub fn search_era_japan(text: &str) -> String{
-    for (key, year) in TEXT_ERA_JAPAN_YEAR.iter(){
-        if !text.contains(key){continue}
-        let entry = text.replace("元年", "0年");
-        if let Some(pattern) =  REGEX_ERA_JAPAN.get(key){
-            let out = pattern.replace(&entry, |caps: &regex::Captures| {
-                if caps.len() < 2{return "".to_string()}
-                let mut number: i32 = caps[1].parse().unwrap();
-                if number > 0{
-                    number = number - 1
-                }
-                (number + year).to_string() + " "
-            });
-            return out.to_string()
+/// 伊斯兰历月份
+static TEXT_MONTH_HIJRI:Lazy<HashMap<&str, u32>> = Lazy::new(|| {
+    let seed = r#"{"محرم": 1, "المحرم": 1, "صفر": 2, "ربيع الأول": 3, "ربيع الاول": 3, "ربيع أول": 3, "ربيع الآخر": 4, "ربيع الاخر": 4, "ربيع الأخر": 4, "ربيع الثاني": 4, "ربيع ثاني": 4, "جمادى الأولى": 5, "جمادى الاولى": 5, "جمادى الأول": 5, "جمادى الآخرة": 6, "جمادى الاخرة": 6, "جمادى الآخر": 6, "جمادى الثانية": 6, "رجب": 7, "شعبان": 8, "رمضان": 9, "شوال": 10, "ذو القعدة": 11, "ذي القعدة": 11, "ذو الحجة": 12, "ذي الحجة": 12, "muharram": 1, "safar": 2, "rabi al-awwal": 3, "rabi' al-awwal": 3, "rabi al-thani": 4, "rabi' al-thani": 4, "rabi al-akhir": 4, "jumada al-awwal": 5, "jumada al-ula": 5, "jumada al-thani": 6, "jumada al-akhirah": 6, "rajab": 7, "sha'ban": 8, "shaban": 8, "ramadan": 9, "shawwal": 10, "dhu al-qadah": 11, "dhu al-qi'dah": 11, "dhul qadah": 11, "dhu al-hijjah": 12, "dhul hijjah": 12}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 伊斯兰历日期正则表达式
+/// name 日+月份名称+年 例如 20 ربيع الأول 1445 هـ
+/// numeric 带有纪元标志的年/月/日 例如 1445/03/20 هـ
+static REGEX_HIJRI:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let mut names: Vec<&str> = TEXT_MONTH_HIJRI.keys().copied().collect();
+    names.sort_by_key(|v| std::cmp::Reverse(v.chars().count()));
+    let names: Vec<String> = names.iter().map(|v| regex::escape(v)).collect();
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    let name = r"(\d{1,2})\s+(".to_string() + &names.join("|") + r")\s*[,،]?\s*(\d{3,4})\s*(?:هـ|ه|ah)?";
+    items.insert("name", Regex::new(&name).unwrap());
+    items.insert("numeric", Regex::new(r"(\d{3,4})[/\-.](\d{1,2})[/\-.](\d{1,2})\s*(?:هـ|ه|ah)").unwrap());
+    items.insert("gregorian_year", Regex::new(r"(?:^|\D)(19[7-9]\d|2\d{3})(?:\D|$)").unwrap());
+    items
+});
+
+/// 农历月份名称
+static TEXT_MONTH_LUNAR:Lazy<HashMap<&str, u32>> = Lazy::new(|| {
+    let seed = r#"{"正": 1, "一": 1, "二": 2, "三": 3, "四": 4, "五": 5, "六": 6, "七": 7, "八": 8, "九": 9, "十": 10, "十一": 11, "冬": 11, "十二": 12, "腊": 12, "臘": 12}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 天干
+const TEXT_STEM: &str = "甲乙丙丁戊己庚辛壬癸";
+/// 地支
+const TEXT_BRANCH: &str = "子丑寅卯辰巳午未申酉戌亥";
+
+/// 农历日期正则表达式
+/// 1 农历标志 2 公历年份 3 干支年份 4 农历标志 5 闰月标志 6 月份 7 日期
+/// 例如 农历八月十五 正月初一 腊月廿三 2023年闰二月初一 癸卯年腊月廿三
+static REGEX_LUNAR:Lazy<Regex> = Lazy::new(||{
+    let pattern = r"(?:(农历|農曆|阴历|陰曆|旧历|舊曆)\s*)?(?:(\d{4}|[〇零一二三四五六七八九]{4})\s*(?:年\s*)?)?(?:([甲乙丙丁戊己庚辛壬癸][子丑寅卯辰巳午未申酉戌亥])\s*年\s*)?(?:(农历|農曆|阴历|陰曆|旧历|舊曆)\s*)?(闰|閏)?(正|十一|十二|冬|腊|臘|[一二三四五六七八九十]|\d{1,2})\s*月\s*(初[一二三四五六七八九十]|十[一二三四五六七八九]|二十|廿[一二三四五六七八九]?|三十|卅|[一二三四五六七八九十]|\d{1,2})(?:日|号|號)?";
+    Regex::new(pattern).unwrap()
+});
+
+/// 节日名称及其日期规则｜fixed 月 日、weekday 月 星期 第N个、lunar 月 日、easter 之后可以跟随偏移天数
+/// 可以通过 Lexicon::insert_holiday 补充或覆盖
+static TEXT_HOLIDAY:Lazy<HashMap<String, Holiday>> = Lazy::new(|| {
+    let seed = r#"{"元旦": "fixed 1 1", "情人节": "fixed 2 14", "情人節": "fixed 2 14", "妇女节": "fixed 3 8", "婦女節": "fixed 3 8", "植树节": "fixed 3 12", "植樹節": "fixed 3 12", "愚人节": "fixed 4 1", "愚人節": "fixed 4 1", "劳动节": "fixed 5 1", "勞動節": "fixed 5 1", "五一劳动节": "fixed 5 1", "五一勞動節": "fixed 5 1", "五一": "fixed 5 1", "青年节": "fixed 5 4", "青年節": "fixed 5 4", "儿童节": "fixed 6 1", "兒童節": "fixed 6 1", "建党节": "fixed 7 1", "建黨節": "fixed 7 1", "建军节": "fixed 8 1", "建軍節": "fixed 8 1", "教师节": "fixed 9 10", "教師節": "fixed 9 10", "国庆节": "fixed 10 1", "國慶節": "fixed 10 1", "国庆": "fixed 10 1", "國慶": "fixed 10 1", "万圣节": "fixed 10 31", "萬聖節": "fixed 10 31", "平安夜": "fixed 12 24", "圣诞节": "fixed 12 25", "聖誕節": "fixed 12 25", "圣诞": "fixed 12 25", "聖誕": "fixed 12 25", "母亲节": "weekday 5 sun 2", "母親節": "weekday 5 sun 2", "父亲节": "weekday 6 sun 3", "父親節": "weekday 6 sun 3", "感恩节": "weekday 11 thu 4", "感恩節": "weekday 11 thu 4", "春节": "lunar 1 1", "春節": "lunar 1 1", "除夕": "lunar 1 1 -1", "大年三十": "lunar 1 1 -1", "元宵节": "lunar 1 15", "元宵節": "lunar 1 15", "元宵": "lunar 1 15", "端午节": "lunar 5 5", "端午節": "lunar 5 5", "端午": "lunar 5 5", "七夕": "lunar 7 7", "中元节": "lunar 7 15", "中元節": "lunar 7 15", "中秋节": "lunar 8 15", "中秋節": "lunar 8 15", "中秋": "lunar 8 15", "重阳节": "lunar 9 9", "重陽節": "lunar 9 9", "腊八节": "lunar 12 8", "臘八節": "lunar 12 8", "腊八": "lunar 12 8", "臘八": "lunar 12 8", "复活节": "easter", "復活節": "easter", "new year's day": "fixed 1 1", "new years day": "fixed 1 1", "new year's eve": "fixed 12 31", "new years eve": "fixed 12 31", "valentine's day": "fixed 2 14", "valentines day": "fixed 2 14", "st. patrick's day": "fixed 3 17", "st patrick's day": "fixed 3 17", "april fools' day": "fixed 4 1", "april fools day": "fixed 4 1", "independence day": "fixed 7 4", "halloween": "fixed 10 31", "veterans day": "fixed 11 11", "christmas eve": "fixed 12 24", "christmas": "fixed 12 25", "christmas day": "fixed 12 25", "boxing day": "fixed 12 26", "martin luther king day": "weekday 1 mon 3", "presidents' day": "weekday 2 mon 3", "presidents day": "weekday 2 mon 3", "mother's day": "weekday 5 sun 2", "mothers day": "weekday 5 sun 2", "memorial day": "weekday 5 mon -1", "father's day": "weekday 6 sun 3", "fathers day": "weekday 6 sun 3", "labor day": "weekday 9 mon 1", "columbus day": "weekday 10 mon 2", "thanksgiving": "weekday 11 thu 4", "thanksgiving day": "weekday 11 thu 4", "black friday": "weekday 11 thu 4 +1", "cyber monday": "weekday 11 thu 4 +4", "chinese new year": "lunar 1 1", "lunar new year": "lunar 1 1", "dragon boat festival": "lunar 5 5", "mid-autumn festival": "lunar 8 15", "easter": "easter", "easter sunday": "easter", "easter monday": "easter +1", "good friday": "easter -2"}"#;
+    let m: HashMap<String, String> = serde_json::from_str(seed).unwrap();
+    m.iter().filter_map(|(k, v)| convert_holiday_rule(v).map(|rule| (k.clone(), rule))).collect()
+});
+
+/// 节日前后的年份｜例如 2023年国庆节 Christmas 2023 不含日期中的年份 例如 2023-10-01
+static REGEX_HOLIDAY_YEAR:Lazy<Regex> = Lazy::new(||{
+    Regex::new(r"(?:^|[^\d\-/.:])((19[7-9]\d|2\d{3})(?:\s*年)?)(?:[^\d\-/.:月]|$)").unwrap()
+});
+
+/// 节日之外的日期和时间｜用于判断节日是否表示日期
+/// date 含有月和日的完整日期 例如 2023-10-05 2023年10月5日 10月5日 Oct 5 5 October
+/// time 时刻 例如 20:00 8点 8pm
+static REGEX_HOLIDAY_CONTEXT:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let month = r"(?:jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)";
+    let date = format!(r"\d{{4}}\s*[-/.年]\s*\d{{1,2}}\s*[-/.月]\s*\d{{1,2}}|\d{{1,2}}\s*[-/.]\s*\d{{1,2}}\s*[-/.]\s*\d{{4}}|\d{{1,2}}\s*月\s*\d{{1,2}}\s*[日号號]|\b{}[a-z]*\.?\s+\d{{1,2}}\b|\b\d{{1,2}}(?:st|nd|rd|th)?\s+{}", month, month);
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    items.insert("date", RegexBuilder::new(&date).case_insensitive(true).build().unwrap());
+    items.insert("time", RegexBuilder::new(r"\d{1,2}\s*(?::\d{2}|[点點时時]|[ap]\.?m\b)").case_insensitive(true).build().unwrap());
+    items
+});
+
+/// 中日汉字数字
+static TEXT_NUMERAL:Lazy<HashMap<&str, u32>> = Lazy::new(|| {
+    let seed = r#"{"〇": 0, "零": 0, "一": 1, "二": 2, "两": 2, "兩": 2, "三": 3, "四": 4, "五": 5, "六": 6, "七": 7, "八": 8, "九": 9, "十": 10, "廿": 20, "卅": 30, "卌": 40, "百": 100, "千": 1000}"#;
+    serde_json::from_str(seed).unwrap()
+});
+
+/// 汉字数字正则表达式
+/// colon_fullwidth 数字之间的全角冒号
+/// unit 后接日期时间单位的汉字数字
+/// initial 农历日期中的初一至初十
+/// half_* 带有"半"的时间表达
+static REGEX_NUMERAL:Lazy<HashMap<&str, Regex>> = Lazy::new(||{
+    let mut items: HashMap<&str, Regex> = HashMap::new();
+    items.insert("colon_fullwidth", Regex::new(r"([0-9])\s*：\s*([0-9])").unwrap());
+    items.insert("unit", Regex::new(r"([〇零一二两兩三四五六七八九十廿卅卌百千]+)([年月日号號点點时時分秒天周週个個小半刻钟鐘])").unwrap());
+    items.insert("initial", Regex::new(r"初([一二三四五六七八九十])").unwrap());
+    items.insert("half_time", Regex::new(r"(\d{1,2})\s*(点|點|时|時)半").unwrap());
+    items.insert("half_hours", Regex::new(r"(\d+)\s*[个個]?半\s*(小时|小時|钟头|鐘頭)").unwrap());
+    items.insert("half_hour", Regex::new(r"半\s*[个個]?\s*(小时|小時|钟头|鐘頭)").unwrap());
+    items.insert("half_minute", Regex::new(r"半\s*分\s*[钟鐘]?").unwrap());
+    items.insert("half_day", Regex::new(r"半\s*天\s*(前|后|後)").unwrap());
+    items.insert("half_month", Regex::new(r"半\s*[个個]\s*月\s*(前|后|後)").unwrap());
+    items.insert("half_year", Regex::new(r"半\s*年\s*(前|后|後)").unwrap());
+    items
+});
+
+/// 日本年号转公历
+/// 令和5年10月5日 -> 2023年10月5日、R5.10.5 -> 2023年10月5日、令和5年 -> 2023
+/// 基于年号的确切开始日期校验 例如平成止于 2019-04-30 因此 平成31年5月1日 为非法日期
+/// 宽松模式下不校验年号的起止
+pub fn search_era_japan(text: &str, lenient: bool) -> (String, DateValidity){
+    let mut validity = DateValidity::Unrecognized;
+    let mut data = text.to_string();
+    if let Some(pattern) = REGEX_ERA_JAPAN.get("full"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            let month = caps.get(3).and_then(|v| v.as_str().parse().ok());
+            let day = caps.get(4).and_then(|v| v.as_str().parse().ok());
+            let (item, status) = convert_era_japan(&caps[1], &caps[2], month, day, lenient);
+            validity = merge_era_validity(&validity, status);
+            item.unwrap_or(caps[0].to_string())
+        }).to_string();
+    }
+    if let Some(pattern) = REGEX_ERA_JAPAN.get("dot"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            let (item, status) = convert_era_japan(&caps[1], &caps[2], caps[3].parse().ok(), caps[4].parse().ok(), lenient);
+            validity = merge_era_validity(&validity, status);
+            item.unwrap_or(caps[0].to_string())
+        }).to_string();
+    }
+    // 罗马字缩写容易与版本号混淆 例如 r2.1.3｜只在有日文语境或者其余部分没有文字时处理 且文本中已有公历年份时不处理
+    if let Some(pattern) = REGEX_ERA_JAPAN.get("abbr"){
+        let source = data.clone();
+        data = pattern.replace_all(&source, |caps: &regex::Captures| {
+            let whole = caps.get(0).unwrap();
+            let rest = source[..whole.start()].to_string() + " " + &source[whole.end()..];
+            let alone = !rest.chars().any(|ch| ch.is_alphabetic());
+            if !(alone || has_japanese_script(&source)) || has_gregorian_year(&rest){
+                return caps[0].to_string()
+            }
+            let name = caps[2].to_lowercase();
+            let (item, status) = convert_era_japan(&name, &caps[3], caps[4].parse().ok(), caps[5].parse().ok(), lenient);
+            validity = merge_era_validity(&validity, status);
+            match item{
+                Some(v) => caps[1].to_string() + &v,
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    (data, validity)
+}
+
+/// 同一文本中出现多个年号时只要有一个合法即视为合法
+fn merge_era_validity(current: &DateValidity, status: DateValidity) -> DateValidity{
+    if *current == DateValidity::Valid || status == DateValidity::Unrecognized{
+        return current.clone()
+    }
+    status
+}
+
+/// 基于年号的开始日期换算公历年份并校验是否在年号起止范围内
+fn convert_era_japan(name: &str, year: &str, month: Option<u32>, day: Option<u32>, lenient: bool) -> (Option<String>, DateValidity){
+    let name = *TEXT_ERA_JAPAN_ALIAS.get(name).unwrap_or(&name);
+    let index = match TEXT_ERA_JAPAN.iter().position(|(key, _)| *key == name){
+        Some(i) => i,
+        None => return (None, DateValidity::Unrecognized)
+    };
+    let start = match NaiveDate::parse_from_str(TEXT_ERA_JAPAN[index].1, "%Y-%m-%d"){
+        Ok(v) => v,
+        Err(_) => return (None, DateValidity::Unrecognized)
+    };
+    let end = TEXT_ERA_JAPAN.get(index + 1).and_then(|(_, v)| NaiveDate::parse_from_str(v, "%Y-%m-%d").ok());
+    let number: i32 = if year == "元" {1} else {year.parse().unwrap_or(0)};
+    if number < 1{
+        return (None, DateValidity::Invalid)
+    }
+    let gregorian = start.year() + number - 1;
+    // 指定范围内的首尾日期 用于判断是否与年号的起止有交集
+    let (first, last) = match (month, day){
+        (Some(m), Some(d)) => {
+            match NaiveDate::from_ymd_opt(gregorian, m, d){
+                Some(v) => (v, v),
+                None => return (None, DateValidity::Invalid)
+            }
+        }
+        (Some(m), None) => {
+            match NaiveDate::from_ymd_opt(gregorian, m, 1){
+                Some(v) => (v, v + Duration::days(days_in_month(gregorian, m) as i64 - 1)),
+                None => return (None, DateValidity::Invalid)
+            }
         }
+        _ => {
+            match (NaiveDate::from_ymd_opt(gregorian, 1, 1), NaiveDate::from_ymd_opt(gregorian, 12, 31)){
+                (Some(a), Some(b)) => (a, b),
+                _ => return (None, DateValidity::Invalid)
+            }
+        }
+    };
+    if (last < start || end.is_some_and(|v| first >= v)) && !lenient{
+        return (None, DateValidity::Invalid)
     }
-    return text.to_string()
+    let item = match (month, day){
+        (Some(m), Some(d)) => format!("{}年{}月{}日", gregorian, m, d),
+        (Some(m), None) => format!("{}年{}月", gregorian, m),
+        _ => gregorian.to_string() + " "
+    };
+    (Some(item), DateValidity::Valid)
+}
+
+/// 查找伊斯兰历日期｜返回文本位置和年月日
+pub fn search_hijri_date(text: &str) -> Option<(usize, usize, i32, u32, u32)>{
+    if let Some(pattern) = REGEX_HIJRI.get("name"){
+        if let Some(caps) = pattern.captures(text){
+            let whole = caps.get(0)?;
+            let month = *TEXT_MONTH_HIJRI.get(&caps[2])?;
+            return Some((whole.start(), whole.end(), caps[3].parse().ok()?, month, caps[1].parse().ok()?))
+        }
+    }
+    if let Some(pattern) = REGEX_HIJRI.get("numeric"){
+        if let Some(caps) = pattern.captures(text){
+            let whole = caps.get(0)?;
+            return Some((whole.start(), whole.end(), caps[1].parse().ok()?, caps[2].parse().ok()?, caps[3].parse().ok()?))
+        }
+    }
+    None
+}
+
+/// 查找农历日期
+/// 需有农历标志（农历、阴历、旧历）、干支年份或农历特有的月份名称（正月、冬月、腊月、闰月）之一
+/// 不带上述特征的 八月十五 十月初五 十二月廿三日 按公历处理
+/// 返回 (起始位置, 结束位置, 年份, 月, 日, 是否闰月) 年份缺省时为 None
+pub fn search_lunar_date(text: &str) -> Option<(usize, usize, Option<i32>, u32, u32, bool)>{
+    for caps in REGEX_LUNAR.captures_iter(text){
+        let whole = caps.get(0)?;
+        let marker = caps.get(1).is_some() || caps.get(4).is_some();
+        let cyclic = caps.get(3).map(|v| v.as_str());
+        let leap = caps.get(5).is_some();
+        let month_text = &caps[6];
+        let day_text = &caps[7];
+        let lunar_month = ["正", "冬", "腊", "臘"].contains(&month_text);
+        if !(marker || leap || cyclic.is_some() || lunar_month){continue}
+        let month = match TEXT_MONTH_LUNAR.get(month_text){
+            Some(v) => *v,
+            None => match month_text.parse(){Ok(v) => v, Err(_) => continue}
+        };
+        let day = match convert_numeral(day_text.trim_start_matches("初")).and_then(|v| v.parse().ok()){
+            Some(v) => v,
+            None => continue
+        };
+        let mut year = caps.get(2).and_then(|v| convert_numeral(v.as_str())).and_then(|v| v.parse().ok());
+        if year.is_none(){
+            year = cyclic.and_then(convert_cyclic_year);
+        }
+        return Some((whole.start(), whole.end(), year, month, day, leap))
+    }
+    None
+}
+
+/// 干支纪年转公历年份 取距今最近的年份
+/// 1984 年为甲子年
+fn convert_cyclic_year(text: &str) -> Option<i32>{
+    let mut chars = text.chars();
+    let (first, second) = (chars.next()?, chars.next()?);
+    let stem = TEXT_STEM.chars().position(|v| v == first)? as i32;
+    let branch = TEXT_BRANCH.chars().position(|v| v == second)? as i32;
+    let current = Local::now().year();
+    for diff in 0..60{
+        for year in [current - diff, current + diff]{
+            let index = year - 1984;
+            if index.rem_euclid(10) == stem && index.rem_euclid(12) == branch{
+                return Some(year)
+            }
+        }
+    }
+    None
+}
+
+/// 文本中是否存在公历年份
+pub fn has_gregorian_year(text: &str) -> bool{
+    if let Some(pattern) = REGEX_HIJRI.get("gregorian_year"){
+        return pattern.is_match(text)
+    }
+    false
 }
 
 /// REGEX_SPECIAL_EXPRESS_TIME
 pub fn unitize_spacial_express_time(text: &str) -> String{
-    let mut data: String = text.to_string().to_lowercase();
+    let mut data: String = unitize_korean_express_time(&text.to_lowercase());
     if let Some(_) = REGEX_SPECIAL_EXPRESS_TIME_BASE.get("base"){
         for (key, pattern) in REGEX_SPECIAL_EXPRESS_TIME.iter(){
             if !data.contains(&key.to_lowercase()){continue}
@@ -168,9 +701,200 @@ pub fn unitize_spacial_express_time(text: &str) -> String{
             data = temp.to_string();
         }
     }
+    let data = unitize_hour_express_time(&data);
+    unitize_day_period(&data)
+}
+
+/// 时段的处理
+/// 根据时段调整钟点 例如 凌晨2:00 -> 2:00 晚上8:00 -> 20:00 中午1:00 -> 13:00
+/// 确切时刻直接转为钟点 例如 noon -> 12:00 midnight -> 0:00
+/// 文本中没有钟点时使用时段的缺省钟点 例如 2023年10月5日晚上 -> 2023年10月5日 20:00
+pub fn unitize_day_period(text: &str) -> String{
+    let mut data = text.to_string();
+    if let Some(pattern) = REGEX_DAY_PERIOD.get("after"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            let minute = caps.get(3).map(|v| v.as_str());
+            match adjust_day_period(&caps[4], &caps[2], minute){
+                Some(v) => caps[1].to_string() + &v,
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    if let Some(pattern) = REGEX_DAY_PERIOD.get("before"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            adjust_day_period(&caps[1], &caps[2], Some(&caps[3])).unwrap_or(caps[0].to_string())
+        }).to_string();
+    }
+    if let Some(pattern) = REGEX_DAY_PERIOD.get("time"){
+        if pattern.is_match(&data){
+            return data
+        }
+    }
+    let name = match REGEX_DAY_PERIOD.get("name"){
+        Some(v) => v,
+        None => return data
+    };
+    // 单字时段容易误判 例如 早 晚 朝 夜 不作为缺省钟点
+    for hit in name.find_iter(&data.clone()){
+        let (start, end, default) = match TEXT_DAY_PERIOD.get(hit.as_str()){
+            Some(v) => *v,
+            None => continue
+        };
+        if hit.as_str().chars().count() < 2 && start != end{continue}
+        // 前面紧邻数字时无法区分钟点和日期 例如 10 at night、Oct 5 in the afternoon
+        let connective = hit.as_str().starts_with("at ") || hit.as_str().starts_with("in ");
+        if connective && data[..hit.start()].trim_end().ends_with(|ch: char| ch.is_ascii_digit()){break}
+        data = data[..hit.start()].to_string() + " " + &format!("{}:00", default) + " " + &data[hit.end()..];
+        break
+    }
+    data
+}
+
+/// 根据时段调整钟点
+/// 未写分钟的钟点仅用于确切时刻 且须与时刻一致 例如 12 noon｜Oct 5 noon 中的 5 是日期
+fn adjust_day_period(name: &str, hour: &str, minute: Option<&str>) -> Option<String>{
+    let (start, end, default) = *TEXT_DAY_PERIOD.get(name)?;
+    let hour: u32 = hour.parse().ok()?;
+    if start == end{
+        if minute.is_none() && hour % 12 != default % 12{
+            return None
+        }
+        return Some(format!("{}:00", default))
+    }
+    let minute = minute?;
+    if hour > 12{
+        return Some(format!("{}:{}", hour, minute))
+    }
+    let within = |v: u32| if start < end {v >= start && v < end} else {v >= start || v < end};
+    for candidate in [hour % 12, hour % 12 + 12]{
+        if within(candidate){
+            return Some(format!("{}:{}", candidate, minute))
+        }
+    }
+    Some(format!("{}:{}", hour, minute))
+}
+
+/// 仅有钟点的时间表达的处理｜统一转为 hh:mm 并使午线独立成词
+/// 下午3点 -> 下午 3:00
+/// 3pm -> 3:00 pm
+/// half past three -> 3:30
+pub fn unitize_hour_express_time(text: &str) -> String{
+    let mut data = text.to_string();
+    if let Some(pattern) = REGEX_HOUR.get("meridian"){
+        data = pattern.replace_all(&data, " $1 ").to_string();
+    }
+    data = unitize_hour_unit(&data);
+    if let Some(pattern) = REGEX_HOUR.get("meridian_en"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            match format_hour(&caps[2], "00"){
+                Some(v) => format!("{}{} {}m{}", &caps[1], v, &caps[3], &caps[4]),
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    if let Some(pattern) = REGEX_HOUR.get("h"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            match format_hour(&caps[2], "00"){
+                Some(v) => caps[1].to_string() + &v + &caps[3],
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    for (name, minute) in [("half_past", "30"), ("clock", "00")]{
+        if let Some(pattern) = REGEX_HOUR.get(name){
+            data = pattern.replace_all(&data, |caps: &regex::Captures| {
+                let hour = match TEXT_HOUR_EN.get(&caps[1]){
+                    Some(v) => v.to_string(),
+                    None => caps[1].to_string()
+                };
+                format_hour(&hour, minute).unwrap_or(caps[0].to_string())
+            }).to_string();
+        }
+    }
     return data
 }
 
+/// 钟点和分钟拼接为 hh:mm｜超出范围时返回 None 例如 48h 这类时长
+/// 3点 3点20 3点 20分 -> 3:00 3:20 3:20
+/// 分钟只在其后有 分 或者紧跟 点/时 时计入 例如 下午3点 2023-10-05 中的 20 不是分钟
+/// 点赞 時間 钟头 等词语不是时间 例如 3点赞 共3時間 3个钟头
+fn unitize_hour_unit(text: &str) -> String{
+    let (pattern, minutes) = match (REGEX_HOUR.get("unit"), REGEX_HOUR.get("minute")){
+        (Some(a), Some(b)) => (a, b),
+        _ => return text.to_string()
+    };
+    let mut data = String::new();
+    let mut last = 0;
+    for caps in pattern.captures_iter(text){
+        let hit = match caps.get(0){
+            Some(v) if v.start() >= last => v,
+            _ => continue
+        };
+        let rest = &text[hit.end()..];
+        let unit = caps.get(3).unwrap_or(caps.get(2).unwrap()).as_str();
+        if TEXT_HOUR_STOP.iter().any(|(prefix, suffix)| prefix.contains(unit) && suffix.chars().any(|ch| rest.starts_with(ch))){
+            continue
+        }
+        let (minute, length) = match minutes.captures(rest){
+            Some(v) => match v.get(1).or(v.get(2)){
+                Some(m) => (m.as_str(), if v.get(1).is_some() {v.get(0).map(|x| x.end()).unwrap_or(0)} else {m.end()}),
+                None => ("00", 0)
+            },
+            None => ("00", 0)
+        };
+        let item = match format_hour(&caps[1], minute){
+            Some(v) => v,
+            None => continue
+        };
+        data.push_str(&text[last..hit.start()]);
+        data.push_str(&item);
+        data.push(' ');
+        last = hit.end() + length;
+    }
+    data.push_str(&text[last..]);
+    data
+}
+
+fn format_hour(hour: &str, minute: &str) -> Option<String>{
+    let hour: u32 = hour.parse().ok()?;
+    let minute: u32 = minute.parse().ok()?;
+    if hour > 24 || minute > 59{
+        return None
+    }
+    Some(format!("{}:{:0>2}", hour, minute))
+}
+
+/// 韩文时间表达的处理
+/// 오후 3시 20분 -> 오후 3:20 使 오전/오후 紧邻 hh:mm 以便识别上下午
+/// 韩文页面中两位年份的 23.10.05 按 年.月.日 处理
+pub fn unitize_korean_express_time(text: &str) -> String{
+    if !has_hangul(text){
+        return text.to_string()
+    }
+    let mut data = text.to_string();
+    if let Some(pattern) = REGEX_KOREAN.get("time"){
+        data = pattern.replace_all(&data, |caps: &regex::Captures| {
+            let minute = caps.get(2).map(|v| v.as_str()).unwrap_or("00");
+            let second = caps.get(3).map(|v| ":".to_string() + v.as_str()).unwrap_or_default();
+            format!("{}:{:0>2}{} {}", &caps[1], minute, second, &caps[4])
+        }).to_string();
+    }
+    if let Some(pattern) = REGEX_KOREAN.get("short_date"){
+        data = pattern.replace_all(&data, "${1}20${2}.${3}.${4} ${5}").to_string();
+    }
+    data
+}
+
+/// 文本中是否存在日文假名或汉字
+pub fn has_japanese_script(text: &str) -> bool{
+    text.chars().any(|ch| ('\u{3040}'..='\u{30FF}').contains(&ch) || ('\u{4E00}'..='\u{9FFF}').contains(&ch))
+}
+
+/// 文本中是否存在韩文
+pub fn has_hangul(text: &str) -> bool{
+    text.chars().any(|ch| ('\u{AC00}'..='\u{D7A3}').contains(&ch))
+}
+
 pub static OFFSET_LOCAL_UTC: Lazy<i64> = Lazy::new(|| {
     Local::now().naive_local().timestamp() - Utc::now().naive_utc().timestamp()
 });
@@ -179,8 +903,18 @@ pub fn get_offset_local_utc() -> i64{
     return *OFFSET_LOCAL_UTC
 }
 
-pub fn unitize_timezone_with_text(key: &str) -> Option<&i32>{
-    if let Some(v) = TIMEZONE_OFFSET.get(key){
+/// 内置词库
+pub fn default_lexicon() -> Arc<Lexicon>{
+    LEXICON.clone()
+}
+
+/// 参数指定的词库｜未指定时为内置词库
+pub fn get_lexicon(param: &Param) -> Arc<Lexicon>{
+    param.lexicon.clone().unwrap_or_else(default_lexicon)
+}
+
+pub fn unitize_timezone_with_text<'a>(key: &str, lexicon: &'a Lexicon) -> Option<&'a i32>{
+    if let Some(v) = lexicon.timezone.get(key){
         return Some(v)
     }
     return None
@@ -204,8 +938,8 @@ pub fn unitize_month_text(key: &str) -> Option<String>{
 
 
 /// 规整月份以达到统一表述的目的｜返回月份的英文名称
-pub fn unitize_month_name(key: &str) -> Option<&str>{
-    if let Some(v) = TEXT_MONTH.get(key){
+pub fn unitize_month_name<'a>(key: &str, lexicon: &'a Lexicon) -> Option<&'a str>{
+    if let Some(v) = lexicon.month.get(key){
         return Some(v.as_str());
     }
     return None
@@ -246,6 +980,67 @@ pub fn search_with_utc_pattern(key: &str) -> Option<&Regex>{
     return REGEX_UTC_TIMEZONE.get(key)
 }
 
+pub fn search_with_log_pattern(key: &str) -> Option<&Regex>{
+    REGEX_LOG.get(key)
+}
+
+pub fn search_with_rfc_pattern(key: &str) -> Option<&Regex>{
+    REGEX_RFC.get(key)
+}
+
+/// 邮件日期时区名称转偏移量(秒)｜未知的军用时区字母视为 0
+pub fn unitize_zone_rfc(key: &str) -> Option<i32>{
+    let key = key.to_lowercase();
+    if let Some(v) = TEXT_ZONE_RFC.get(key.as_str()){
+        return Some(*v)
+    }
+    if key.len() == 1 && key != "j"{
+        return Some(0)
+    }
+    None
+}
+
+/// 月份缩写转数字 例如 oct -> 10
+pub fn unitize_month_abbr(key: &str) -> Option<u32>{
+    let key = key.to_lowercase();
+    if key.len() < 3{
+        return None
+    }
+    for (name, value) in MONTH_NUMERIC.iter(){
+        if name.starts_with(key.as_str()){
+            return Some(*value)
+        }
+    }
+    None
+}
+
+pub fn search_with_iso8601_pattern(key: &str) -> Option<&Regex>{
+    REGEX_ISO8601.get(key)
+}
+
+pub fn search_with_numeral_pattern(key: &str) -> Option<&Regex>{
+    REGEX_NUMERAL.get(key)
+}
+
+/// 任意书写系统的十进制数字转 ASCII 数字
+pub fn unitize_digit_char(ch: char) -> Option<char>{
+    let code = ch as u32;
+    for zero in DIGIT_ZERO.iter(){
+        if code >= *zero && code < zero + 10{
+            return char::from_digit(code - zero, 10)
+        }
+    }
+    None
+}
+
+/// 汉字数字转数值
+pub fn unitize_numeral_char(key: &str) -> Option<&u32>{
+    if let Some(v) = TEXT_NUMERAL.get(key){
+        return Some(v)
+    }
+    None
+}
+
 pub fn search_meridian(text: &str) -> Option<String>{
     if let Some(pattern) = PATTERNS.get("meridian"){
         if let Some(hit) = pattern.find(text){
@@ -269,28 +1064,184 @@ pub fn search_dubious_date(text: &str) -> Option<String>{
     return None
 }
 
-pub fn unitize_date_text(key: &str) -> Option<String>{
+pub fn search_with_duration_pattern(key: &str) -> Option<&Regex>{
+    REGEX_DURATION.get(key)
+}
+
+pub fn search_with_recurrence_pattern(key: &str) -> Option<&Regex>{
+    REGEX_RECURRENCE.get(key)
+}
+
+/// 节日规则文本转为规则｜例如 weekday 11 thu 4 +1 为感恩节的后一天
+pub fn convert_holiday_rule(text: &str) -> Option<Holiday>{
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let number = |i: usize| words.get(i).and_then(|v| v.parse::<u32>().ok());
+    let (rule, count) = match *words.first()?{
+        "fixed" => (HolidayRule::Fixed(number(1)?, number(2)?), 3),
+        "weekday" => (HolidayRule::Weekday(number(1)?, words.get(2)?.parse::<Weekday>().ok()?, words.get(3)?.parse().ok()?), 4),
+        "lunar" => (HolidayRule::Lunar(number(1)?, number(2)?), 3),
+        "easter" => (HolidayRule::Easter, 1),
+        _ => return None
+    };
+    let offset = match words.get(count){
+        Some(v) => v.parse::<i64>().ok()?,
+        None => 0
+    };
+    Some(Holiday{rule, offset})
+}
+
+/// 查找节日｜取最长的节日名称 英文名称需要完整的单词 例如 easter 不匹配 eastern
+/// 返回 (起始位置, 结束位置, 节日)
+pub fn search_holiday(text: &str, lexicon: &Lexicon) -> Option<(usize, usize, Holiday)>{
+    let lower = text.to_ascii_lowercase();
+    let mut data: Option<(usize, usize, &Holiday)> = None;
+    for (name, holiday) in lexicon.holiday.iter(){
+        for (start, _) in lower.match_indices(name.as_str()){
+            let end = start + name.len();
+            let word = |v: Option<char>| v.map(|ch| ch.is_ascii_alphanumeric()).unwrap_or(false);
+            if name.is_ascii() && (word(lower[..start].chars().last()) || word(lower[end..].chars().next())){
+                continue
+            }
+            let better = match data{
+                Some((s, e, _)) => end - start > e - s || (end - start == e - s && start < s),
+                None => true
+            };
+            if better{
+                data = Some((start, end, holiday));
+            }
+        }
+    }
+    data.map(|(start, end, holiday)| (start, end, holiday.clone()))
+}
+
+/// 节日前后的年份｜返回 (起始位置, 结束位置, 年份)
+pub fn search_holiday_year(text: &str) -> Option<(usize, usize, i32)>{
+    let caps = REGEX_HOLIDAY_YEAR.captures(text)?;
+    let whole = caps.get(1)?;
+    Some((whole.start(), whole.end(), caps[2].parse().ok()?))
+}
+
+/// 节日之外是否有完整的日期
+pub fn has_holiday_date(text: &str) -> bool{
+    REGEX_HOLIDAY_CONTEXT.get("date").map(|pattern| pattern.is_match(text)).unwrap_or(false)
+}
+
+/// 节日之外是否有时刻 例如 国庆节晚上8点
+pub fn has_holiday_time(text: &str) -> bool{
+    REGEX_HOLIDAY_CONTEXT.get("time").map(|pattern| pattern.is_match(text)).unwrap_or(false)
+}
+
+pub fn search_with_period_pattern(key: &str) -> Option<&Regex>{
+    REGEX_PERIOD.get(key)
+}
+
+/// 参数指定的词库中的时长单位｜未指定词库时为内置的时长单位
+pub fn get_duration_unit(param: &Param) -> Cow<'static, HashMap<String, String>>{
+    if let Some(lexicon) = &param.lexicon{
+        return Cow::Owned(create_duration_unit(lexicon.relative.iter().map(|(k, v)| (k.as_str(), v.as_str()))))
+    }
+    Cow::Borrowed(&TEXT_DURATION_UNIT)
+}
+
+/// 时长单位转为英文单位｜year month week day hour minute second
+pub fn unitize_duration_unit<'a>(key: &str, units: &'a HashMap<String, String>) -> Option<&'a str>{
+    let key = key.to_lowercase();
+    if let Some(v) = units.get(key.trim_end_matches('.')){
+        return Some(v.as_str())
+    }
+    None
+}
+
+/// 是否为月份名称｜英文缩写或者各语种的月份名称
+pub fn is_month_name(text: &str, lexicon: &Lexicon) -> bool{
+    unitize_month_abbr(text).is_some() || lexicon.month.contains_key(text.to_lowercase().as_str())
+}
+
+/// 星期名称转为星期｜例如 mon 星期一 周一 月曜日
+pub fn unitize_weekday(text: &str, lexicon: &Lexicon) -> Option<Weekday>{
+    let key = text.to_lowercase().replace('周', "週");
+    if let Some(v) = lexicon.weekday.get(key.as_str()){
+        return v.parse::<Weekday>().ok()
+    }
+    key.parse::<Weekday>().ok()
+}
+
+pub fn unitize_date_text(key: &str, lexicon: &Lexicon) -> Option<String>{
     if let Some(v) = TEXT_TIME_SYMBOL.get(key){
         return Some(v.to_string())
     }
-    if let Some(v) = TEXT_MERIDIAN.get(key){
+    if let Some(v) = lexicon.meridian.get(key){
         return Some(v.to_string())
     }
     if let Some(v) = TEXT_TIMEZONE.get(key){
         return Some(v.to_string())
     }
-    if let Some(v) = TEXT_RELATIVE.get(key){
+    if let Some(v) = lexicon.relative.get(key){
         return Some(v.to_string())
     }
-    if let Some(v) = TEXT_MONTH.get(key){
+    if let Some(v) = lexicon.month.get(key){
         return Some(v.to_string())
     }
-    if let Some(v) = TEXT_WEEKDAY.get(key){
+    if let Some(v) = lexicon.weekday.get(key){
         return Some(v.to_string())
     }
     return None
 }
 
+/// 基于纪元标志判断纪元
+pub fn search_era_marker(text: &str) -> Option<EraBasedCalendar>{
+    for (marker, era) in TEXT_ERA_MARKER.iter(){
+        if !text.contains(marker){continue}
+        return match *era{
+            "buddhist" => Some(EraBasedCalendar::Buddhist),
+            "gregorian" => Some(EraBasedCalendar::Gregorian),
+            "minguo" => Some(EraBasedCalendar::Minguo),
+            "jalali" => Some(EraBasedCalendar::Jalali),
+            _ => None
+        }
+    }
+    None
+}
+
+/// 是否为使用民国纪年的台湾地区站点
+/// 可用于决定是否开启 Param 的民国纪年模式
+pub fn is_minguo_domain(host: &str) -> bool{
+    let host = host.trim().to_lowercase();
+    let host = host.split("://").last().unwrap_or("");
+    let host = host.split(['/', ':', '?', '#']).next().unwrap_or("");
+    for domain in DOMAIN_MINGUO.iter(){
+        if domain.starts_with('.') && host.ends_with(domain){
+            return true
+        }
+        if host == *domain || host.ends_with(&(".".to_string() + domain)){
+            return true
+        }
+    }
+    false
+}
+
+/// 是否包含波斯数字
+pub fn has_persian_digit(text: &str) -> bool{
+    text.chars().any(|ch| ('\u{06F0}'..='\u{06F9}').contains(&ch))
+}
+
+/// 是否包含泰文
+pub fn has_thai_script(text: &str) -> bool{
+    text.chars().any(|ch| ('\u{0E00}'..='\u{0E7F}').contains(&ch))
+}
+
+/// 伊朗历月份名称转为相同序号的英文月份｜仅在伊朗历的上下文中使用 例如 مهر 也是常用词
+pub fn unitize_month_jalali(key: &str) -> Option<&str>{
+    TEXT_MONTH_JALALI.get(key).map(|v| v.as_str())
+}
+
+/// 是否有伊朗历范围内的四位数年份 例如 1402
+pub fn has_year_jalali(text: &str) -> bool{
+    text.split(|ch: char| !ch.is_ascii_digit()).any(|v| {
+        v.len() == 4 && v.parse::<u32>().map(|year| (MIN_YEAR_JALALI..MAX_YEAR_JALALI).contains(&year)).unwrap_or(false)
+    })
+}
+
 pub fn is_thai_month(key: &str) -> bool {
     if let Some(_) = TEXT_MONTH_THAI.get(key){
         return true
@@ -298,12 +1249,12 @@ pub fn is_thai_month(key: &str) -> bool {
     return false
 }
 
-pub fn unitize_relative_text(key: &str) -> ResultRelativeText{
+pub fn unitize_relative_text(key: &str, lexicon: &Lexicon) -> ResultRelativeText{
     let mut data = ResultRelativeText{..Default::default()};
-    let relatives = &ARRAY_RELATIVE;
+    let relatives = &lexicon.relative_order;
     for item in relatives.iter(){
-        if !key.contains(item){continue}
-        if let Some(v) = TEXT_RELATIVE.get(item){
+        if !key.contains(item.as_str()){continue}
+        if let Some(v) = lexicon.relative.get(item){
             data.hit = true;
             data.text = v.to_string();
             let clean_key = key.to_string().trim().to_string();
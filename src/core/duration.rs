@@ -0,0 +1,121 @@
+use chrono::{Duration, Months, NaiveDateTime};
+use regex::Captures;
+use crate::bind::{Param, ResultDuration};
+use crate::core::corpus::{get_duration_unit, search_with_duration_pattern, unitize_duration_unit};
+use crate::utils::unitize_numeral;
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 3600.0;
+const SECONDS_PER_DAY: f64 = 86400.0;
+const DAYS_PER_WEEK: f64 = 7.0;
+
+
+impl ResultDuration{
+    /// 将时长加到指定时间上｜年和月按日历计算 月末溢出时取当月最后一天 例如 01-31 加 1 个月得到 02-28
+    pub fn add_to(&self, time: NaiveDateTime) -> Option<NaiveDateTime>{
+        let months = self.years * 12 + self.months;
+        let time = if months >= 0 {time.checked_add_months(Months::new(months as u32))?} else {time.checked_sub_months(Months::new(months.unsigned_abs() as u32))?};
+        let nanos = (self.seconds * 1_000_000_000.0).round() as i64;
+        time.checked_add_signed(Duration::days(self.days))?.checked_add_signed(Duration::nanoseconds(nanos))
+    }
+}
+
+
+/// 时长解析｜周换算为 7 天 小数的天、时、分换算为秒 年和月不允许小数
+/// 1. ISO 8601 例如 PT2H30M P1Y2M P3W P0001-02-03T04:05:06
+/// 2. 时:分[:秒] 例如 1:30:00
+/// 3. 数字与单位 例如 3天4小时 2 hrs 15 mins｜单位来自相对时间的词库 支持多种语言 指定 Param.lexicon 时来自该词库
+pub fn parse_duration(text: &str, options: Option<Param>) -> ResultDuration{
+    let text = text.trim();
+    let mut data = ResultDuration{..Default::default()};
+    for (name, method) in [("iso", "iso8601"), ("iso_alternative", "iso8601"), ("clock", "clock")]{
+        if let Some(caps) = search_with_duration_pattern(name).and_then(|pattern| pattern.captures(text)){
+            if let Some(item) = create_duration(&caps){
+                data = item;
+                data.method = method.to_string();
+            }
+            return data
+        }
+    }
+    let text = unitize_numeral(text);
+    let pattern = match search_with_duration_pattern("number"){
+        Some(v) => v,
+        None => return data
+    };
+    let units = get_duration_unit(&options.unwrap_or_default());
+    let mut hit = false;
+    for caps in pattern.captures_iter(&text){
+        let number = match str_convert_duration(&caps["number"]){
+            Some(v) => v,
+            None => return ResultDuration{..Default::default()}
+        };
+        let unit = match unitize_duration_unit(&caps["unit"], &units){
+            Some(v) => v,
+            None => return ResultDuration{..Default::default()}
+        };
+        if !accumulate_duration(&mut data, unit, number){
+            return ResultDuration{..Default::default()}
+        }
+        hit = true;
+    }
+    if hit{
+        data.status = true;
+        data.method = String::from("text");
+    }
+    data
+}
+
+/// ISO 8601 和 时:分:秒 的各个部分
+fn create_duration(caps: &Captures) -> Option<ResultDuration>{
+    let mut data = ResultDuration{..Default::default()};
+    let mut hit = false;
+    for name in ["year", "month", "week", "day", "hour", "minute", "second"]{
+        if let Some(v) = caps.name(name){
+            if !accumulate_duration(&mut data, name, str_convert_duration(v.as_str())?){
+                return None
+            }
+            hit = true;
+        }
+    }
+    if !hit{
+        return None
+    }
+    if caps.name("sign").map(|v| v.as_str() == "-").unwrap_or(false){
+        data.years = -data.years;
+        data.months = -data.months;
+        data.days = -data.days;
+        data.seconds = 0.0 - data.seconds;
+    }
+    data.status = true;
+    Some(data)
+}
+
+fn accumulate_duration(data: &mut ResultDuration, unit: &str, number: f64) -> bool{
+    match unit{
+        "year" | "month" => {
+            if number.fract() != 0.0{
+                return false
+            }
+            if unit == "year"{
+                data.years += number as i64;
+            }else{
+                data.months += number as i64;
+            }
+        }
+        "week" | "day" => {
+            let days = if unit == "week" {number * DAYS_PER_WEEK} else {number};
+            data.days += days.trunc() as i64;
+            data.seconds += days.fract() * SECONDS_PER_DAY;
+        }
+        "hour" => data.seconds += number * SECONDS_PER_HOUR,
+        "minute" => data.seconds += number * SECONDS_PER_MINUTE,
+        "second" => data.seconds += number,
+        _ => return false
+    }
+    true
+}
+
+/// 小数点可以是逗号｜ISO 8601 允许 PT0,5S
+fn str_convert_duration(text: &str) -> Option<f64>{
+    text.replace(',', ".").parse::<f64>().ok()
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use chrono::Weekday;
+use crate::bind::{DateTimeLabel, Layout, Lexicon, Param, ParseAbsolute, Result, Token};
+use crate::core::corpus::{default_lexicon, get_lexicon, is_month_name};
+use crate::core::parse_auto::clone_options;
+use crate::core::standard::parse_format;
+use crate::{parse_absolute, parse_series};
+
+const ORDER_DATE: [&str; 3] = ["ymd", "dmy", "mdy"];  // 日期字段的顺序｜得分相同时按此优先级选择
+const LEN_SERIES_DATE: usize = 8;  // 20231005
+const LEN_SERIES_TIME: usize = 6;  // 142030
+
+
+impl Layout{
+    /// 按推断出的格式解析｜不符合格式的文本视为离群值 不再使用通用流程逐行猜测
+    pub fn parse(&self, text: &str, options: Option<Param>) -> Result{
+        let mut ins = ParseAbsolute::new(options);
+        ins.param.formats = vec![self.format.clone()];
+        if let Some(r) = ins.parse_hint(text){
+            return r
+        }
+        Result{method: String::from("absolute"), timezone: ins.param.timezone.clone(), ..Default::default()}
+    }
+}
+
+
+/// 从同一来源的样本中推断时间格式
+/// 1. 使用 absolute 和 series 解析样本 均无法识别的样本不参与推断
+/// 2. 按字段结构(数字位数、分隔符和文字)分组 取样本最多的一组
+/// 3. 依次尝试 ymd dmy mdy 三种日期顺序 编译为 strftime 格式
+///    能解析的样本最多者胜出｜例如出现 25/10/2023 时只有 dmy 能解析全部样本
+///    数量相同时取与解析器结果一致最多的顺序 仍相同时按 ymd dmy mdy 的优先级
+///    options 用于解析样本 其中的词库也用于识别月份名称
+pub fn infer_layout(samples: &[&str], options: Option<Param>) -> Option<Layout>{
+    let lexicon = options.as_ref().map(get_lexicon).unwrap_or_else(default_lexicon);
+    let mut groups: HashMap<String, Vec<(&str, Result)>> = HashMap::new();
+    let mut signatures: Vec<String> = vec![];
+    for sample in samples{
+        let sample = sample.trim();
+        let mut r = parse_absolute(sample, clone_options(&options));
+        if !r.status{
+            r = parse_series(sample, clone_options(&options));
+        }
+        if !r.status{
+            continue
+        }
+        let signature = sign_layout(&tokenize_layout(sample), &lexicon);
+        if !groups.contains_key(&signature){
+            signatures.push(signature.clone());
+        }
+        groups.entry(signature).or_default().push((sample, r));
+    }
+    // 样本数量相同时取先出现的一组
+    let mut best: Option<&Vec<(&str, Result)>> = None;
+    for signature in signatures.iter(){
+        let group = &groups[signature];
+        if best.map(|v| group.len() > v.len()).unwrap_or(true){
+            best = Some(group);
+        }
+    }
+    let group = best?;
+    let tokens = tokenize_layout(group[0].0);
+    let mut data: Option<(Layout, usize)> = None;
+    for order in ORDER_DATE{
+        let (format, timezone) = match compile_layout(&tokens, order, &lexicon){
+            Some(v) => v,
+            None => continue
+        };
+        let formats = vec![format.clone()];
+        let mut count = 0;
+        let mut votes = 0;
+        for (sample, r) in group.iter(){
+            if let Some((_, item)) = parse_format(sample, &formats, &lexicon){
+                count += 1;
+                if item.time.date() == r.time.date(){
+                    votes += 1;
+                }
+            }
+        }
+        if count == 0{
+            continue
+        }
+        let better = match &data{
+            Some((layout, v)) => (count, votes) > (layout.samples, *v),
+            None => true
+        };
+        if better{
+            data = Some((Layout{format, order: order.to_string(), timezone, samples: count}, votes));
+        }
+    }
+    data.map(|(layout, _)| layout)
+}
+
+/// 将文本拆分为数字、文字和分隔符｜连续的空白视为一个空格
+fn tokenize_layout(text: &str) -> Vec<Token>{
+    let mut tokens: Vec<Token> = vec![];
+    for ch in text.chars(){
+        let label = if ch.is_ascii_digit() {DateTimeLabel::Numeric} else if ch.is_alphabetic() {DateTimeLabel::Characters} else {DateTimeLabel::Invalid};
+        let ch = if ch.is_whitespace() {' '} else {ch};
+        if let Some(last) = tokens.last_mut(){
+            let joinable = label != DateTimeLabel::Invalid || (ch == ' ' && last.text == " ");
+            if last.label == label && joinable{
+                if ch != ' '{
+                    last.text.push(ch);
+                }
+                continue
+            }
+        }
+        tokens.push(Token{text: ch.to_string(), label});
+    }
+    tokens
+}
+
+/// 字段结构｜一至两位数字视为同一类 月份、星期名称和上下午视为同一类
+fn sign_layout(tokens: &[Token], lexicon: &Lexicon) -> String{
+    let words: Vec<String> = tokens.iter().map(|token| {
+        match token.label{
+            DateTimeLabel::Numeric if token.text.len() <= 2 => "n".to_string(),
+            DateTimeLabel::Numeric => format!("n{}", token.text.len()),
+            DateTimeLabel::Characters if is_month_name(&token.text, lexicon) => "month".to_string(),
+            DateTimeLabel::Characters if is_weekday_word(&token.text) => "weekday".to_string(),
+            DateTimeLabel::Characters if is_meridian_word(&token.text) => "meridian".to_string(),
+            _ => token.text.clone()
+        }
+    }).collect();
+    words.join("\u{1}")
+}
+
+/// 仅限英文星期名称｜%A 只能解析英文
+fn is_weekday_word(text: &str) -> bool{
+    text.parse::<Weekday>().is_ok()
+}
+
+fn is_meridian_word(text: &str) -> bool{
+    text.eq_ignore_ascii_case("am") || text.eq_ignore_ascii_case("pm")
+}
+
+/// 按日期顺序将字段编译为 strftime 格式
+/// 返回 (格式, 是否带有时区偏移量)
+fn compile_layout(tokens: &[Token], order: &str, lexicon: &Lexicon) -> Option<(String, bool)>{
+    let mut specs: Vec<Option<String>> = vec![None; tokens.len()];
+    let numeric = |i: usize| tokens.get(i).filter(|v| v.label == DateTimeLabel::Numeric);
+    let literal = |i: usize, text: &str| tokens.get(i).map(|v| v.text == text).unwrap_or(false);
+    let meridian = tokens.iter().any(|v| v.label == DateTimeLabel::Characters && is_meridian_word(&v.text));
+    let hour = if meridian {"%I"} else {"%H"};
+    let mut timezone = false;
+    // 时间｜时:分[:秒][.小数] 其后可以跟随偏移量
+    let mut end = None;
+    for i in 0..tokens.len(){
+        if numeric(i).is_some() && literal(i + 1, ":") && numeric(i + 2).is_some(){
+            specs[i] = Some(hour.to_string());
+            specs[i + 1] = Some(":".to_string());
+            specs[i + 2] = Some("%M".to_string());
+            let mut j = i + 3;
+            if literal(j, ":") && numeric(j + 1).is_some(){
+                specs[j] = Some(":".to_string());
+                specs[j + 1] = Some("%S".to_string());
+                j += 2;
+                if let (Some(separator), Some(fraction)) = (tokens.get(j), numeric(j + 1)){
+                    match (separator.text.as_str(), fraction.text.len()){
+                        (".", _) => specs[j] = Some("%.f".to_string()),
+                        (",", 3) | (",", 6) | (",", 9) => specs[j] = Some(format!(",%{}f", fraction.text.len())),
+                        _ => {}
+                    }
+                    if specs[j].is_some(){
+                        specs[j + 1] = Some(String::new());
+                        j += 2;
+                    }
+                }
+            }
+            end = Some(j);
+            break
+        }
+    }
+    // 紧凑的时间｜T142030
+    if end.is_none(){
+        for i in 1..tokens.len(){
+            if tokens[i - 1].text.eq_ignore_ascii_case("t") && numeric(i).map(|v| v.text.len() == LEN_SERIES_TIME).unwrap_or(false){
+                specs[i] = Some("%H%M%S".to_string());
+                end = Some(i + 1);
+                break
+            }
+        }
+    }
+    // 偏移量｜Z +08 +0800 +08:00
+    if let Some(j) = end{
+        let j = if literal(j, " ") {j + 1} else {j};
+        if tokens.get(j).map(|v| v.text.eq_ignore_ascii_case("z")).unwrap_or(false){
+            specs[j] = Some("%#z".to_string());
+            timezone = true;
+        }else if (literal(j, "+") || literal(j, "-")) && numeric(j + 1).is_some(){
+            specs[j] = Some("%#z".to_string());
+            specs[j + 1] = Some(String::new());
+            if literal(j + 2, ":") && numeric(j + 3).is_some(){
+                specs[j + 2] = Some(String::new());
+                specs[j + 3] = Some(String::new());
+            }
+            timezone = true;
+        }
+    }
+    // 日期
+    let mut dates: Vec<usize> = vec![];
+    for (i, token) in tokens.iter().enumerate(){
+        if specs[i].is_some(){
+            continue
+        }
+        if token.label == DateTimeLabel::Numeric || (token.label == DateTimeLabel::Characters && is_month_name(&token.text, lexicon)){
+            dates.push(i);
+        }
+    }
+    match dates.len(){
+        1 => {
+            let token = &tokens[dates[0]];
+            if token.label != DateTimeLabel::Numeric{
+                return None
+            }
+            let spec = match (token.text.len(), order){
+                (LEN_SERIES_DATE, "ymd") => "%Y%m%d",
+                (LEN_SERIES_DATE, "dmy") => "%d%m%Y",
+                (LEN_SERIES_DATE, "mdy") => "%m%d%Y",
+                (12, "ymd") if end.is_none() => "%Y%m%d%H%M",
+                (14, "ymd") if end.is_none() => "%Y%m%d%H%M%S",
+                _ => return None
+            };
+            specs[dates[0]] = Some(spec.to_string());
+        }
+        3 => {
+            for (i, role) in dates.iter().zip(order.chars()){
+                let token = &tokens[*i];
+                let length = token.text.len();
+                let spec = match (role, &token.label){
+                    ('m', DateTimeLabel::Characters) => "%B",
+                    (_, DateTimeLabel::Characters) => return None,
+                    ('y', _) if length == 4 => "%Y",
+                    ('y', _) if length == 2 => "%y",
+                    ('m', _) if length <= 2 => "%m",
+                    ('d', _) if length <= 2 => "%d",
+                    _ => return None
+                };
+                specs[*i] = Some(spec.to_string());
+            }
+        }
+        _ => return None
+    }
+    // 其余文字和分隔符
+    let mut format = String::new();
+    for (i, token) in tokens.iter().enumerate(){
+        match &specs[i]{
+            Some(v) => format.push_str(v),
+            None => {
+                match token.label{
+                    DateTimeLabel::Numeric => return None,
+                    DateTimeLabel::Characters if is_weekday_word(&token.text) => format.push_str("%A"),
+                    DateTimeLabel::Characters if is_meridian_word(&token.text) => format.push_str("%p"),
+                    _ => format.push_str(&token.text.replace('%', "%%"))
+                }
+            }
+        }
+    }
+    Some((format, timezone))
+}
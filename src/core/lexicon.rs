@@ -0,0 +1,100 @@
+use std::{collections::HashMap, fs, io, path::Path};
+use serde::de::Error;
+use serde_json::Value;
+use crate::bind::{Holiday, Lexicon};
+use crate::core::corpus::{convert_holiday_rule, default_lexicon};
+
+
+impl Lexicon{
+    /// 内置词库的副本｜在此基础上补充或覆盖
+    pub fn new() -> Lexicon{
+        default_lexicon().as_ref().clone()
+    }
+
+    /// 从 JSON 补充或覆盖词库｜同名的词以 JSON 为准
+    /// 例如 {"month": {"setiembre": "september"}, "relative": {"hace un rato": "minute ago"}, "timezone": {"brt": -10800}, "holiday": {"双十一": "fixed 11 11"}}
+    /// 键为 month relative weekday meridian timezone holiday 其余的键视为错误｜节日规则同 convert_holiday_rule｜先校验全部内容 出错时词库保持不变
+    /// 仅支持 JSON TOML 等格式需先转换为 JSON
+    pub fn extend_json(&mut self, text: &str) -> Result<(), serde_json::Error>{
+        let sections: HashMap<String, HashMap<String, Value>> = serde_json::from_str(text)?;
+        let mut words: Vec<(&str, &str, &str)> = vec![];
+        let mut offsets: Vec<(&str, i32)> = vec![];
+        let mut holidays: Vec<(&str, Holiday)> = vec![];
+        for (section, items) in sections.iter(){
+            if !["month", "relative", "weekday", "meridian", "timezone", "holiday"].contains(&section.as_str()){
+                return Err(serde_json::Error::custom(format!("unknown section {}", section)))
+            }
+            for (key, value) in items{
+                if section == "timezone"{
+                    let offset = value.as_i64().and_then(|v| i32::try_from(v).ok()).ok_or_else(|| serde_json::Error::custom(format!("invalid offset of {}", key)))?;
+                    offsets.push((key, offset));
+                    continue
+                }
+                let value = value.as_str().ok_or_else(|| serde_json::Error::custom(format!("invalid value of {}", key)))?;
+                if section == "holiday"{
+                    let holiday = convert_holiday_rule(value).ok_or_else(|| serde_json::Error::custom(format!("invalid rule of {}", key)))?;
+                    holidays.push((key, holiday));
+                    continue
+                }
+                words.push((section, key, value));
+            }
+        }
+        for (section, key, value) in words{
+            match section{
+                "month" => self.insert_month(key, value),
+                "relative" => self.insert_relative(key, value),
+                "weekday" => self.insert_weekday(key, value),
+                _ => self.insert_meridian(key, value)
+            }
+        }
+        for (key, offset) in offsets{
+            self.insert_timezone(key, offset);
+        }
+        for (key, holiday) in holidays{
+            self.insert_holiday(key, holiday);
+        }
+        Ok(())
+    }
+
+    /// 从 JSON 文件补充或覆盖词库｜格式同 extend_json
+    pub fn load_json<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()>{
+        let text = fs::read_to_string(path)?;
+        self.extend_json(&text)?;
+        Ok(())
+    }
+
+    /// 月份名称｜month 为英文月份 例如 january
+    pub fn insert_month(&mut self, name: &str, month: &str){
+        self.month.insert(name.to_lowercase(), month.to_lowercase());
+    }
+
+    /// 相对时间｜text 为 year ago month ago week ago day ago hour ago minute ago second ago 之一
+    /// 新的表达排在匹配顺序的最后 即同时命中时优先
+    pub fn insert_relative(&mut self, name: &str, text: &str){
+        let name = name.to_lowercase();
+        if !self.relative_order.contains(&name){
+            self.relative_order.push(name.clone());
+        }
+        self.relative.insert(name, text.to_lowercase());
+    }
+
+    /// 星期名称｜weekday 为英文星期 例如 monday
+    pub fn insert_weekday(&mut self, name: &str, weekday: &str){
+        self.weekday.insert(name.to_lowercase(), weekday.to_lowercase());
+    }
+
+    /// 上下午｜meridian 为 am 或 pm
+    pub fn insert_meridian(&mut self, name: &str, meridian: &str){
+        self.meridian.insert(name.to_lowercase(), meridian.to_lowercase());
+    }
+
+    /// 时区缩写｜offset 为相对于 UTC 的偏移量(秒)
+    pub fn insert_timezone(&mut self, name: &str, offset: i32){
+        self.timezone.insert(name.to_lowercase(), offset);
+    }
+
+    /// 节日｜例如 双十一 -> Holiday{rule: HolidayRule::Fixed(11, 11), offset: 0}
+    pub fn insert_holiday(&mut self, name: &str, holiday: Holiday){
+        self.holiday.insert(name.to_lowercase(), holiday);
+    }
+}
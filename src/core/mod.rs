@@ -4,4 +4,12 @@ pub mod timestamp;
 pub mod series;
 pub mod relative;
 pub mod absolute;
+pub mod standard;
+pub mod layout;
+pub mod range;
+pub mod duration;
+pub mod recurrence;
+pub mod period;
+pub mod lexicon;
+pub mod param;
 pub mod parse_auto;
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use crate::bind::{Lexicon, Param};
+
+
+/// 链式设置参数｜新增字段时不影响已有的调用方
+/// 例如 Param::new().with_strict(true).with_formats(&["%d/%m/%Y"])
+impl Param{
+    pub fn new() -> Param{
+        Param{..Default::default()}
+    }
+
+    pub fn with_timezone(mut self, timezone: &str) -> Param{
+        self.timezone = timezone.to_string();
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Param{
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_lenient(mut self, lenient: bool) -> Param{
+        self.lenient = lenient;
+        self
+    }
+
+    pub fn with_minguo(mut self, minguo: bool) -> Param{
+        self.minguo = minguo;
+        self
+    }
+
+    /// 格式提示｜按顺序优先尝试
+    pub fn with_formats(mut self, formats: &[&str]) -> Param{
+        self.formats = formats.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    /// 财年起始月份
+    pub fn with_fiscal(mut self, fiscal: u32) -> Param{
+        self.fiscal = fiscal;
+        self
+    }
+
+    pub fn with_trace(mut self, trace: bool) -> Param{
+        self.trace = trace;
+        self
+    }
+
+    pub fn with_span(mut self, span: bool) -> Param{
+        self.span = span;
+        self
+    }
+
+    pub fn with_lexicon(mut self, lexicon: Lexicon) -> Param{
+        self.lexicon = Some(Arc::new(lexicon));
+        self
+    }
+}
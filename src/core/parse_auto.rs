@@ -1,8 +1,9 @@
-use crate::bind::{Param, Result};
+use crate::bind::{DateValidity, Param, Result};
 use crate::{parse_absolute, parse_relative, parse_series, parse_timestamp};
 
+/// 依次尝试时间戳、相对时间、绝对时间和时间序列｜均失败时保留最强的合法性 例如 2023-02-30 为 Invalid
 pub fn parse_auto(text: &str, options: Option<Param>) -> Result{
-    let item = Result{timezone: "none".to_string(), method: String::from("none"), ..Default::default()};
+    let mut item = Result{timezone: "none".to_string(), method: String::from("none"), ..Default::default()};
     let result_parse_timestamp = parse_timestamp(text, clone_options(&options));
     if result_parse_timestamp.status{
         return result_parse_timestamp
@@ -15,19 +16,45 @@ pub fn parse_auto(text: &str, options: Option<Param>) -> Result{
     if result_parse_absolute.status{
         return result_parse_absolute
     }
+    // 已识别出日期但日期不合法时不再尝试时间序列 例如 2023-10-05T14:20:30+25:00
+    if result_parse_absolute.validity == DateValidity::Invalid{
+        item.validity = DateValidity::Invalid;
+        return item
+    }
     let result_parse_series = parse_series(text, clone_options(&options));
     if result_parse_series.status{
         return result_parse_series
     }
+    for validity in [result_parse_timestamp.validity, result_parse_relative.validity, result_parse_absolute.validity, result_parse_series.validity]{
+        if rank_validity(&validity) > rank_validity(&item.validity){
+            item.validity = validity;
+        }
+    }
     return item
 }
 
-fn clone_options(options: &Option<Param>) -> Option<Param>{
+/// 合法性的强弱｜Invalid 强于 Rolled 强于 Unrecognized
+fn rank_validity(validity: &DateValidity) -> u8{
+    match validity{
+        DateValidity::Invalid => 2,
+        DateValidity::Rolled => 1,
+        _ => 0
+    }
+}
+
+pub fn clone_options(options: &Option<Param>) -> Option<Param>{
     if let Some(opt) = options{
         let param = Param{
             // era: opt.era.clone(),
             timezone: opt.timezone.clone(),
-            strict: opt.strict.clone()
+            strict: opt.strict,
+            lenient: opt.lenient,
+            minguo: opt.minguo,
+            formats: opt.formats.clone(),
+            fiscal: opt.fiscal,
+            trace: opt.trace,
+            span: opt.span,
+            lexicon: opt.lexicon.clone(),
         };
         return Some(param)
     }
@@ -0,0 +1,109 @@
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
+use regex::Captures;
+use crate::bind::{Granularity, Param, ResultRange};
+use crate::core::corpus::search_with_period_pattern;
+use crate::core::parse_auto::clone_options;
+use crate::parse_absolute;
+use crate::utils::unitize_numeral;
+
+const MONTHS_QUARTER: u32 = 3;
+const MONTHS_HALF: u32 = 6;
+const MONTHS_YEAR: u32 = 12;
+const CENTURY: i32 = 2000;  // 两位数的财年 例如 FY23
+
+
+/// 季度、半年、周和财年解析｜结束时间为时间段的最后一天 例如 Q3 2023、Q3 FY2023、2023年第三季度、2023年上半年、H1 2022、2023年第42周、week 42
+/// 1. 周为 ISO 8601 周 从周一至周日 缺少年份时取当前的 ISO 年
+/// 2. 季度、半年和财年从 Param.fiscal 指定的月份开始 财年以结束时所在的年份命名 缺少年份时取当前年份
+/// 3. 只有年份时需要 FY 财年 等标志 例如 FY2023 2023财年
+/// 4. Param.fiscal 超过 12 时视为错误的参数 不做解析
+pub fn parse_period(text: &str, options: Option<Param>) -> ResultRange{
+    let text = normalize_period(&unitize_numeral(&text.trim().to_lowercase()));
+    let fiscal = options.as_ref().map(|v| v.fiscal).unwrap_or(0);
+    if fiscal > MONTHS_YEAR{
+        return ResultRange::default()
+    }
+    let month = fiscal.max(1);
+    let (start, end, granularity) = match create_period(&text, month){
+        Some(v) => v,
+        None => return ResultRange::default()
+    };
+    ResultRange{
+        status: true,
+        start: parse_absolute(&start.format("%Y-%m-%d").to_string(), clone_options(&options)),
+        end: parse_absolute(&end.format("%Y-%m-%d").to_string(), clone_options(&options)),
+        granularity,
+    }
+}
+
+/// 统一为 q3 h1 w42 的写法｜第三季度 -> q3 上半年 -> h1 第42周 -> w42 third quarter -> q3
+fn normalize_period(text: &str) -> String{
+    let mut text = text.to_string();
+    for name in ["week", "week_zh"]{
+        if let Some(pattern) = search_with_period_pattern(name){
+            text = pattern.replace_all(&text, " w${number} ").to_string();
+        }
+    }
+    // 单个汉字数字不会被 unitize_numeral 转换｜三季度
+    if let Some(pattern) = search_with_period_pattern("quarter_zh"){
+        text = pattern.replace_all(&text, |caps: &Captures| {
+            let number = match &caps["number"]{"一" => "1", "二" => "2", "三" => "3", "四" => "4", v => v};
+            format!(" q{} ", number)
+        }).to_string();
+    }
+    if let Some(pattern) = search_with_period_pattern("half_zh"){
+        text = pattern.replace_all(&text, |caps: &Captures| {
+            if &caps["half"] == "上" {" h1 "} else {" h2 "}
+        }).to_string();
+    }
+    if let Some(pattern) = search_with_period_pattern("ordinal"){
+        text = pattern.replace_all(&text, |caps: &Captures| {
+            let number = match &caps["ordinal"]{
+                "first" | "1st" => 1,
+                "second" | "2nd" => 2,
+                "third" | "3rd" => 3,
+                _ => 4
+            };
+            format!(" {}{} ", &caps["unit"][..1], number)
+        }).to_string();
+    }
+    text
+}
+
+/// 返回 (起始日期, 结束日期, 粒度)
+fn create_period(text: &str, month: u32) -> Option<(NaiveDate, NaiveDate, Granularity)>{
+    let year = search_year_period(text);
+    let period = search_with_period_pattern("period").and_then(|pattern| pattern.captures(text)).and_then(|caps| {
+        let kind = caps.name("kind").or(caps.name("kind_reverse"))?.as_str().to_string();
+        let number = caps.name("number").or(caps.name("number_reverse"))?.as_str().parse::<u32>().ok()?;
+        Some((kind, number))
+    });
+    let (offset, length, granularity) = match period{
+        Some((kind, number)) if kind == "w" => {
+            let year = year.unwrap_or(Local::now().date_naive().iso_week().year());
+            let start = NaiveDate::from_isoywd_opt(year, number, Weekday::Mon)?;
+            return Some((start, start + Duration::days(6), Granularity::Week))
+        }
+        Some((kind, number)) if kind == "q" && (1..=4).contains(&number) => ((number - 1) * MONTHS_QUARTER, MONTHS_QUARTER, Granularity::Quarter),
+        Some((kind, number)) if kind == "h" && (1..=2).contains(&number) => ((number - 1) * MONTHS_HALF, MONTHS_HALF, Granularity::Half),
+        None if year.is_some() && search_with_period_pattern("fiscal").map(|v| v.is_match(text)).unwrap_or(false) => (0, MONTHS_YEAR, Granularity::Year),
+        _ => return None
+    };
+    // 财年以结束时所在的年份命名｜起始月份不是 1 月时 财年从上一年开始
+    let year = year.unwrap_or(Local::now().year());
+    let first = NaiveDate::from_ymd_opt(if month > 1 {year - 1} else {year}, month, 1)?;
+    let start = first.checked_add_months(Months::new(offset))?;
+    let end = start.checked_add_months(Months::new(length))? - Duration::days(1);
+    Some((start, end, granularity))
+}
+
+/// 年份｜FY23 FY2023 或者四位数字
+fn search_year_period(text: &str) -> Option<i32>{
+    for name in ["year_fiscal", "year"]{
+        if let Some(caps) = search_with_period_pattern(name).and_then(|pattern| pattern.captures(text)){
+            let year = caps["year"].parse::<i32>().ok()?;
+            return Some(if caps["year"].len() == 2 {CENTURY + year} else {year})
+        }
+    }
+    None
+}
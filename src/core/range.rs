@@ -0,0 +1,201 @@
+use chrono::{Datelike, Duration, Local, Weekday};
+use crate::bind::{Lexicon, Param, ResultRange};
+use crate::core::corpus::{default_lexicon, get_lexicon, is_month_name, unitize_weekday};
+use crate::core::parse_auto::clone_options;
+use crate::parse_absolute;
+
+const TEXT_RANGE_SEPARATOR: [&str; 9] = ["~", "～", "至", "到", "–", "—", "until", "till", "to"];
+const TEXT_RANGE_PREFIX: [&str; 5] = ["from", "between", "从", "自", "由"];
+const LEN_YEAR: usize = 4;
+
+
+/// 时间范围解析｜例如 2023-10-01 ~ 2023-10-05、10月1日至7日、from Mon to Fri
+/// 1. 分隔符分为三组 ~ 至 到 to until – 等、两侧有空格的连字符、其余连字符 只尝试存在的第一组 文字分隔符两侧需要有空格
+/// 2. 两端缺省的部分相互继承｜结束时间缺少开头的年月时继承起始时间 起始时间缺少结尾的月份年份时继承结束时间
+/// 3. 两端都没有年份时取当前年份 继承年份后结束时间早于起始时间视为跨年 例如 12月25日至1月3日
+pub fn parse_range(text: &str, options: Option<Param>) -> ResultRange{
+    let text = text.trim();
+    // 只尝试优先级最高的一组分隔符｜例如存在 ~ 时不再以日期中的连字符拆分
+    if let Some(positions) = split_range(text).into_iter().find(|v| !v.is_empty()){
+        // 同一优先级的分隔符中取继承内容最少的拆分方式｜例如 2023-10-01-2023-10-05 从中间拆分
+        let mut best: Option<(ResultRange, usize)> = None;
+        for (start, end) in positions{
+            let left = eliminate_prefix_range(text[..start].trim());
+            let right = text[end..].trim();
+            if left.is_empty() || right.is_empty(){
+                continue
+            }
+            let item = match parse_range_weekday(left, right, &options){
+                Some(v) => Some((v, 0)),
+                None => parse_range_date(left, right, &options)
+            };
+            if let Some((data, cost)) = item{
+                if best.as_ref().map(|v| cost < v.1).unwrap_or(true){
+                    best = Some((data, cost));
+                }
+            }
+        }
+        if let Some((data, _)) = best{
+            return data
+        }
+    }
+    ResultRange::default()
+}
+
+/// 分隔符的位置 按优先级分组｜其他分隔符 两侧有空格的连字符 其余连字符
+fn split_range(text: &str) -> Vec<Vec<(usize, usize)>>{
+    let lower = text.to_ascii_lowercase();
+    let mut positions: Vec<(usize, usize)> = vec![];
+    for separator in TEXT_RANGE_SEPARATOR{
+        let word = separator.chars().all(|ch| ch.is_ascii_alphabetic());
+        for (i, _) in lower.match_indices(separator){
+            let end = i + separator.len();
+            if word && !(lower[..i].ends_with(' ') && lower[end..].starts_with(' ')){
+                continue
+            }
+            positions.push((i, end));
+        }
+    }
+    let hyphens: Vec<usize> = text.match_indices('-').map(|(i, _)| i).collect();
+    let spaced = |i: &usize| text[..*i].ends_with(' ') || text[*i + 1..].starts_with(' ');
+    let spaced_hyphens = hyphens.iter().filter(|i| spaced(i)).map(|i| (*i, *i + 1)).collect();
+    let bare_hyphens = hyphens.iter().filter(|i| !spaced(i)).map(|i| (*i, *i + 1)).collect();
+    vec![positions, spaced_hyphens, bare_hyphens]
+}
+
+fn eliminate_prefix_range(text: &str) -> &str{
+    let lower = text.to_ascii_lowercase();
+    for prefix in TEXT_RANGE_PREFIX{
+        if lower.starts_with(prefix){
+            return text[prefix.len()..].trim()
+        }
+    }
+    text
+}
+
+/// 星期范围｜起始时间取今天或之后最近的一天 结束时间取起始时间当天或之后最近的一天
+fn parse_range_weekday(left: &str, right: &str, options: &Option<Param>) -> Option<ResultRange>{
+    let trim = |v: &str| v.trim_matches(|ch: char| ch.is_ascii_punctuation()).to_string();
+    let lexicon = options.as_ref().map(get_lexicon).unwrap_or_else(default_lexicon);
+    let first = unitize_weekday(&trim(left), &lexicon)?;
+    let last = unitize_weekday(&trim(right), &lexicon)?;
+    let today = Local::now().date_naive();
+    let distance = |from: Weekday, to: Weekday| (to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64).rem_euclid(7);
+    let start = today + Duration::days(distance(today.weekday(), first));
+    let end = start + Duration::days(distance(first, last));
+    let data = ResultRange{
+        status: true,
+        start: parse_absolute(&start.format("%Y-%m-%d").to_string(), clone_options(options)),
+        end: parse_absolute(&end.format("%Y-%m-%d").to_string(), clone_options(options)),
+        ..Default::default()
+    };
+    Some(data)
+}
+
+/// 日期范围｜返回 (结果, 继承的字符数)
+fn parse_range_date(left: &str, right: &str, options: &Option<Param>) -> Option<(ResultRange, usize)>{
+    let lexicon = options.as_ref().map(get_lexicon).unwrap_or_else(default_lexicon);
+    let lexicon = lexicon.as_ref();
+    let components_left = search_component_range(left, lexicon);
+    let components_right = search_component_range(right, lexicon);
+    // 年份单独继承 其余成分按数量继承
+    let dates_left: Vec<&Component> = components_left.iter().filter(|v| !v.time && !v.year).collect();
+    let dates_right: Vec<&Component> = components_right.iter().filter(|v| !v.time && !v.year).collect();
+    // 起始时间继承结束时间末尾的月份和年份｜1 - 7 Oct 2023 -> 1 Oct 2023
+    let mut text_left = left.to_string();
+    if !dates_left.is_empty() && dates_left.len() < dates_right.len(){
+        let from = dates_right[dates_left.len() - 1].end;
+        let to = components_right.iter().find(|v| v.time).map(|v| v.start).unwrap_or(right.len());
+        if from < to{
+            text_left.push_str(&right[from..to]);
+        }
+    }
+    if !has_year_range(&text_left, lexicon){
+        let year = match components_right.iter().find(|v| v.year){
+            Some(v) => right[v.start..v.end].to_string(),
+            None => Local::now().year().to_string()
+        };
+        text_left = format!("{} {}", year, text_left);
+    }
+    let start = parse_absolute(&text_left, clone_options(options));
+    if !start.status{
+        return None
+    }
+    // 结束时间继承起始时间开头的年月或者日期｜10月1日至7日 -> 10月7日 2023-10-05 09:00 - 17:00 -> 2023-10-05 17:00
+    let mut text_right = right.to_string();
+    if dates_right.is_empty() && !has_year_range(right, lexicon){
+        let to = components_left.iter().find(|v| v.time).map(|v| v.start).unwrap_or(left.len());
+        text_right = format!("{} {}", left[..to].trim(), right);
+    }else if dates_right.len() < dates_left.len(){
+        let to = dates_left[dates_left.len() - dates_right.len()].start;
+        text_right = left[..to].to_string() + right;
+    }
+    if !has_year_range(&text_right, lexicon){
+        text_right = format!("{} {}", start.time.year(), text_right);
+    }
+    let mut end = parse_absolute(&text_right, clone_options(options));
+    // 结束时间早于起始时间时顺延｜只有时刻的顺延一天 2023-10-05 22:00 - 02:00 其余顺延一年 12月25日至1月3日
+    if !has_year_range(right, lexicon) && end.status && end.time < start.time{
+        let time = if dates_right.is_empty(){
+            end.time.checked_add_signed(Duration::days(1))
+        }else{
+            end.time.with_year(end.time.year() + 1)
+        };
+        if let Some(time) = time{
+            end = parse_absolute(&time.format("%Y-%m-%d %H:%M:%S").to_string(), clone_options(options));
+        }
+    }
+    if !end.status || end.time < start.time{
+        return None
+    }
+    let cost = text_left.len() - left.len() + text_right.len() - right.len();
+    Some((ResultRange{status: true, start, end, ..Default::default()}, cost))
+}
+
+fn has_year_range(text: &str, lexicon: &Lexicon) -> bool{
+    search_component_range(text, lexicon).iter().any(|v| v.year)
+}
+
+/// 文本中的日期和时间成分｜数字、月份名称以及 时:分[:秒]
+struct Component{
+    start: usize,
+    end: usize,
+    time: bool,
+    year: bool,
+}
+
+fn search_component_range(text: &str, lexicon: &Lexicon) -> Vec<Component>{
+    let mut components: Vec<Component> = vec![];
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len(){
+        let (start, ch) = chars[i];
+        let mut j = i;
+        if ch.is_ascii_digit(){
+            while j < chars.len() && chars[j].1.is_ascii_digit(){
+                j += 1;
+            }
+            let digits = j - i;
+            let time = j + 1 < chars.len() && chars[j].1 == ':' && chars[j + 1].1.is_ascii_digit();
+            if time{
+                while j < chars.len() && (chars[j].1.is_ascii_digit() || chars[j].1 == ':'){
+                    j += 1;
+                }
+            }
+            let end = chars.get(j).map(|v| v.0).unwrap_or(text.len());
+            components.push(Component{start, end, time, year: !time && digits == LEN_YEAR});
+        }else if ch.is_alphabetic(){
+            while j < chars.len() && chars[j].1.is_alphabetic(){
+                j += 1;
+            }
+            let end = chars.get(j).map(|v| v.0).unwrap_or(text.len());
+            if is_month_name(&text[start..end], lexicon){
+                components.push(Component{start, end, time: false, year: false});
+            }
+        }else{
+            j += 1;
+        }
+        i = j;
+    }
+    components
+}
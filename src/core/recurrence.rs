@@ -0,0 +1,294 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use crate::bind::{Frequency, Lexicon, Param, ResultRecurrence};
+use crate::core::corpus::{default_lexicon, get_lexicon, search_with_recurrence_pattern, unitize_weekday, unitize_month_abbr};
+use crate::core::parse_auto::clone_options;
+use crate::parse_absolute;
+use crate::utils::{days_in_month, unitize_numeral};
+
+const MAX_DAYS_RECURRENCE: i64 = 366 * 100;  // 枚举的上限｜避免 2月30日 这类永远不会发生的规则无限循环
+const LEN_WEEKDAY_PLURAL: usize = 5;  // 复数星期去掉 s 之后的最短长度｜排除 tues weds thurs 等缩写
+const TEXT_WEEKDAY_ZH: [char; 7] = ['一', '二', '三', '四', '五', '六', '日'];
+const TEXT_RANGE_WEEKDAY: [&str; 8] = ["至", "到", "-", "~", "–", "to", "through", "thru"];
+const TEXT_RECURRENCE_MARKER: [&str; 4] = ["every", "each", "每", "逢"];
+const WORKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+const WEEKENDS: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+
+impl ResultRecurrence{
+    /// 参考时间之后的 N 次发生时间｜参考时间同时作为间隔的起点 即 RRULE 的 DTSTART
+    pub fn occurrences(&self, after: NaiveDateTime, count: usize) -> Vec<NaiveDateTime>{
+        let mut data: Vec<NaiveDateTime> = vec![];
+        if !self.status{
+            return data
+        }
+        let time = self.time.unwrap_or(NaiveTime::MIN);
+        let start = after.date();
+        for i in 0..MAX_DAYS_RECURRENCE{
+            if data.len() >= count{
+                break
+            }
+            let date = start + Duration::days(i);
+            if !self.contains(start, date){
+                continue
+            }
+            let item = NaiveDateTime::new(date, time);
+            if item > after{
+                data.push(item);
+            }
+        }
+        data
+    }
+
+    /// 日期是否符合规则｜未指定的部分取起点的对应值 例如 monthly 未指定日期时取起点的日
+    fn contains(&self, start: NaiveDate, date: NaiveDate) -> bool{
+        let interval = self.interval.max(1) as i64;
+        let weekday = if self.by_day.is_empty() {vec![start.weekday()]} else {self.by_day.clone()};
+        let month_day = if self.by_month_day.is_empty() {vec![start.day() as i32]} else {self.by_month_day.clone()};
+        // 负数从月末倒数｜-1 为每月的最后一天
+        let last = days_in_month(date.year(), date.month()) as i32;
+        let day = |v: &i32| if *v < 0 {last + 1 + v} else {*v};
+        let hit = month_day.iter().any(|v| day(v) == date.day() as i32);
+        let month = if self.by_month.is_empty() {vec![start.month()]} else {self.by_month.clone()};
+        let months = (date.year() - start.year()) as i64 * 12 + date.month() as i64 - start.month() as i64;
+        match self.frequency{
+            Frequency::Daily => {
+                (date - start).num_days() % interval == 0
+                    && (self.by_day.is_empty() || self.by_day.contains(&date.weekday()))
+            }
+            Frequency::Weekly => {
+                let monday = |v: NaiveDate| v - Duration::days(v.weekday().num_days_from_monday() as i64);
+                (monday(date) - monday(start)).num_days() / 7 % interval == 0 && weekday.contains(&date.weekday())
+            }
+            Frequency::Monthly => {
+                if months % interval != 0{
+                    return false
+                }
+                if self.by_month_day.is_empty() && !self.by_day.is_empty(){
+                    return self.by_day.contains(&date.weekday())
+                }
+                hit
+            }
+            Frequency::Yearly => {
+                (date.year() - start.year()) as i64 % interval == 0 && month.contains(&date.month()) && hit
+            }
+            Frequency::Non => false
+        }
+    }
+
+    /// 转为 RFC 5545 的 RRULE 文本 例如 FREQ=WEEKLY;INTERVAL=1;BYDAY=MO;BYHOUR=9;BYMINUTE=0;BYSECOND=0
+    pub fn to_rrule(&self) -> String{
+        let frequency = match self.frequency{
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+            Frequency::Non => return String::new()
+        };
+        let mut items = vec![format!("FREQ={}", frequency), format!("INTERVAL={}", self.interval.max(1))];
+        if !self.by_day.is_empty(){
+            let days: Vec<String> = self.by_day.iter().map(|v| v.to_string()[..2].to_uppercase()).collect();
+            items.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty(){
+            let days: Vec<String> = self.by_month_day.iter().map(|v| v.to_string()).collect();
+            items.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        if !self.by_month.is_empty(){
+            let months: Vec<String> = self.by_month.iter().map(|v| v.to_string()).collect();
+            items.push(format!("BYMONTH={}", months.join(",")));
+        }
+        if let Some(time) = self.time{
+            items.push(format!("BYHOUR={};BYMINUTE={};BYSECOND={}", time.hour(), time.minute(), time.second()));
+        }
+        items.join(";")
+    }
+}
+
+
+/// 重复规则解析｜例如 every Monday at 9am、每月1号、daily at 08:00、每两周、每周一至周五
+/// 没有 every 每 等标志时不视为重复规则 例如 Monday at 9am
+/// 1. 频率和间隔来自 every 2 weeks、每隔3天 这类表达或者 daily weekly 等频率词
+/// 2. 星期来自星期词库 连续的两个星期之间是 至 到 - to 时视为范围
+/// 3. 时间交给绝对时间解析 因此支持 9am 下午3点半 等写法
+/// 4. 每月最后一天 last day of the month 记为 BYMONTHDAY=-1
+pub fn parse_recurrence(text: &str, options: Option<Param>) -> ResultRecurrence{
+    let lexicon = options.as_ref().map(get_lexicon).unwrap_or_else(default_lexicon);
+    let lexicon = lexicon.as_ref();
+    let original = text.trim().to_lowercase();
+    let text = unitize_numeral(&original);
+    let mut data = ResultRecurrence{interval: 1, ..Default::default()};
+    let mut marker = TEXT_RECURRENCE_MARKER.iter().any(|v| original.contains(v));
+    if let Some(caps) = search_with_recurrence_pattern("interval").and_then(|pattern| pattern.captures(&text)){
+        marker = true;
+        let unit = caps.name("unit").or(caps.name("unit_zh")).map(|v| v.as_str()).unwrap_or("");
+        data.frequency = match unit{
+            "day" | "天" | "日" => Frequency::Daily,
+            "week" | "周" | "週" | "星期" | "礼拜" | "禮拜" => Frequency::Weekly,
+            "month" | "月" => Frequency::Monthly,
+            _ => Frequency::Yearly
+        };
+        data.interval = match caps.name("number").or(caps.name("number_zh")).map(|v| v.as_str()){
+            Some("other") => 2,
+            Some(v) => v.parse().unwrap_or(1),
+            None => 1
+        };
+    }else if let Some(caps) = search_with_recurrence_pattern("frequency").and_then(|pattern| pattern.captures(&text)){
+        marker = true;
+        let unit = caps.name("unit").or(caps.name("unit_zh")).map(|v| v.as_str()).unwrap_or("");
+        (data.frequency, data.interval) = match unit{
+            "daily" | "everyday" | "天天" => (Frequency::Daily, 1),
+            "weekly" => (Frequency::Weekly, 1),
+            "biweekly" | "fortnightly" => (Frequency::Weekly, 2),
+            "monthly" => (Frequency::Monthly, 1),
+            _ => (Frequency::Yearly, 1)
+        };
+    }
+    // 星期
+    let (by_day, plural) = search_weekday_recurrence(&original, lexicon);
+    data.by_day = by_day;
+    marker = marker || plural;
+    // 日期和月份
+    if let Some(pattern) = search_with_recurrence_pattern("month_day"){
+        for caps in pattern.captures_iter(&text){
+            if let Some(day) = caps.name("day").or(caps.name("day_zh")).and_then(|v| v.as_str().parse::<i32>().ok()){
+                if (1..=31).contains(&day) && !data.by_month_day.contains(&day){
+                    data.by_month_day.push(day);
+                }
+            }
+            if let Some(month) = caps.name("month_zh").and_then(|v| v.as_str().parse::<u32>().ok()){
+                if (1..=12).contains(&month) && !data.by_month.contains(&month){
+                    data.by_month.push(month);
+                }
+            }
+        }
+    }
+    if search_with_recurrence_pattern("last_day").map(|pattern| pattern.is_match(&text)).unwrap_or(false) && !data.by_month_day.contains(&-1){
+        data.by_month_day.push(-1);
+    }
+    for word in original.split(|ch: char| !ch.is_ascii_alphabetic()){
+        if word.len() < 3 || unitize_weekday(word, lexicon).is_some(){
+            continue
+        }
+        if let Some(month) = unitize_month_recurrence(word, lexicon){
+            if !data.by_month.contains(&month){
+                data.by_month.push(month);
+            }
+            // 月份名称之后的日期｜every year on Feb 29
+            let position = word.as_ptr() as usize - original.as_ptr() as usize + word.len();
+            if let Some(day) = search_day_after_month(&original[position..]){
+                if !data.by_month_day.contains(&day){
+                    data.by_month_day.push(day);
+                }
+            }
+        }
+    }
+    // 时间
+    if let Some(hit) = search_with_recurrence_pattern("time").and_then(|pattern| pattern.find(&text)){
+        let r = parse_absolute(&format!("2000-01-01 {}", hit.as_str()), clone_options(&options));
+        if r.status{
+            data.time = Some(r.time.time());
+        }
+    }
+    if data.frequency == Frequency::Non{
+        data.frequency = if !data.by_day.is_empty() {Frequency::Weekly} else if !data.by_month.is_empty() {Frequency::Yearly} else if !data.by_month_day.is_empty() {Frequency::Monthly} else {Frequency::Non};
+    }
+    if !marker || data.frequency == Frequency::Non{
+        return ResultRecurrence{..Default::default()}
+    }
+    data.status = true;
+    data
+}
+
+/// 月份名称转为月份｜词库中的本地化名称和英文缩写 例如 setiembre feb
+fn unitize_month_recurrence(word: &str, lexicon: &Lexicon) -> Option<u32>{
+    match lexicon.month.get(word){
+        Some(v) => unitize_month_abbr(v),
+        None => unitize_month_abbr(word)
+    }
+}
+
+/// 紧跟在月份名称之后的日期｜例如 feb 29、feb. 29th 不含年份和时刻 例如 feb 2024、feb 9:00
+fn search_day_after_month(text: &str) -> Option<i32>{
+    let rest = text.trim_start_matches('.').trim_start();
+    let digits = rest.len() - rest.trim_start_matches(|ch: char| ch.is_ascii_digit()).len();
+    if !(1..=2).contains(&digits) || rest[digits..].starts_with(':'){
+        return None
+    }
+    let day: i32 = rest[..digits].parse().ok()?;
+    (1..=31).contains(&day).then_some(day)
+}
+
+/// 文本中的星期｜返回 (星期, 是否出现了复数形式 例如 mondays weekdays)
+/// 只有去掉末尾的 s 才能识别的完整星期名称视为复数 例如 mondays｜tues thurs 为缩写 不是复数
+fn search_weekday_recurrence(text: &str, lexicon: &Lexicon) -> (Vec<Weekday>, bool){
+    let mut hits: Vec<(usize, usize, Vec<Weekday>)> = vec![];
+    let mut plural = false;
+    if let Some(pattern) = search_with_recurrence_pattern("weekday"){
+        for caps in pattern.captures_iter(text){
+            let hit = caps.get(0).unwrap();
+            let day = caps["day"].chars().next().unwrap_or('日');
+            let index = TEXT_WEEKDAY_ZH.iter().position(|v| *v == day).unwrap_or(6);
+            if let Ok(weekday) = Weekday::try_from(index as u8){
+                hits.push((hit.start(), hit.end(), vec![weekday]));
+            }
+        }
+    }
+    for word in text.split(|ch: char| !ch.is_alphanumeric()){
+        let position = word.as_ptr() as usize - text.as_ptr() as usize;
+        if !word.is_ascii() || word.len() < 3{
+            continue
+        }
+        let days = match word{
+            "weekday" => WORKDAYS.to_vec(),
+            "weekend" => WEEKENDS.to_vec(),
+            "weekdays" => {
+                plural = true;
+                WORKDAYS.to_vec()
+            }
+            "weekends" => {
+                plural = true;
+                WEEKENDS.to_vec()
+            }
+            _ => {
+                match unitize_weekday(word, lexicon){
+                    Some(v) => vec![v],
+                    None => match word.strip_suffix('s').and_then(|v| unitize_weekday(v, lexicon).map(|day| (v, day))){
+                        Some((v, day)) => {
+                            plural = plural || v.len() >= LEN_WEEKDAY_PLURAL;
+                            vec![day]
+                        }
+                        None => continue
+                    }
+                }
+            }
+        };
+        hits.push((position, position + word.len(), days));
+    }
+    for (name, days) in [("工作日", WORKDAYS.to_vec()), ("周末", WEEKENDS.to_vec()), ("週末", WEEKENDS.to_vec())]{
+        if let Some(position) = text.find(name){
+            hits.push((position, position + name.len(), days));
+        }
+    }
+    hits.sort_by_key(|v| v.0);
+    let mut data: Vec<Weekday> = vec![];
+    for (i, (_, end, days)) in hits.iter().enumerate(){
+        // 星期范围｜周一至周五 mon-fri
+        if let Some((next, _, next_days)) = hits.get(i + 1){
+            if days.len() == 1 && next_days.len() == 1 && TEXT_RANGE_WEEKDAY.contains(&text[*end..*next].trim()){
+                let mut day = days[0];
+                while day != next_days[0]{
+                    if !data.contains(&day){
+                        data.push(day);
+                    }
+                    day = day.succ();
+                }
+            }
+        }
+        for day in days{
+            if !data.contains(day){
+                data.push(*day);
+            }
+        }
+    }
+    (data, plural)
+}
@@ -1,10 +1,10 @@
 use std::collections::HashMap;
 use chrono::prelude::*;
 use chrono::Duration;
-use crate::bind::{ParseRelative, Param, Result};
+use crate::bind::{ParseRelative, Param, Result, DateValidity};
 use crate::core::interfaces::{Parse};
-use crate::core::corpus::{get_offset_local_utc, unitize_relative_text};
-use crate::utils::{create_datetime, create_timestamp, eliminate_noise, split_with_numeric, str_convert};
+use crate::core::corpus::{get_offset_local_utc, get_lexicon, unitize_relative_text};
+use crate::utils::{create_datetime, create_timestamp, eliminate_noise, split_with_numeric, str_convert, unitize_numeral};
 
 const MAX_SECTION_LEN_TRUSTED: usize = 5;
 const MAX_TEXT_LEN_TRUSTED: usize = 12;
@@ -33,6 +33,9 @@ impl ParseRelative{
     }
 
     fn assemble(&self, item: &mut Result){
+        if item.status{
+            item.validity = DateValidity::Valid;
+        }
         // 无时区
         item.datetime.local.datetime = item.time;
         // println!("local datetime: {:?}", item.datetime.local.datetime);
@@ -46,6 +49,7 @@ impl ParseRelative{
         // 去噪
         let mut item = eliminate_noise(text);
         // 规整
+        item = unitize_numeral(item.as_str());
         item =  self.unitize_text(item.as_str());
         // 切分
         let section: Vec<String> = split_with_numeric(item);
@@ -54,11 +58,12 @@ impl ParseRelative{
             return data
         }
         let numeric = self.search_numeric(&section);
+        let lexicon = get_lexicon(&self.param);
         for (index, item) in section.iter().enumerate() {
             if self.param.strict && item.chars().count() > MAX_TEXT_LEN_TRUSTED{
                 continue
             }
-            let result_relative_text = unitize_relative_text(item.as_str());
+            let result_relative_text = unitize_relative_text(item.as_str(), &lexicon);
             if !result_relative_text.hit{continue}
             if result_relative_text.same && numeric.len() == 0{
                 // 完全等于的情况下使用0时
@@ -121,7 +126,8 @@ impl ParseRelative{
                 return Some(datetime - Duration::hours(number))
             }
             "minute ago" =>{
-                if number > MAX_NUMBER_MINUTES{
+                // 一个半小时前 -> 90分钟前
+                if number > MAX_NUMBER_MINUTES*MAX_NUMBER_HOURS{
                     return None
                 }
                 return Some(datetime - Duration::minutes(number))
@@ -1,16 +1,11 @@
 use chrono::Duration;
 use chrono::prelude::*;
-use crate::bind::{ParseSeries, Param, Result, DateType, ResultDateText};
-use crate::core::corpus::{get_offset_local_utc, has_symbol, unitize_month_name, unitize_month_text};
+use crate::bind::{ParseSeries, Param, Result, DateType, DateValidity, ResultDateText};
+use crate::core::corpus::{get_offset_local_utc, get_lexicon, has_symbol, unitize_month_name, unitize_month_text};
 use crate::core::interfaces::{Parse};
-use crate::utils::{eliminate_no_break_space, eliminate_symbol_normal, eliminate_symbol_safe, is_number, section_with_space, split_with_numeric, unitize_text, eliminate_empty_item, create_datetime, create_timestamp};
+use crate::utils::{eliminate_no_break_space, eliminate_symbol_normal, eliminate_symbol_safe, is_number, section_with_space, split_with_numeric, unitize_text, eliminate_empty_item, create_datetime, create_date, create_timestamp, unitize_numeral, unitize_digit};
 
 const LEN_SLIDER_SECTION: usize = 8;  // 年月日长度要求一定为8｜例如 20230115 20150630
-const MAX_NUMBER_MONTH: u32 = 12;
-const MAX_NUMBER_DAY: u32 = 31;
-const MAX_NUMBER_HOURS: u32 = 24;
-const MAX_NUMBER_MINUTES: u32 = 60;
-const MAX_NUMBER_SECONDS: u32 = 60;
 const MIN_NUMBER_YEAR: i32 = 1970;
 const MAX_NUMBER_YEAR: i32 = 9999;
 
@@ -43,7 +38,8 @@ impl ParseSeries{
         // 去噪
         let mut item = self.eliminate(text);
         // 规整
-        (item, _) = unitize_text(item.as_str());
+        item = unitize_numeral(item.as_str());
+        (item, _) = unitize_text(item.as_str(), &get_lexicon(&self.param), false);
         // 切分
         let mut section: Vec<String> = split_with_numeric(item);
         section = section_with_space(section);
@@ -55,10 +51,12 @@ impl ParseSeries{
         // 按位分组
         let seeds = self.slider_window(&dates);
         // 正式解析
-        if let Some(di) = self.reload_datetime_with_text_format(seeds){
+        let (datetime, validity) = self.reload_datetime_with_text_format(seeds);
+        if let Some(di) = datetime{
             data.time = di;
             data.status = true;
         }
+        data.validity = validity;
         return data
     }
     fn assemble(&self, item: &mut Result){
@@ -71,52 +69,49 @@ impl ParseSeries{
     }
 
     // 基于固有文本格式刷新时间对象
-    fn reload_datetime_with_text_format(&self, seeds: Vec<String>) -> Option<NaiveDateTime>{
-        let tail = " 00:00:00";
-        for mut item in seeds{
-            item.push_str(tail);
-            match  NaiveDateTime::parse_from_str(item.as_str(), "%Y%m%d %H:%M:%S"){
-                Err(_) => {}
-                Ok(datetime) =>{
-                    if self.validate(datetime){
-                        return Some(datetime)
-                    }
-                }
+    // 年月日数值合理但日期不存在时记为非法日期并继续尝试后续窗口
+    fn reload_datetime_with_text_format(&self, seeds: Vec<String>) -> (Option<NaiveDateTime>, DateValidity){
+        let mut validity = DateValidity::Unrecognized;
+        for item in seeds{
+            let (year, month, day) = match (item.get(0..4), item.get(4..6), item.get(6..8)){
+                (Some(y), Some(m), Some(d)) => (y, m, d),
+                _ => continue
+            };
+            let (year, month, day): (i32, u32, u32) = match (year.parse(), month.parse(), day.parse()){
+                (Ok(y), Ok(m), Ok(d)) => (y, m, d),
+                _ => continue
+            };
+            if !self.validate(year){
+                continue
+            }
+            let (date, status) = create_date(year, month, day, self.param.lenient);
+            if let Some(di) = date{
+                let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+                return (Some(NaiveDateTime::new(di, time)), status)
+            }
+            if status == DateValidity::Invalid{
+                validity = status;
             }
         }
-        return None
+        (None, validity)
     }
 
     /// 严格模式和限制模式的校验
-    /// 校验日期数值合法性
-    fn validate(&self, datetime: NaiveDateTime) -> bool{
+    /// 校验年份范围｜月份天数的校验在生成日期时完成
+    fn validate(&self, year: i32) -> bool{
         let mut threshold = MAX_NUMBER_YEAR;
         if self.param.strict{
             threshold = create_datetime(false, false).year();
         }
-        if datetime.year() < MIN_NUMBER_YEAR || datetime.year() > threshold{
-            return false
-        }
-        if datetime.month() > MAX_NUMBER_MONTH{
-            return false
-        }
-        if  datetime.day() > MAX_NUMBER_DAY{
-            return false
-        }
-        if datetime.hour() > MAX_NUMBER_HOURS{
-            return false
-        }
-        if datetime.minute() > MAX_NUMBER_MINUTES{
-            return false
-        }
-        if datetime.second() > MAX_NUMBER_SECONDS{
+        if year < MIN_NUMBER_YEAR || year > threshold{
             return false
         }
         return true
     }
 
     fn eliminate(&self, text: &str) -> String{
-        let mut item = eliminate_no_break_space(text);
+        let mut item = unitize_digit(text);
+        item = eliminate_no_break_space(item.as_str());
         item = eliminate_symbol_safe(item.as_str());
         item = eliminate_no_break_space(item.as_str());
         return item.trim().to_string()
@@ -163,6 +158,7 @@ impl ParseSeries{
     fn eliminate_noise_series(&self, section:Vec<String>) -> Vec<String>{
         let mut data: Vec<String> = Vec::new();
         let mut items = Vec::new();
+        let lexicon = get_lexicon(&self.param);
         for text in section{
             if is_number(text.as_str()){
                 items.push(text.to_string());
@@ -171,7 +167,7 @@ impl ParseSeries{
             let mut value:&str = "";
             let mut types = DateType::NONE;
             let text_for_search = eliminate_symbol_normal(text.as_str());
-            if let Some(tv) = unitize_month_name(text_for_search.as_str()){
+            if let Some(tv) = unitize_month_name(text_for_search.as_str(), &lexicon){
                 value = tv;
                 types = DateType::MONTH;
             }
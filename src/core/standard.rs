@@ -0,0 +1,374 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc, Weekday};
+use regex::Captures;
+use crate::bind::{DateValidity, Lexicon, ResultStandard};
+use crate::core::corpus::{search_with_iso8601_pattern, search_with_rfc_pattern, search_with_log_pattern, unitize_zone_rfc, unitize_month_abbr, unitize_month_name};
+use crate::utils::create_date;
+
+const MAX_NUMBER_HOUR: u32 = 24;  // ISO 8601 允许 24:00 表示当天结束
+const NANOS_PER_SECOND: f64 = 1_000_000_000.0;
+const MAX_NUMBER_YEAR_FUTURE: i32 = 50;  // RFC 7231 两位年份超出未来 50 年时视为上个世纪
+const MAX_SECONDS_OFFSET: i32 = 14 * 3600;  // 现行时区的偏移量在 ±14:00 之内
+
+
+/// ISO 8601 快速解析
+/// 支持日历日期、周日期、序数日期、基本格式与扩展格式、精度缩减的时间、小写 t 或空格分隔以及 Z ±hh ±hh:mm ±hhmm 偏移量
+/// 不符合 ISO 8601 的文本返回 None 交由通用流程处理
+pub fn parse_iso8601(text: &str, lenient: bool) -> Option<ResultStandard>{
+    search_iso8601(text, lenient, true)
+}
+
+/// 通用解析前的快速解析｜没有连字符的序数日期需要 T 时间部分 避免将任意七位数字视为日期 例如 2023278T142030Z
+pub fn parse_iso8601_fast(text: &str, lenient: bool) -> Option<ResultStandard>{
+    search_iso8601(text, lenient, false)
+}
+
+/// explicit 调用方明确要求 ISO 8601 此时接受 2023278 这类基本格式的序数日期
+fn search_iso8601(text: &str, lenient: bool, explicit: bool) -> Option<ResultStandard>{
+    let text = text.trim();
+    for name in ["calendar", "week", "ordinal"]{
+        let caps = match search_with_iso8601_pattern(name).and_then(|pattern| pattern.captures(text)){
+            Some(v) => v,
+            None => continue
+        };
+        if name == "ordinal" && !explicit && text.get(4..5) != Some("-"){
+            let separator = caps.name("separator").map(|v| v.as_str()).unwrap_or("");
+            if !separator.eq_ignore_ascii_case("t"){
+                return None
+            }
+        }
+        // 空格分隔时至少需要时和分 避免将 2023-10-05 14 这类文本误判为时间
+        if let Some(separator) = caps.name("separator"){
+            if separator.as_str().trim().is_empty() && caps.name("minute").is_none(){
+                return None
+            }
+        }
+        let mut data = ResultStandard{offset: parse_offset_iso8601(&caps), ..Default::default()};
+        // 偏移量超出 ±14:00 或分钟超过 59 时为非法时间 例如 +25:00
+        if caps.name("offset").is_some() && data.offset.is_none(){
+            data.validity = DateValidity::Invalid;
+            return Some(data)
+        }
+        let (date, validity) = create_date_iso8601(name, &caps, lenient)?;
+        let date = match date{
+            Some(v) => v,
+            None => {
+                data.validity = validity;
+                return Some(data)
+            }
+        };
+        match parse_time_iso8601(&caps){
+            Some((time, overflow)) => {
+                data.time = NaiveDateTime::new(date, time) + Duration::days(overflow as i64);
+                data.validity = validity;
+            }
+            None => {
+                data.validity = DateValidity::Invalid;
+            }
+        }
+        return Some(data)
+    }
+    None
+}
+
+/// ISO 8601 日期部分
+fn create_date_iso8601(name: &str, caps: &Captures, lenient: bool) -> Option<(Option<NaiveDate>, DateValidity)>{
+    let number = |key: &str| caps.name(key).and_then(|v| v.as_str().parse::<u32>().ok());
+    let year = number("year").or(number("year_basic"))? as i32;
+    match name{
+        "calendar" => {
+            let month = number("month").or(number("month_basic"))?;
+            let day = number("day").or(number("day_basic"))?;
+            return Some(create_date(year, month, day, lenient))
+        }
+        "week" => {
+            let weekday = match number("weekday").unwrap_or(1){
+                1 => Weekday::Mon, 2 => Weekday::Tue, 3 => Weekday::Wed, 4 => Weekday::Thu,
+                5 => Weekday::Fri, 6 => Weekday::Sat, _ => Weekday::Sun
+            };
+            return match NaiveDate::from_isoywd_opt(year, number("week")?, weekday){
+                Some(v) => Some((Some(v), DateValidity::Valid)),
+                None => Some((None, DateValidity::Invalid))
+            }
+        }
+        "ordinal" => {
+            return match NaiveDate::from_yo_opt(year, number("ordinal")?){
+                Some(v) => Some((Some(v), DateValidity::Valid)),
+                None => Some((None, DateValidity::Invalid))
+            }
+        }
+        _ => {}
+    }
+    None
+}
+
+/// ISO 8601 时间部分｜小数作用于最后一个时间单位 例如 T14.5 -> 14:30
+/// 返回 (时间, 是否为 24:00 而需顺延一天)
+fn parse_time_iso8601(caps: &Captures) -> Option<(NaiveTime, bool)>{
+    let number = |key: &str| caps.name(key).and_then(|v| v.as_str().parse::<u32>().ok());
+    let hour = match number("hour"){
+        Some(v) => v,
+        None => return Some((NaiveTime::MIN, false))
+    };
+    let minute = number("minute");
+    let second = number("second");
+    let fraction = match caps.name("fraction"){
+        Some(v) => ("0.".to_string() + v.as_str()).parse::<f64>().ok()?,
+        None => 0.0
+    };
+    if hour > MAX_NUMBER_HOUR || minute.unwrap_or(0) > 59 || second.unwrap_or(0) > 60{
+        return None
+    }
+    let unit = match (minute, second){
+        (None, _) => 3600.0,
+        (Some(_), None) => 60.0,
+        _ => 1.0
+    };
+    let total = fraction * unit;
+    let seconds = hour * 3600 + minute.unwrap_or(0) * 60 + second.unwrap_or(0).min(59) + total.trunc() as u32;
+    let nanos = (total.fract() * NANOS_PER_SECOND).round() as u32;
+    if hour == MAX_NUMBER_HOUR{
+        if seconds != MAX_NUMBER_HOUR * 3600 || nanos != 0{
+            return None
+        }
+        return Some((NaiveTime::MIN, true))
+    }
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos.min(999_999_999))?;
+    Some((time, false))
+}
+
+/// ISO 8601 偏移量(秒)｜超出范围时为 None
+fn parse_offset_iso8601(caps: &Captures) -> Option<i32>{
+    let text = caps.name("offset")?.as_str();
+    if text.eq_ignore_ascii_case("z"){
+        return Some(0)
+    }
+    let sign = if text.starts_with('+') {1} else {-1};
+    let digits: String = text.chars().filter(|ch| ch.is_ascii_digit()).collect();
+    let hours: i32 = digits.get(..2)?.parse().ok()?;
+    let minutes: i32 = digits.get(2..).filter(|v| !v.is_empty()).map(|v| v.parse().unwrap_or(0)).unwrap_or(0);
+    create_offset(sign, hours, minutes)
+}
+
+/// 偏移量(秒)｜分钟超过 59 或超出 ±14:00 时为 None
+fn create_offset(sign: i32, hours: i32, minutes: i32) -> Option<i32>{
+    let seconds = hours * 3600 + minutes * 60;
+    if minutes > 59 || seconds > MAX_SECONDS_OFFSET{
+        return None
+    }
+    Some(sign * seconds)
+}
+
+/// 邮件和 HTTP 日期快速解析
+/// RFC 2822/5322 和 RFC 7231 IMF-fixdate 例如 Thu, 05 Oct 2023 14:20:30 +0800
+/// RFC 850 例如 Thursday, 05-Oct-23 14:20:30 GMT
+/// asctime 例如 Thu Oct  5 14:20:30 2023｜HTTP 规定为 GMT
+/// 星期仅作校验参考 与日期不符时以日期为准
+/// 可以带有前缀和后缀 例如 Date: Thu, 05 Oct 2023 14:20:30 +0800｜紧跟偏移量或时间的不属于邮件日期 例如 GMT+8
+pub fn parse_rfc(text: &str, lenient: bool) -> Option<ResultStandard>{
+    let mut text = text.trim().to_string();
+    // 注释替换为等长的空白 保持位置不变
+    if let Some(pattern) = search_with_rfc_pattern("comment"){
+        text = pattern.replace_all(&text, |caps: &Captures| " ".repeat(caps[0].len())).to_string();
+    }
+    for name in ["rfc2822", "rfc850", "asctime"]{
+        let caps = match search_with_rfc_pattern(name).and_then(|pattern| pattern.captures(&text)){
+            Some(v) => v,
+            None => continue
+        };
+        let whole = caps.get(0)?;
+        if text[whole.end()..].starts_with(['+', '-', ':', '.']){
+            return None
+        }
+        let number = |key: &str| caps.name(key).and_then(|v| v.as_str().parse::<u32>().ok());
+        let offset = match caps.name("zone").map(|v| v.as_str()){
+            Some(zone) => match parse_zone_rfc(zone){
+                Some(v) => v,
+                // 超出范围的数字偏移量为非法时间 例如 +2500｜未知的时区名称交由通用流程
+                None if zone.starts_with(['+', '-']) => return Some(ResultStandard{validity: DateValidity::Invalid, ..Default::default()}),
+                None => return None
+            },
+            None => 0
+        };
+        let year = caps.name("year")?.as_str();
+        let year = expand_year_rfc(name, year.len(), year.parse().ok()?);
+        let month = unitize_month_abbr(&caps["month"])?;
+        let mut data = ResultStandard{offset: Some(offset), span: Some((whole.start(), whole.end())), ..Default::default()};
+        let (date, validity) = create_date(year, month, number("day")?, lenient);
+        data.validity = validity;
+        let time = NaiveTime::from_hms_opt(number("hour")?, number("minute")?, number("second").unwrap_or(0));
+        match (date, time){
+            (Some(date), Some(time)) => data.time = NaiveDateTime::new(date, time),
+            (Some(_), None) => data.validity = DateValidity::Invalid,
+            _ => {}
+        }
+        return Some(data)
+    }
+    None
+}
+
+/// 邮件日期的时区｜+0800 -0500 或者 GMT EST 等名称
+fn parse_zone_rfc(text: &str) -> Option<i32>{
+    if text.starts_with('+') || text.starts_with('-'){
+        let sign = if text.starts_with('+') {1} else {-1};
+        let hours: i32 = text.get(1..3)?.parse().ok()?;
+        let minutes: i32 = text.get(3..5)?.parse().ok()?;
+        return create_offset(sign, hours, minutes)
+    }
+    unitize_zone_rfc(text)
+}
+
+/// 补全两位或三位年份
+/// RFC 5322 两位年份 00-49 为 20xx 50-99 为 19xx 三位年份加上 1900
+/// RFC 850 两位年份超出未来 50 年时视为上个世纪
+fn expand_year_rfc(name: &str, length: usize, year: i32) -> i32{
+    match (name, length){
+        ("rfc850", 2) => {
+            let year = year + 2000;
+            if year - Utc::now().year() > MAX_NUMBER_YEAR_FUTURE{
+                return year - 100
+            }
+            year
+        }
+        (_, 2) if year < 50 => year + 2000,
+        (_, 2) | (_, 3) => year + 1900,
+        _ => year
+    }
+}
+
+/// 日志时间快速解析
+/// 日志行中除时间以外的内容会干扰通用流程 例如 CLF 中以冒号连接的日期和时间、Log4j 中以逗号分隔的毫秒
+/// syslog 不带年份 取当前年份 得到的时间晚于明天时视为去年的日志｜主机名按词库排除上下午和时区名称
+pub fn parse_log(text: &str, lenient: bool, lexicon: &Lexicon) -> Option<ResultStandard>{
+    let text = text.trim();
+    if let Some(caps) = search_with_log_pattern("iso").and_then(|pattern| pattern.captures(text)){
+        let hit = caps.name("time")?;
+        let mut data = parse_iso8601(hit.as_str(), lenient)?;
+        data.span = Some((hit.start(), hit.end()));
+        return Some(data)
+    }
+    for name in ["clf", "syslog", "java"]{
+        let caps = match search_with_log_pattern(name).and_then(|pattern| pattern.captures(text)){
+            Some(v) => v,
+            None => continue
+        };
+        let number = |key: &str| caps.name(key).and_then(|v| v.as_str().parse::<u32>().ok());
+        let (month, offset) = match name{
+            "clf" => (unitize_month_abbr(&caps["month"])?, Some(parse_zone_rfc(caps.name("zone").map(|v| v.as_str()).unwrap_or("+0000"))?)),
+            "syslog" => {
+                // 主机名为年份、上下午、时区或偏移量 或者其后跟随年份时是普通的日期文本
+                // 例如 Oct 5 14:20:30 2023、Oct 5 10:20:30 pm 2023、Oct 5 10:20:30 CST 2023
+                if !is_host_syslog(&caps["host"], lexicon) || has_year_syslog(&text[caps.get(0)?.end()..]){
+                    return None
+                }
+                (unitize_month_abbr(&caps["month"])?, None)
+            }
+            _ => {
+                // 没有逗号毫秒、日志级别或者方括号时交由通用流程 例如 2023-10-05 10:03:37 pm
+                let separator = caps.name("separator").map(|v| v.as_str());
+                if separator != Some(",") && caps.name("tail").is_none(){
+                    return None
+                }
+                (number("month")?, None)
+            }
+        };
+        let year = match number("year"){
+            Some(v) => v as i32,
+            None => infer_year_syslog(month, number("day")?)
+        };
+        let nanos = match caps.name("fraction"){
+            Some(v) => (format!("{:0<9}", v.as_str())).parse().ok()?,
+            None => 0
+        };
+        // 日志行中时间所在的范围｜clf 为整个匹配 syslog 和 java 从月份或年份到秒
+        let start = caps.name(if name == "syslog" {"month"} else {"year"}).filter(|_| name != "clf").unwrap_or(caps.get(0)?).start();
+        let end = caps.name("fraction").or(caps.name("second")).filter(|_| name != "clf").unwrap_or(caps.get(0)?).end();
+        let mut data = ResultStandard{offset, span: Some((start, end)), ..Default::default()};
+        let (date, validity) = create_date(year, month, number("day")?, lenient);
+        data.validity = validity;
+        let time = NaiveTime::from_hms_nano_opt(number("hour")?, number("minute")?, number("second")?, nanos);
+        match (date, time){
+            (Some(date), Some(time)) => data.time = NaiveDateTime::new(date, time),
+            (Some(_), None) => data.validity = DateValidity::Invalid,
+            _ => {}
+        }
+        return Some(data)
+    }
+    None
+}
+
+/// syslog 主机名｜不能是年份、上下午、时区名称或偏移量
+fn is_host_syslog(host: &str, lexicon: &Lexicon) -> bool{
+    let key = host.to_lowercase();
+    let digit = |v: &str| !v.is_empty() && v.chars().all(|ch| ch.is_ascii_digit() || ch == ':');
+    if key.len() == 4 && digit(&key){
+        return false
+    }
+    if key.starts_with(['+', '-']) && digit(&key[1..]){
+        return false
+    }
+    !(lexicon.meridian.contains_key(&key) || lexicon.timezone.contains_key(&key))
+}
+
+/// 主机名之后紧跟年份｜例如 Oct 5 10:20:30 myhost 2023
+fn has_year_syslog(text: &str) -> bool{
+    let word = text.split_whitespace().next().unwrap_or("").trim_matches(|ch: char| ch.is_ascii_punctuation());
+    word.len() == 4 && word.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// syslog 年份推断｜跨年时 12 月的日志在 1 月读取 应属于去年
+fn infer_year_syslog(month: u32, day: u32) -> i32{
+    let now = Local::now().naive_local();
+    let year = now.year();
+    if let Some(date) = NaiveDate::from_ymd_opt(year, month, day){
+        if date > now.date() + Duration::days(1){
+            return year - 1
+        }
+    }
+    year
+}
+
+/// 按格式提示解析｜chrono strftime 格式 例如 %d/%m/%Y %H:%M
+/// 依次尝试带偏移量的时间、不带偏移量的时间和仅有日期的格式
+/// 原文无法匹配时将本地化的月份名称换成英文后再试一次 例如 5 octobre 2023 -> 5 october 2023 以匹配 %d %B %Y
+/// 返回 (匹配的格式, 解析结果)
+pub fn parse_format(text: &str, formats: &[String], lexicon: &Lexicon) -> Option<(String, ResultStandard)>{
+    let text = text.trim();
+    let localized = unitize_month_words(text, lexicon);
+    for format in formats{
+        for candidate in [text, localized.as_str()]{
+            if let Some(data) = parse_with_format(candidate, format){
+                return Some((format.clone(), data))
+            }
+        }
+    }
+    None
+}
+
+fn parse_with_format(text: &str, format: &str) -> Option<ResultStandard>{
+    if let Ok(v) = DateTime::parse_from_str(text, format){
+        return Some(ResultStandard{time: v.naive_local(), validity: DateValidity::Valid, offset: Some(v.offset().fix().local_minus_utc()), span: None})
+    }
+    if let Ok(v) = NaiveDateTime::parse_from_str(text, format){
+        return Some(ResultStandard{time: v, validity: DateValidity::Valid, offset: None, span: None})
+    }
+    if let Ok(v) = NaiveDate::parse_from_str(text, format){
+        return Some(ResultStandard{time: NaiveDateTime::new(v, NaiveTime::MIN), validity: DateValidity::Valid, offset: None, span: None})
+    }
+    None
+}
+
+/// 将以空格分隔的本地化月份名称换成英文｜保留末尾的逗号或句点
+fn unitize_month_words(text: &str, lexicon: &Lexicon) -> String{
+    let words: Vec<String> = text.split(' ').map(|word| {
+        let lower = word.to_lowercase();
+        if let Some(v) = unitize_month_name(&lower, lexicon){
+            return v.to_string()
+        }
+        let trimmed = lower.trim_end_matches([',', '.']);
+        match unitize_month_name(trimmed, lexicon){
+            Some(v) => v.to_string() + &lower[trimmed.len()..],
+            None => word.to_string()
+        }
+    }).collect();
+    words.join(" ")
+}
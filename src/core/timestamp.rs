@@ -1,8 +1,8 @@
 use chrono::{Duration, NaiveDateTime};
-use crate::bind::{Param, Result, ParseTimestamp};
+use crate::bind::{Param, Result, ParseTimestamp, DateValidity};
 use crate::core::interfaces::{Parse};
-use crate::utils::{is_timestamp, create_timestamp};
-use crate::core::corpus::{get_offset_local_utc, unitize_timezone_with_text};
+use crate::utils::{is_timestamp, create_timestamp, unitize_digit};
+use crate::core::corpus::{get_lexicon, get_offset_local_utc, unitize_timezone_with_text};
 
 const LEN_TIMESTAMP_SECOND: usize = 10;
 const LEN_TIMESTAMP_MILLISECOND: usize = 13;
@@ -27,7 +27,7 @@ impl ParseTimestamp{
     }
 
     fn set_offset_with_timezone(&mut self){
-        if let Some(offset) = unitize_timezone_with_text(self.param.timezone.as_str()){
+        if let Some(offset) = unitize_timezone_with_text(self.param.timezone.as_str(), &get_lexicon(&self.param)){
             self.offset = *offset;
         }else{
             self.param.timezone = "".to_string();
@@ -36,6 +36,8 @@ impl ParseTimestamp{
 
     fn work(&self, text: &str) -> Result{
         let mut item = Result{timezone: self.param.timezone.clone(), method: String::from("timestamp"), ..Default::default()};
+        let text = unitize_digit(text);
+        let text = text.as_str();
         if !is_timestamp(text){
             return item
         }
@@ -70,6 +72,9 @@ impl ParseTimestamp{
     }
 
     fn assemble(&self, item: &mut Result){
+        if item.status{
+            item.validity = DateValidity::Valid;
+        }
         self.attach_timezone(item);
     }
 
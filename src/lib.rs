@@ -2,9 +2,16 @@ use crate::core::interfaces::Parse;
 pub mod core;
 pub mod bind;
 pub mod utils;
+#[cfg(test)]
+mod tests;
 
-use crate::bind::{Result, Param, ParseTimestamp, ParseSeries, ParseRelative, ParseAbsolute};
+use crate::bind::{Result, Param, ParseTimestamp, ParseSeries, ParseRelative, ParseAbsolute, Layout, ResultRange, ResultDuration, ResultRecurrence};
 use crate::core::parse_auto::parse_auto;
+use crate::core::layout;
+use crate::core::range;
+use crate::core::duration;
+use crate::core::recurrence;
+use crate::core::period;
 
 pub fn parse_timestamp(text: &str, options: Option<Param>) -> Result{
     let mut ins = ParseTimestamp::new(options);
@@ -32,4 +39,24 @@ pub fn parse_absolute(text: &str, options: Option<Param>) -> Result{
 
 pub fn parse(text: &str, options: Option<Param>) -> Result{
     return parse_auto(text, options)
+}
+
+pub fn infer_layout(samples: &[&str], options: Option<Param>) -> Option<Layout>{
+    layout::infer_layout(samples, options)
+}
+
+pub fn parse_range(text: &str, options: Option<Param>) -> ResultRange{
+    range::parse_range(text, options)
+}
+
+pub fn parse_duration(text: &str, options: Option<Param>) -> ResultDuration{
+    duration::parse_duration(text, options)
+}
+
+pub fn parse_recurrence(text: &str, options: Option<Param>) -> ResultRecurrence{
+    recurrence::parse_recurrence(text, options)
+}
+
+pub fn parse_period(text: &str, options: Option<Param>) -> ResultRange{
+    period::parse_period(text, options)
 }
\ No newline at end of file
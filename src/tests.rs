@@ -0,0 +1,473 @@
+use crate::{parse, parse_absolute, parse_range, parse_recurrence, parse_relative};
+use crate::bind::{DateValidity, EraBasedCalendar, Holiday, HolidayRule, Lexicon, Param};
+use crate::core::standard::parse_iso8601;
+
+
+/// parse 返回的合法性｜均未识别时保留 Invalid
+#[test]
+fn parse_auto_validity(){
+    let r = parse("2023-02-28", None);
+    assert!(r.status);
+    assert_eq!(r.validity, DateValidity::Valid);
+    let r = parse("2023-02-30", None);
+    assert!(!r.status);
+    assert_eq!(r.validity, DateValidity::Invalid);
+    let r = parse("2023-02-30", Some(Param{lenient: true, ..Default::default()}));
+    assert!(r.status);
+    assert_eq!(r.validity, DateValidity::Rolled);
+    assert_eq!(r.time.to_string(), "2023-03-02 00:00:00");
+    assert_eq!(parse("hello", None).validity, DateValidity::Unrecognized);
+}
+
+/// 一个半小时前 -> 90分钟前
+#[test]
+fn parse_relative_half_hours(){
+    for sample in ["一个半小时前", "1个半小时前"]{
+        let expected = chrono::Local::now().naive_local() - chrono::Duration::minutes(90);
+        let r = parse_relative(sample, None);
+        assert!(r.status, "{}", sample);
+        assert!((r.time - expected).num_seconds().abs() < 5, "{}", sample);
+    }
+}
+
+/// 点 分 刻 个 只在时间语境中转换为数字
+#[test]
+fn unitize_numeral_context(){
+    for (sample, expected) in [("有一点冷", "有一点冷"), ("三点水", "三点水"), ("下午3点 十分重要", "下午3点 十分重要"),
+                               ("一个人", "一个人"), ("下午三点十分", "下午3点10分"), ("三点钟", "3点钟"), ("两个小时前", "2个小时前")]{
+        assert_eq!(crate::utils::unitize_numeral(sample), expected, "{}", sample);
+    }
+    let r = parse_absolute("2023年10月5日 下午3点 十分重要", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 15:00:00");
+    let r = parse_absolute("2023年10月5日下午三点十分", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 15:10:00");
+}
+
+/// 非 ASCII 的十进制数字统一为 ASCII
+#[test]
+fn unitize_digit_systems(){
+    for sample in ["٢٠٢٣/١٠/٠٥", "۲۰۲۳-۱۰-۰۵ ۱۴:۲۰", "२०२३-१०-०५", "２０２３年１０月５日"]{
+        let r = parse_absolute(sample, None);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.time.format("%Y-%m-%d").to_string(), "2023-10-05", "{}", sample);
+    }
+    assert!(!parse_absolute("version ٣٫٢", None).status);
+    assert!(!parse_absolute("٢٠٢٣/١٣/٤٥", None).status);
+}
+
+/// 泰国佛历｜完整月份名称、泰文数字和纪元标志 ค.ศ. 为公历
+#[test]
+fn search_era_thai(){
+    let samples = [
+        ("๒๖ ก.ค. ๒๕๖๖", EraBasedCalendar::Thai),
+        ("26 กรกฎาคม 2566", EraBasedCalendar::Thai),
+        ("พ.ศ. 2566 ก.ค. 26", EraBasedCalendar::Buddhist),
+        ("ค.ศ. 2023 ก.ค. 26", EraBasedCalendar::Gregorian),
+    ];
+    for (sample, calendar) in samples{
+        let r = parse_absolute(sample, None);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.time.to_string(), "2023-07-26 00:00:00", "{}", sample);
+        assert_eq!(r.calendar, calendar, "{}", sample);
+    }
+    // 公历年份不减去 543
+    let r = parse_absolute("26 ก.ค. 2023", None);
+    assert_eq!(r.time.to_string(), "2023-07-26 00:00:00");
+    assert!(!parse_absolute("กรกฎาคม", None).status);
+}
+
+/// 日本年号的罗马字缩写不能与版本号混淆 也不能覆盖已有的公历日期
+#[test]
+fn search_era_japan_abbr(){
+    let r = parse_absolute("Version r2.1.3 released on 2023-10-05", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(r.calendar, crate::bind::EraBasedCalendar::Non);
+    let r = parse_absolute("Version r2.1.3 released", None);
+    assert!(!r.status);
+    let r = parse_absolute("R5.10.5", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    let r = parse_absolute("更新日 R5.10.5", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+}
+
+/// 民国纪年｜带纪元标志时始终换算 三位数年份仅在民国纪年模式下换算
+#[test]
+fn parse_minguo(){
+    for sample in ["民國112年10月5日", "民国112年10月5日"]{
+        let r = parse_absolute(sample, None);
+        assert_eq!(r.time.to_string(), "2023-10-05 00:00:00", "{}", sample);
+        assert_eq!(r.calendar, EraBasedCalendar::Minguo, "{}", sample);
+    }
+    assert!(!parse_absolute("112/10/05", None).status);
+    let r = parse_absolute("112/10/05", Some(Param{minguo: true, ..Default::default()}));
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert!(!parse_absolute("民國112年2月30日", None).status);
+    assert!(crate::core::corpus::is_minguo_domain("https://www.gov.tw/news"));
+    assert!(!crate::core::corpus::is_minguo_domain("https://www.gov.cn/news"));
+}
+
+/// 伊斯兰历超出当月天数时 宽松模式下顺延到下个月
+#[test]
+fn unitize_era_hijri_lenient(){
+    let r = parse_absolute("30 صفر 1445", None);
+    assert!(!r.status);
+    assert_eq!(r.validity, DateValidity::Invalid);
+    let r = parse_absolute("30 صفر 1445", Some(Param{lenient: true, ..Default::default()}));
+    assert_eq!(r.time.to_string(), "2023-09-16 00:00:00");
+    assert_eq!(r.validity, DateValidity::Rolled);
+    assert_eq!(r.calendar, crate::bind::EraBasedCalendar::Hijri);
+}
+
+/// 伊朗历月份名称仅在伊朗历的上下文中转换
+#[test]
+fn unitize_text_jalali_month(){
+    for sample in ["13 مهر 1402", "۱۳ مهر ۱۴۰۲", "13 مهر 1402 ه.ش"]{
+        let r = parse_absolute(sample, None);
+        assert_eq!(r.time.to_string(), "2023-10-05 00:00:00", "{}", sample);
+        assert_eq!(r.calendar, crate::bind::EraBasedCalendar::Jalali, "{}", sample);
+    }
+    let r = parse_absolute("تیر 2023/10/05", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(r.calendar, crate::bind::EraBasedCalendar::Non);
+}
+
+/// 农历数据覆盖 1900-2100 年 超出范围的年份无法识别而不是日期无效
+#[test]
+fn unitize_era_lunar_range(){
+    for sample in ["农历1850年八月十五", "农历2150年八月十五"]{
+        let r = parse_absolute(sample, None);
+        assert!(!r.status, "{}", sample);
+        assert_eq!(r.validity, DateValidity::Unrecognized, "{}", sample);
+    }
+    for sample in ["农历2023年八月十五", "农历二〇二三年八月十五"]{
+        let r = parse_absolute(sample, None);
+        assert_eq!(r.time.to_string(), "2023-09-29 00:00:00", "{}", sample);
+    }
+}
+
+/// 农历日期需要农历标志｜已有公历日期时不切换历法
+#[test]
+fn unitize_era_lunar_marker(){
+    let r = parse_absolute("2023年10月5日 十月初五", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(r.calendar, EraBasedCalendar::Non);
+    let r = parse_absolute("2023年10月5日 农历八月廿一", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(r.calendar, EraBasedCalendar::Non);
+    assert!(!parse_absolute("十月初五", None).status);
+    let r = parse_absolute("2023年农历八月十五", None);
+    assert_eq!(r.time.to_string(), "2023-09-29 00:00:00");
+    assert_eq!(r.calendar, EraBasedCalendar::Lunar);
+}
+
+/// 韩文日期｜년 월 일 시 분 以及钟点之前的 오전 오후
+#[test]
+fn parse_korean(){
+    let samples = [
+        ("2023년 10월 5일 오후 3시 20분", "2023-10-05 15:20:00"),
+        ("23.10.05 오후 3:20", "2023-10-05 15:20:00"),
+        ("2023년 10월 5일 오전 12시", "2023-10-05 00:00:00"),
+        ("2023년 10월 5일 오전 9시 5분 30초", "2023-10-05 09:05:30"),
+    ];
+    for (sample, expected) in samples{
+        let r = parse_absolute(sample, None);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.time.to_string(), expected, "{}", sample);
+    }
+    assert!(!parse_absolute("2023년 10월 32일", None).status);
+    assert!(!parse_absolute("안녕하세요", None).status);
+}
+
+/// 钟点之后的数字只在带有 分 或者紧跟钟点时视为分钟｜点赞 時間 不是时间
+#[test]
+fn unitize_hour_unit_minute(){
+    let r = parse_absolute("下午3点 2023-10-05", None);
+    assert_eq!(r.validity, DateValidity::Valid);
+    assert_eq!(r.time.to_string(), "2023-10-05 15:00:00");
+    let r = parse_absolute("2023-10-05 下午3点20", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 15:20:00");
+    let r = parse_absolute("2023-10-05 下午3点 20分", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 15:20:00");
+    let r = parse_absolute("2023年10月5日 阅读 3点赞", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    let r = parse_absolute("2023年10月5日 共3時間", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    let r = parse_absolute("2023年10月5日 用了3个钟头", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+}
+
+/// 时段调整钟点｜确切时刻不吞掉日期中的数字
+#[test]
+fn unitize_day_period_hour(){
+    let samples = [
+        ("2023-10-05 凌晨2点", "2023-10-05 02:00:00"),
+        ("2023-10-05 傍晚6点", "2023-10-05 18:00:00"),
+        ("2023-10-05 中午12点", "2023-10-05 12:00:00"),
+        ("2023-10-05 noon", "2023-10-05 12:00:00"),
+        ("2023-10-05 midnight", "2023-10-05 00:00:00"),
+        ("2023-10-05 12 noon", "2023-10-05 12:00:00"),
+        ("2023年10月5日晚上", "2023-10-05 20:00:00"),
+    ];
+    for (sample, expected) in samples{
+        let r = parse_absolute(sample, None);
+        assert!(r.status, "{}", sample);
+        assert_eq!(r.time.to_string(), expected, "{}", sample);
+    }
+    // 单字时段不作为缺省钟点 Oct 5 中的 5 不是钟点
+    assert_eq!(parse_absolute("2023年10月5日 早", None).time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(parse_absolute("Oct 5 2023 noon", None).time.to_string(), "2023-10-05 12:00:00");
+}
+
+/// ISO 8601 的日历日期、周日期、序数日期、基本格式与扩展格式、精度缩减的时间和偏移量
+#[test]
+fn parse_iso8601_conformance(){
+    // (样本, 期望的 UTC 时间｜无时区时为原始时间, 期望的时区)
+    let samples = vec![
+        ("2023-10-05", "2023-10-05 00:00:00", ""),
+        ("20231005", "2023-10-05 00:00:00", ""),
+        ("2023-10-05T14:20:30Z", "2023-10-05 14:20:30", "utc"),
+        ("2023-10-05t14:20:30z", "2023-10-05 14:20:30", "utc"),
+        ("2023-10-05 14:20:30Z", "2023-10-05 14:20:30", "utc"),
+        ("2023-10-05T14:20:30.123456Z", "2023-10-05 14:20:30.123456", "utc"),
+        ("2023-10-05T14:20:30,5Z", "2023-10-05 14:20:30.500", "utc"),
+        ("2023-10-05T14:20:30+08:00", "2023-10-05 06:20:30", "other"),
+        ("2023-10-05T14:20:30+0800", "2023-10-05 06:20:30", "other"),
+        ("2023-10-05T14:20:30+08", "2023-10-05 06:20:30", "other"),
+        ("2023-10-05T14:20:30-05:30", "2023-10-05 19:50:30", "other"),
+        ("2023-10-05T14:20:30+00:00", "2023-10-05 14:20:30", "utc"),
+        ("20231005T142030Z", "2023-10-05 14:20:30", "utc"),
+        ("20231005T142030+0800", "2023-10-05 06:20:30", "other"),
+        ("2023-10-05T14:20", "2023-10-05 14:20:00", ""),
+        ("2023-10-05 14:20", "2023-10-05 14:20:00", ""),
+        ("2023-10-05T14", "2023-10-05 14:00:00", ""),
+        ("2023-10-05T14.5", "2023-10-05 14:30:00", ""),
+        ("2023-10-05T14:20.5", "2023-10-05 14:20:30", ""),
+        ("2023-10-05T24:00:00", "2023-10-06 00:00:00", ""),
+        ("2023-W40-4", "2023-10-05 00:00:00", ""),
+        ("2023W404", "2023-10-05 00:00:00", ""),
+        ("2023-W40", "2023-10-02 00:00:00", ""),
+        ("2023-W40-4T14:20:30Z", "2023-10-05 14:20:30", "utc"),
+        ("2020-W53-5", "2021-01-01 00:00:00", ""),
+        ("2023-278", "2023-10-05 00:00:00", ""),
+        ("2023278T142030Z", "2023-10-05 14:20:30", "utc"),
+        ("2024-366", "2024-12-31 00:00:00", ""),
+        ("2023-278T14:20:30Z", "2023-10-05 14:20:30", "utc"),
+    ];
+    for (sample, expected, timezone) in samples{
+        let r = parse_absolute(sample, None);
+        let actual = if r.timezone.is_empty() {r.time} else {r.datetime.timezone.datetime};
+        assert!(r.status, "{}", sample);
+        assert_eq!(actual.to_string(), expected, "{}", sample);
+        assert_eq!(r.timezone, timezone, "{}", sample);
+    }
+    // 格式正确但日期不存在
+    for sample in ["2023-02-30", "2023-W53-1", "2023-366", "2023-10-05T25:00", "2023-10-05T24:30"]{
+        let r = parse_absolute(sample, Some(Param{strict: true, ..Default::default()}));
+        assert!(!r.status, "{}", sample);
+        assert_eq!(r.validity, DateValidity::Invalid, "{}", sample);
+    }
+}
+
+/// 没有连字符也没有时间部分的七位数字不是序数日期｜明确调用 ISO 8601 解析时除外
+#[test]
+fn parse_iso8601_ordinal_basic(){
+    let r = parse("2023278", None);
+    assert_ne!(r.time.to_string(), "2023-10-05 00:00:00");
+    let r = parse_absolute("1234567", None);
+    assert_eq!(r.validity, DateValidity::Unrecognized);
+    let r = parse_iso8601("2023278", false).unwrap();
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+}
+
+/// 偏移量超出 ±14:00 或分钟超过 59 时为非法时间
+#[test]
+fn parse_iso8601_offset_range(){
+    for sample in ["2023-10-05T14:20:30+25:00", "2023-10-05T14:20:30+08:60", "20231005T142030+2500", "2023-10-05T14:20:30-15"]{
+        let r = parse_absolute(sample, None);
+        assert!(!r.status, "{}", sample);
+        assert_eq!(r.validity, DateValidity::Invalid, "{}", sample);
+        assert!(!parse(sample, None).status, "{}", sample);
+    }
+    for (sample, offset) in [("2023-10-05T14:20:30+14:00", 14 * 3600), ("2023-10-05T14:20:30-12:00", -12 * 3600)]{
+        let r = parse_iso8601(sample, false).unwrap();
+        assert_eq!(r.offset, Some(offset), "{}", sample);
+    }
+}
+
+/// 带有邮件头前缀的 RFC 2822 日期保留偏移量
+#[test]
+fn parse_rfc_header_prefix(){
+    let samples = [
+        ("Date: Thu, 05 Oct 2023 14:20:30 +0800", "2023-10-05 06:20:30", (6, 37)),
+        ("Received: from x by y; Thu, 05 Oct 2023 14:20:30 -0500", "2023-10-05 19:20:30", (23, 54)),
+        ("Date: Thu, 05 Oct 2023 14:20:30 +0800 (CST)", "2023-10-05 06:20:30", (6, 37)),
+        ("Last-Modified: Thursday, 05-Oct-23 14:20:30 GMT", "2023-10-05 14:20:30", (15, 47)),
+    ];
+    for (sample, expected, span) in samples{
+        let r = parse_absolute(sample, Some(Param{span: true, ..Default::default()}));
+        assert!(r.status, "{}", sample);
+        assert_ne!(r.timezone, "", "{}", sample);
+        assert_eq!(r.datetime.timezone.datetime.to_string(), expected, "{}", sample);
+        assert_eq!(r.span, Some(span), "{}", sample);
+    }
+    let r = crate::core::standard::parse_rfc("Thu, 05 Oct 2023 14:20:30 GMT+8", false);
+    assert!(r.is_none());
+    // 超出 ±14:00 的数字偏移量为非法时间
+    assert!(!parse_absolute("Thu, 05 Oct 2023 14:20:30 +2500", None).status);
+}
+
+/// 月 日 时:分:秒 之后是上下午、时区或偏移量时不是 syslog 交由通用流程
+#[test]
+fn parse_log_syslog_host(){
+    let r = parse_absolute("Oct 5 10:20:30 pm 2023", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 22:20:30");
+    let r = parse_absolute("Oct 5 10:20:30 CST 2023", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 10:20:30");
+    assert_eq!(r.timezone, "cst");
+    let r = parse_absolute("Oct 05 14:20:30 +0800 2023", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 14:20:30");
+    // 真正的 syslog 行
+    let r = parse("Oct  5 14:20:30 myhost sshd[123]: Accepted publickey", None);
+    assert!(r.status);
+    assert_eq!(r.time.format("%m-%d %H:%M:%S").to_string(), "10-05 14:20:30");
+    assert_eq!(r.validity, DateValidity::Valid);
+}
+
+/// 结束时间只有时刻且早于起始时间时顺延一天｜带有日期时顺延一年
+#[test]
+fn parse_range_overnight(){
+    let r = parse_range("2023-10-05 22:00 - 02:00", None);
+    assert!(r.status);
+    assert_eq!(r.start.time.to_string(), "2023-10-05 22:00:00");
+    assert_eq!(r.end.time.to_string(), "2023-10-06 02:00:00");
+    let r = parse_range("2023年12月25日至1月3日", None);
+    assert!(r.status);
+    assert_eq!(r.end.time.to_string(), "2024-01-03 00:00:00");
+}
+
+/// 时:分[:秒] 形式的时长｜分和秒不超过 59
+#[test]
+fn parse_duration_clock(){
+    let r = crate::parse_duration("1:30:00", None);
+    assert!(r.status);
+    assert_eq!(r.seconds, 5400.0);
+    assert_eq!(crate::parse_duration("10:59", None).seconds, 10.0 * 3600.0 + 59.0 * 60.0);
+    for sample in ["10:70", "1:30:75", "1:60"]{
+        assert!(!crate::parse_duration(sample, None).status, "{}", sample);
+    }
+}
+
+/// 重复规则｜缩写的星期不是复数 月份之后的日期 每月最后一天 以及词库中的名称
+#[test]
+fn parse_recurrence_rules(){
+    assert!(!parse_recurrence("Tues at 9am", None).status);
+    assert_eq!(parse_recurrence("mondays", None).to_rrule(), "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO");
+    assert_eq!(parse_recurrence("every year on Feb 29", None).to_rrule(), "FREQ=YEARLY;INTERVAL=1;BYMONTHDAY=29;BYMONTH=2");
+    assert_eq!(parse_recurrence("每月最后一天", None).by_month_day, vec![-1]);
+    let mut lexicon = Lexicon::new();
+    lexicon.insert_weekday("lunday", "monday");
+    lexicon.insert_month("setiembre", "september");
+    let r = parse_recurrence("every lunday", Some(Param::new().with_lexicon(lexicon.clone())));
+    assert_eq!(r.to_rrule(), "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO");
+    let r = parse_recurrence("every setiembre 15", Some(Param::new().with_lexicon(lexicon)));
+    assert_eq!(r.to_rrule(), "FREQ=YEARLY;INTERVAL=1;BYMONTHDAY=15;BYMONTH=9");
+    assert!(parse_recurrence("every lunday", None).by_day.is_empty());
+}
+
+/// 财年起始月份超过 12 时不做解析
+#[test]
+fn parse_period_fiscal_month(){
+    let r = crate::parse_period("Q3 FY2023", Some(Param{fiscal: 10, ..Default::default()}));
+    assert!(r.status);
+    assert_eq!(r.start.time.to_string(), "2023-04-01 00:00:00");
+    let r = crate::parse_period("Q3 FY2023", Some(Param{fiscal: 13, ..Default::default()}));
+    assert!(!r.status);
+    let r = crate::parse_period("2023年第三季度", Some(Param{fiscal: 0, ..Default::default()}));
+    assert_eq!(r.start.time.to_string(), "2023-07-01 00:00:00");
+}
+
+/// 已有完整日期时节日不覆盖日期｜缺省年份且没有时刻的节日不是日期
+#[test]
+fn unitize_holiday_context(){
+    let r = parse_absolute("2023年10月5日 我们在国庆节见", None);
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+    assert_eq!(r.holiday, "国庆节");
+    for sample in ["国庆节快乐", "Version 2.0 released on Christmas"]{
+        let r = parse(sample, None);
+        assert!(!r.status, "{}", sample);
+    }
+    let r = parse_absolute("2023年国庆节", None);
+    assert_eq!(r.time.to_string(), "2023-10-01 00:00:00");
+    let r = parse_absolute("国庆节晚上8点", None);
+    assert!(r.status);
+    assert_eq!(r.time.format("%m-%d %H:%M").to_string(), "10-01 20:00");
+}
+
+/// 词库中任意一项有误时 extend_json 不修改词库
+#[test]
+fn lexicon_extend_json_atomic(){
+    let mut lexicon = crate::bind::Lexicon::new();
+    let r = lexicon.extend_json(r#"{"month": {"avgusta": "august"}, "timezone": {"xyz": "five"}}"#);
+    assert!(r.is_err());
+    assert!(!lexicon.month.contains_key("avgusta"));
+    assert!(lexicon.extend_json(r#"{"month": {"avgusta": "august"}, "timezone": {"xyz": 18000}}"#).is_ok());
+    assert_eq!(lexicon.month.get("avgusta").map(|v| v.as_str()), Some("august"));
+    assert_eq!(lexicon.timezone.get("xyz"), Some(&18000));
+}
+
+/// parse_duration 和 infer_layout 使用 Param.lexicon
+#[test]
+fn lexicon_duration_layout(){
+    let mut lexicon = crate::bind::Lexicon::new();
+    lexicon.insert_relative("horitas atras", "hour ago");
+    lexicon.insert_month("avgusta", "august");
+    let lexicon = std::sync::Arc::new(lexicon);
+    let options = || Some(Param{lexicon: Some(lexicon.clone()), ..Default::default()});
+    assert!(!crate::parse_duration("2 horitas", None).status);
+    let r = crate::parse_duration("2 horitas", options());
+    assert!(r.status);
+    assert_eq!(r.seconds, 7200.0);
+    let layout = crate::infer_layout(&["5 avgusta 2023", "25 avgusta 2023"], options()).unwrap();
+    assert_eq!(layout.format, "%d %B %Y");
+    assert_eq!(layout.parse("7 avgusta 2023", options()).time.to_string(), "2023-08-07 00:00:00");
+}
+
+/// 链式设置参数与结构体字面量等价
+#[test]
+fn param_builder(){
+    let param = Param::new().with_timezone("aest").with_strict(true).with_lenient(true).with_formats(&["%d/%m/%Y"]).with_fiscal(10);
+    assert_eq!(param.timezone, "aest");
+    assert!(param.strict && param.lenient && !param.minguo && !param.trace && !param.span);
+    assert_eq!(param.formats, vec!["%d/%m/%Y".to_string()]);
+    assert_eq!(param.fiscal, 10);
+    assert!(param.lexicon.is_none());
+    let r = parse_absolute("05/10/2023", Some(Param::new().with_formats(&["%d/%m/%Y"])));
+    assert_eq!(r.time.to_string(), "2023-10-05 00:00:00");
+}
+
+/// 补充的节日只在传入的词库中生效｜不影响其他解析
+#[test]
+fn lexicon_holiday_scope(){
+    let mut lexicon = Lexicon::new();
+    lexicon.insert_holiday("双十一", Holiday{rule: HolidayRule::Fixed(11, 11), offset: 0});
+    let r = parse_absolute("2023年双十一", Some(Param::new().with_lexicon(lexicon)));
+    assert_eq!(r.time.to_string(), "2023-11-11 00:00:00");
+    assert_eq!(r.holiday, "双十一");
+    let r = parse_absolute("2023年双十一", None);
+    assert!(r.holiday.is_empty());
+    let mut lexicon = Lexicon::new();
+    assert!(lexicon.extend_json(r#"{"holiday": {"双十二": "fixed 12 12", "x": "monthly"}}"#).is_err());
+    assert!(!lexicon.holiday.contains_key("双十二"));
+}
+
+/// syslog 主机名按传入的词库排除时区名称
+#[test]
+fn parse_log_syslog_lexicon(){
+    let mut lexicon = Lexicon::new();
+    lexicon.insert_timezone("zzt", 3600);
+    let r = parse_absolute("Oct 5 10:20:30 zzt", Some(Param::new().with_lexicon(lexicon)));
+    assert_eq!(r.timezone, "zzt");
+    let r = parse_absolute("Oct 5 10:20:30 zzt", None);
+    assert!(r.status);
+    assert_eq!(r.timezone, "");
+}
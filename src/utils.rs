@@ -1,11 +1,16 @@
 use chrono::prelude::*;
+use chrono::Duration;
 use crate::core::corpus::{replace_with_pattern, unitize_date_text, is_thai_month, get_offset_local_utc,
-                          match_with_pattern, unitize_timezone_with_text};
-use crate::bind::{EraBasedCalendar, DateTimeLabel, Token};
+                          match_with_pattern, unitize_timezone_with_text, search_with_numeral_pattern,
+                          unitize_numeral_char, unitize_digit_char, search_era_marker, has_thai_script, unitize_month_jalali, has_year_jalali};
+use crate::bind::{EraBasedCalendar, DateTimeLabel, DateValidity, Token, Holiday, HolidayRule, Lexicon};
 
 
 const CHAR_NUMERIC: &str = "0123456789:";
 const CHAR_CHARACTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const CHAR_ANCHOR_HOUR: &str = "日号號天午晚早上晨夜到至";  // 点之前的时间语境｜今天 下午 晚上 凌晨 10月5日 到
+const CHAR_ANCHOR_MINUTE: &str = "点點时時";  // 分和刻之前的时刻｜三点十分 三点一刻
+const MAX_CELLS_ALIGN: usize = 40_000;  // 最长公共子序列的规模上限｜超过时中间部分视为整体替换
 
 pub fn is_timestamp(text: &str) -> bool{
     if is_number(text) && (text.chars().count() == 10||text.chars().count() == 13) {
@@ -48,9 +53,452 @@ pub fn create_datetime_current(utc: bool) ->NaiveDateTime{
     return datetime
 }
 
+/// 闰年判断
+pub fn is_leap_year(year: i32) -> bool{
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 指定年月的天数
+pub fn days_in_month(year: i32, month: u32) -> u32{
+    match month{
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0
+    }
+}
+
+/// 基于年月日生成日期并校验合法性
+/// 天数超出当月上限时视为非法日期
+/// 宽松模式下溢出的天数顺延至下个月 例如 2023-02-30 -> 2023-03-02
+pub fn create_date(year: i32, month: u32, day: u32, lenient: bool) -> (Option<NaiveDate>, DateValidity){
+    if month == 0 || month > 12 || day == 0 || day > 31{
+        return (None, DateValidity::Unrecognized)
+    }
+    if day <= days_in_month(year, month){
+        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day){
+            return (Some(date), DateValidity::Valid)
+        }
+        return (None, DateValidity::Unrecognized)
+    }
+    if lenient{
+        if let Some(first) = NaiveDate::from_ymd_opt(year, month, 1){
+            let date = first + Duration::days(day as i64 - 1);
+            return (Some(date), DateValidity::Rolled)
+        }
+    }
+    (None, DateValidity::Invalid)
+}
+
+/// 各书写系统的十进制数字统一为 ASCII 数字
+/// ٢٠٢٣/١٠/٠٥ -> 2023/10/05、२०२३-१०-०५ -> 2023-10-05、２０２３年１０月５日 １４：２０ -> 2023年10月5日 14:20
+pub fn unitize_digit(text: &str) -> String{
+    if text.is_ascii(){
+        return text.to_string()
+    }
+    let mut item = String::with_capacity(text.len());
+    for ch in text.chars(){
+        if ch.is_ascii(){
+            item.push(ch);
+            continue
+        }
+        match unitize_digit_char(ch){
+            Some(digit) => item.push(digit),
+            None => item.push(ch)
+        }
+    }
+    if let Some(pattern) = search_with_numeral_pattern("colon_fullwidth"){
+        item = pattern.replace_all(&item, "${1}:${2}").to_string();
+    }
+    item
+}
+
+/// 汉字数字转阿拉伯数字
+/// 二〇二三年十月五日 -> 2023年10月5日
+/// 下午三点半 -> 下午3点30
+/// 两周前 -> 2周前、半小时前 -> 30分钟前
+pub fn unitize_numeral(text: &str) -> String{
+    if text.is_ascii(){
+        return text.to_string()
+    }
+    let mut item = text.to_string();
+    if let Some(pattern) = search_with_numeral_pattern("unit"){
+        let source = item.clone();
+        item = pattern.replace_all(&source, |caps: &regex::Captures| {
+            let whole = caps.get(0).unwrap();
+            if !is_numeral_time(&source[..whole.start()], &caps[2], &source[whole.end()..]){
+                return caps[0].to_string()
+            }
+            match convert_numeral(&caps[1]){
+                Some(number) => number + &caps[2],
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    if let Some(pattern) = search_with_numeral_pattern("initial"){
+        item = pattern.replace_all(&item, |caps: &regex::Captures| {
+            match convert_numeral(&caps[1]){
+                Some(number) => "初".to_string() + &number,
+                None => caps[0].to_string()
+            }
+        }).to_string();
+    }
+    if let Some(pattern) = search_with_numeral_pattern("half_time"){
+        item = pattern.replace_all(&item, "${1}${2}30").to_string();
+    }
+    if let Some(pattern) = search_with_numeral_pattern("half_hours"){
+        item = pattern.replace_all(&item, |caps: &regex::Captures| {
+            let hours: u32 = caps[1].parse().unwrap_or(0);
+            (hours * 60 + 30).to_string() + "分钟"
+        }).to_string();
+    }
+    for (name, value) in [("half_hour", "30分钟"), ("half_minute", "30秒"), ("half_day", "12小时${1}"),
+                          ("half_month", "15天${1}"), ("half_year", "6个月${1}")]{
+        if let Some(pattern) = search_with_numeral_pattern(name){
+            item = pattern.replace_all(&item, value).to_string();
+        }
+    }
+    item
+}
+
+/// 点 分 刻 个 只在时间语境中视为单位｜有一点冷 三点水 下午3点 十分重要 不转换
+/// 点需要之前有时间语境或者之后为 钟 整 半 几分 几刻 分和刻需要紧接在点之后或者之后为 钟 个需要之后为时间单位
+fn is_numeral_time(head: &str, unit: &str, tail: &str) -> bool{
+    let next = |items: &[&str]| items.iter().any(|v| tail.starts_with(v));
+    match unit{
+        "点" | "點" => {
+            if head.trim_end().chars().last().map(|ch| CHAR_ANCHOR_HOUR.contains(ch)).unwrap_or(false) || next(&["钟", "鐘", "整", "半"]){
+                return true
+            }
+            let rest = tail.trim_start_matches(|ch: char| ch.is_ascii_digit() || unitize_numeral_char(ch.to_string().as_str()).is_some());
+            rest.len() < tail.len() && (rest.starts_with('分') || rest.starts_with('刻'))
+        }
+        "分" | "刻" => head.chars().last().map(|ch| CHAR_ANCHOR_MINUTE.contains(ch)).unwrap_or(false) || next(&["钟", "鐘"]),
+        "个" | "個" => next(&["小时", "小時", "钟头", "鐘頭", "月", "星期", "礼拜", "禮拜", "周", "週", "半", "工作日"]),
+        _ => true
+    }
+}
+
+/// 汉字数字串转数值文本
+/// 不含位值字符时逐位转换 二〇二三 -> 2023
+/// 含位值字符时按位累加 二十三 -> 23、廿五 -> 25、一百零五 -> 105
+/// 阿拉伯数字原样返回 15 -> 15
+pub fn convert_numeral(text: &str) -> Option<String>{
+    if text.chars().all(|ch| ch.is_ascii_digit()) && !text.is_empty(){
+        return Some(text.to_string())
+    }
+    let mut digits = String::new();
+    let mut positional = false;
+    let mut total: u32 = 0;
+    let mut current: u32 = 0;
+    for ch in text.chars(){
+        let value = *unitize_numeral_char(ch.to_string().as_str())?;
+        match value{
+            0..=9 => {
+                digits.push_str(value.to_string().as_str());
+                current = value;
+            }
+            10 | 100 | 1000 => {
+                positional = true;
+                if current == 0{
+                    current = 1;
+                }
+                total += current * value;
+                current = 0;
+            }
+            _ => {
+                // 廿 卅 卌
+                positional = true;
+                total += value;
+                current = 0;
+            }
+        }
+    }
+    if !positional{
+        return Some(digits)
+    }
+    Some((total + current).to_string())
+}
+
+/// 伊斯兰历（算术历）转公历
+/// 每 30 年有 11 个闰年 闰年的 12 月为 30 天
+/// 单数月 30 天 双数月 29 天
+/// 超出当月天数时 宽松模式下顺延到下个月 例如 1445-02-30 -> 1445-03-01 否则为 Invalid
+pub fn create_date_hijri(year: i32, month: u32, day: u32, lenient: bool) -> (Option<NaiveDate>, DateValidity){
+    if year < 1 || month == 0 || month > 12 || day == 0 || day > 30{
+        return (None, DateValidity::Unrecognized)
+    }
+    let leap = (14 + 11 * year).rem_euclid(30) < 11;
+    let days_of_month = match month{
+        12 if leap => 30,
+        m if m % 2 == 1 => 30,
+        _ => 29
+    };
+    let mut validity = DateValidity::Valid;
+    if day > days_of_month{
+        if !lenient{
+            return (None, DateValidity::Invalid)
+        }
+        validity = DateValidity::Rolled;
+    }
+    // 伊斯兰历元年一月一日 即公历 622-07-19
+    let days = (year as i64 - 1) * 354 + ((3 + 11 * year as i64) / 30) + (29.5 * (month as f64 - 1.0)).ceil() as i64 + day as i64 - 1;
+    match NaiveDate::from_ymd_opt(622, 7, 19).and_then(|epoch| epoch.checked_add_signed(Duration::days(days))){
+        Some(date) => (Some(date), validity),
+        None => (None, DateValidity::Unrecognized)
+    }
+}
+
+/// 伊朗历闰年周期的分界年份
+const BREAKS_JALALI: [i32; 20] = [-61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192,
+                                  2262, 2324, 2394, 2456, 3178];
+
+/// 伊朗历年份的闰年信息
+/// 返回 (是否闰年, 对应公历年份, 伊朗历元旦在公历三月的日期)
+fn calendar_jalali(year: i32) -> Option<(bool, i32, i32)>{
+    if year < BREAKS_JALALI[0] || year >= BREAKS_JALALI[BREAKS_JALALI.len() - 1]{
+        return None
+    }
+    let gregorian = year + 621;
+    let mut leap_jalali = -14;
+    let mut previous = BREAKS_JALALI[0];
+    let mut jump = 0;
+    for current in BREAKS_JALALI.iter().skip(1){
+        jump = current - previous;
+        if year < *current{
+            break
+        }
+        leap_jalali += jump / 33 * 8 + jump % 33 / 4;
+        previous = *current;
+    }
+    let mut n = year - previous;
+    leap_jalali += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4{
+        leap_jalali += 1;
+    }
+    let leap_gregorian = gregorian / 4 - (gregorian / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_jalali - leap_gregorian;
+    if jump - n < 6{
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let mut leap = ((n + 1) % 33 - 1) % 4;
+    if leap == -1{
+        leap = 4;
+    }
+    Some((leap == 0, gregorian, march))
+}
+
+/// 伊朗历指定年月的天数
+pub fn days_in_month_jalali(year: i32, month: u32) -> u32{
+    match month{
+        1..=6 => 31,
+        7..=11 => 30,
+        12 => {
+            match calendar_jalali(year){
+                Some((true, _, _)) => 30,
+                _ => 29
+            }
+        }
+        _ => 0
+    }
+}
+
+/// 伊朗历（波斯历）转公历
+/// 1402/07/13 -> 2023-10-05
+pub fn create_date_jalali(year: i32, month: u32, day: u32, lenient: bool) -> (Option<NaiveDate>, DateValidity){
+    if month == 0 || month > 12 || day == 0 || day > 31{
+        return (None, DateValidity::Unrecognized)
+    }
+    let (_, gregorian, march) = match calendar_jalali(year){
+        Some(v) => v,
+        None => return (None, DateValidity::Unrecognized)
+    };
+    let mut validity = DateValidity::Valid;
+    if day > days_in_month_jalali(year, month){
+        if !lenient{
+            return (None, DateValidity::Invalid)
+        }
+        validity = DateValidity::Rolled;
+    }
+    let month = month as i64;
+    let days = (month - 1) * 31 - month / 7 * (month - 7) + day as i64 - 1;
+    if let Some(nowruz) = NaiveDate::from_ymd_opt(gregorian, 3, march as u32){
+        return (Some(nowruz + Duration::days(days)), validity)
+    }
+    (None, DateValidity::Unrecognized)
+}
+
+/// 农历 1900-2100 年的月份信息
+/// 低 4 位为闰月月份 无闰月时为 0
+/// 第 5-16 位依次对应 1-12 月 置位表示大月 30 天 否则为小月 29 天
+/// 第 17 位表示闰月为大月
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+/// 农历数据覆盖的起始年份 农历 1900 年正月初一 即公历 1900-01-31
+const MIN_YEAR_LUNAR: i32 = 1900;
+
+/// 农历年份的月份信息
+fn calendar_lunar(year: i32) -> Option<u32>{
+    if year < MIN_YEAR_LUNAR{
+        return None
+    }
+    LUNAR_INFO.get((year - MIN_YEAR_LUNAR) as usize).copied()
+}
+
+/// 农历指定年份的闰月 无闰月时为 0
+pub fn leap_month_lunar(year: i32) -> u32{
+    calendar_lunar(year).map(|v| v & 0xf).unwrap_or(0)
+}
+
+/// 农历指定年月的天数 闰月由 leap 指定
+pub fn days_in_month_lunar(year: i32, month: u32, leap: bool) -> u32{
+    let info = match calendar_lunar(year){
+        Some(v) => v,
+        None => return 0
+    };
+    if month == 0 || month > 12{
+        return 0
+    }
+    if leap{
+        if info & 0xf != month{
+            return 0
+        }
+        return if info & 0x10000 != 0 {30} else {29}
+    }
+    if info & (0x10000 >> month) != 0 {30} else {29}
+}
+
+/// 农历指定年份的总天数
+fn days_in_year_lunar(year: i32) -> i64{
+    let leap = leap_month_lunar(year);
+    let mut total = 0;
+    for month in 1..=12{
+        total += days_in_month_lunar(year, month, false) as i64;
+        if month == leap{
+            total += days_in_month_lunar(year, month, true) as i64;
+        }
+    }
+    total
+}
+
+/// 农历转公历
+/// 2023 年八月十五 -> 2023-09-29
+/// 2023 年闰二月初一 -> 2023-03-22
+pub fn create_date_lunar(year: i32, month: u32, day: u32, leap: bool, lenient: bool) -> (Option<NaiveDate>, DateValidity){
+    if calendar_lunar(year).is_none() || month == 0 || month > 12 || day == 0 || day > 30{
+        return (None, DateValidity::Unrecognized)
+    }
+    let days_of_month = days_in_month_lunar(year, month, leap);
+    if days_of_month == 0{
+        return (None, DateValidity::Invalid)
+    }
+    let mut validity = DateValidity::Valid;
+    if day > days_of_month{
+        if !lenient{
+            return (None, DateValidity::Invalid)
+        }
+        validity = DateValidity::Rolled;
+    }
+    let mut days: i64 = (MIN_YEAR_LUNAR..year).map(days_in_year_lunar).sum();
+    let leap_month = leap_month_lunar(year);
+    for current in 1..month{
+        days += days_in_month_lunar(year, current, false) as i64;
+        if current == leap_month{
+            days += days_in_month_lunar(year, current, true) as i64;
+        }
+    }
+    if leap{
+        days += days_in_month_lunar(year, month, false) as i64;
+    }
+    days += day as i64 - 1;
+    if let Some(epoch) = NaiveDate::from_ymd_opt(1900, 1, 31){
+        return (Some(epoch + Duration::days(days)), validity)
+    }
+    (None, DateValidity::Unrecognized)
+}
+
+/// 节日在指定公历年份的日期
+/// 农历节日优先取落在该公历年份内的一天 例如 2024 年的腊八为 2024-01-18 该公历年份内没有时取同一农历年份的日期
+pub fn create_date_holiday(holiday: &Holiday, year: i32) -> Option<NaiveDate>{
+    let offset = Duration::days(holiday.offset);
+    match holiday.rule{
+        HolidayRule::Fixed(month, day) => Some(NaiveDate::from_ymd_opt(year, month, day)? + offset),
+        HolidayRule::Weekday(month, weekday, n) => {
+            let date = if n > 0 {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, u8::try_from(n).ok()?)?
+            }else{
+                // 从月末倒数｜-1 为最后一个
+                let last = NaiveDate::from_ymd_opt(year, month, days_in_month(year, month))?;
+                let diff = (last.weekday().num_days_from_monday() as i64 - weekday.num_days_from_monday() as i64).rem_euclid(7);
+                let date = last - Duration::days(diff) + Duration::weeks(n as i64 + 1);
+                if date.month() != month{
+                    return None
+                }
+                date
+            };
+            Some(date + offset)
+        }
+        HolidayRule::Lunar(month, day) => {
+            let date = create_date_lunar(year, month, day, false, false).0? + offset;
+            if date.year() != year{
+                if let (Some(previous), _) = create_date_lunar(year - 1, month, day, false, false){
+                    if (previous + offset).year() == year{
+                        return Some(previous + offset)
+                    }
+                }
+            }
+            Some(date)
+        }
+        HolidayRule::Easter => Some(create_date_easter(year)? + offset)
+    }
+}
+
+/// 复活节｜格里历的计算方法 (Anonymous Gregorian algorithm)
+fn create_date_easter(year: i32) -> Option<NaiveDate>{
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
 /// 去噪
 pub fn eliminate_noise(text: &str) -> String{
-    let mut item = text.trim().replace("/n", " ");
+    let mut item = unitize_digit(text.trim());
+    item = item.replace("/n", " ");
     item = eliminate_no_break_space(item.as_str());
     item = eliminate_symbol_normal(&item);
     item = eliminate_space(&item);
@@ -83,13 +531,23 @@ pub fn eliminate_space(text: &str) -> String{
 /// 10月 -> october
 /// 公元2002年 10월 13 5:50 PM Cst - "公元2002年", "october", "13", "5:50", "pm", "cst"
 ///  return "公元2002年 october 13 5:50 pm cst"
-pub fn unitize_text(text: &str) -> (String, EraBasedCalendar){
+/// 纪元判断｜明确的纪元标志优先 其次是泰语月份 最后是泰文
+/// 伊朗历月份名称仅在伊朗历的上下文中转换｜jalali 为已知的伊朗历 例如原文为波斯数字 或者有伊朗历标志、伊朗历范围内的年份
+pub fn unitize_text(text: &str, lexicon: &Lexicon, jalali: bool) -> (String, EraBasedCalendar){
+    let marker = search_era_marker(text);
+    let jalali = jalali || marker == Some(EraBasedCalendar::Jalali) || has_year_jalali(text);
     let mut era = EraBasedCalendar::Non;
     let mut data: Vec<String> = Vec::new();
     let section: Vec<&str> = text.split(" ").collect();
     for text in section{
         let item = text.to_lowercase();
-        if let Some(value) = unitize_date_text(&item){
+        if let Some(value) = unitize_month_jalali(&item).filter(|_| jalali){
+            // 标记伊朗历
+            era = EraBasedCalendar::Jalali;
+            data.push(value.to_string());
+            continue
+        }
+        if let Some(value) = unitize_date_text(&item, lexicon){
             // 标记泰历
             if is_thai_month(&item){
                 era = EraBasedCalendar::Thai;
@@ -99,6 +557,11 @@ pub fn unitize_text(text: &str) -> (String, EraBasedCalendar){
         }
         data.push(item);
     }
+    if let Some(v) = marker{
+        era = v;
+    }else if era == EraBasedCalendar::Non && has_thai_script(text){
+        era = EraBasedCalendar::Thai;
+    }
     return (data.join(" "), era)
 }
 
@@ -136,11 +599,11 @@ pub fn section_with_space(sec:Vec<String>) -> Vec<String>{
 }
 
 // 基于文本匹配时区和偏移量
-pub fn search_offset_with_text(section: &Vec<String>) ->(i32, String){
+pub fn search_offset_with_text(section: &Vec<String>, lexicon: &Lexicon) ->(i32, String){
     let mut number = 0;
     let mut timezone = String::from("");
     for v in section{
-        if let Some(n) = unitize_timezone_with_text(v){
+        if let Some(n) = unitize_timezone_with_text(v, lexicon){
             number = *n;
             timezone = v.to_string();
         }
@@ -244,4 +707,83 @@ pub fn reorder_text_meridian(text: &str) -> String{
     }
     words[position_time as usize] = item.as_str();
     return words.join(" ")
-}
\ No newline at end of file
+}
+
+/// 逐字符的原文字节范围｜用于在规整文本时追踪原文位置
+pub fn create_origin(text: &str) -> Vec<(usize, usize)>{
+    text.char_indices().map(|(i, ch)| (i, i + ch.len_utf8())).collect()
+}
+
+/// 将改写前逐字符的原文位置映射到改写后的文本
+/// 1. 先去掉首尾相同的部分 中间部分按最长公共子序列对齐 忽略大小写
+/// 2. 对齐的字符沿用原位置 被替换的字符取被替换部分的并集 例如 十月 -> 10 october -> oct
+/// 3. 插入的字符取前一个对齐字符的末尾 宽度为零
+pub fn align_origin(before: &str, after: &str, origin: &[(usize, usize)]) -> Vec<(usize, usize)>{
+    let source: Vec<char> = before.chars().collect();
+    let target: Vec<char> = after.chars().collect();
+    if source.len() != origin.len(){
+        return vec![origin.last().map(|v| (v.1, v.1)).unwrap_or((0, 0)); target.len()]
+    }
+    let same = |a: char, b: char| a == b || a.to_lowercase().eq(b.to_lowercase());
+    let mut head = 0;
+    while head < source.len() && head < target.len() && same(source[head], target[head]){
+        head += 1;
+    }
+    let mut tail = 0;
+    while tail < source.len() - head && tail < target.len() - head && same(source[source.len() - 1 - tail], target[target.len() - 1 - tail]){
+        tail += 1;
+    }
+    let pairs = search_common_subsequence(&source[head..source.len() - tail], &target[head..target.len() - tail], same);
+    // 对齐的字符对｜(改写前下标, 改写后下标)
+    let mut matched: Vec<(usize, usize)> = (0..head).map(|i| (i, i)).collect();
+    matched.extend(pairs.iter().map(|(i, j)| (i + head, j + head)));
+    matched.extend((0..tail).map(|k| (source.len() - tail + k, target.len() - tail + k)));
+    let mut data: Vec<(usize, usize)> = Vec::with_capacity(target.len());
+    let (mut i, mut j) = (0, 0);
+    for (p, q) in matched.into_iter().chain([(source.len(), target.len())]){
+        if j < q{
+            // 被替换或插入的部分
+            let position = if i > 0 {origin[i - 1].1} else {origin.first().map(|v| v.0).unwrap_or(0)};
+            let range = if i < p {(origin[i].0, origin[p - 1].1)} else {(position, position)};
+            data.extend(std::iter::repeat_n(range, q - j));
+        }
+        if p < source.len(){
+            data.push(origin[p]);
+        }
+        i = p + 1;
+        j = q + 1;
+    }
+    data
+}
+
+/// 最长公共子序列｜返回对齐的下标对 规模过大时视为整体替换
+fn search_common_subsequence<F: Fn(char, char) -> bool>(source: &[char], target: &[char], same: F) -> Vec<(usize, usize)>{
+    let (n, m) = (source.len(), target.len());
+    if n == 0 || m == 0 || n * m > MAX_CELLS_ALIGN{
+        return vec![]
+    }
+    let mut table = vec![0u16; (n + 1) * (m + 1)];
+    for i in (0..n).rev(){
+        for j in (0..m).rev(){
+            table[i * (m + 1) + j] = if same(source[i], target[j]) {
+                table[(i + 1) * (m + 1) + j + 1] + 1
+            }else{
+                table[(i + 1) * (m + 1) + j].max(table[i * (m + 1) + j + 1])
+            };
+        }
+    }
+    let mut data: Vec<(usize, usize)> = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m{
+        if same(source[i], target[j]){
+            data.push((i, j));
+            i += 1;
+            j += 1;
+        }else if table[(i + 1) * (m + 1) + j] >= table[i * (m + 1) + j + 1]{
+            i += 1;
+        }else{
+            j += 1;
+        }
+    }
+    data
+}